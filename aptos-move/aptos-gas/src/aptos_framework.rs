@@ -164,6 +164,10 @@ crate::natives::define_gas_parameters_for_natives!(GasParameters, "aptos_framewo
     [.util.from_bytes.per_byte, "util.from_bytes.per_byte", 5 * MUL],
 
     [.transaction_context.get_script_hash.base, "transaction_context.get_script_hash.base", 200 * MUL],
+    [.transaction_context.get_sender.base, { 9.. => "transaction_context.get_sender.base" }, 200 * MUL],
+    [.transaction_context.get_replay_protector_kind.base, { 9.. => "transaction_context.get_replay_protector_kind.base" }, 200 * MUL],
+    [.transaction_context.get_publish_target.base, { 9.. => "transaction_context.get_publish_target.base" }, 200 * MUL],
+    [.transaction_context.get_fee_payer.base, { 9.. => "transaction_context.get_fee_payer.base" }, 200 * MUL],
 
     [.code.request_publish.base, "code.request_publish.base", 500 * MUL],
     [.code.request_publish.per_byte, "code.request_publish.per_byte", 2 * MUL],
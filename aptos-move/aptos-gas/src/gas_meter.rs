@@ -33,6 +33,11 @@ use move_vm_types::{
 use std::collections::BTreeMap;
 
 // Change log:
+// - V9
+//   - Added transaction_context::get_sender native.
+//   - Added transaction_context::get_replay_protector_kind native.
+//   - Added transaction_context::get_publish_target native.
+//   - Added transaction_context::get_fee_payer native.
 // - V8
 //   - Added BLS12-381 operations.
 // - V7
@@ -59,7 +64,9 @@ use std::collections::BTreeMap;
 //       global operations.
 // - V1
 //   - TBA
-pub const LATEST_GAS_FEATURE_VERSION: u64 = 8;
+//
+// When bumping the version, also bump the range of `aptos_config`'s GAS_FEATURE_VERSIONS.
+pub const LATEST_GAS_FEATURE_VERSION: u64 = 9;
 
 pub(crate) const EXECUTION_GAS_MULTIPLIER: u64 = 20;
 
@@ -23,7 +23,9 @@ use aptos_aggregator::{
     transaction::{ChangeSetExt, TransactionOutputExt},
 };
 use aptos_crypto::HashValue;
-use aptos_framework::natives::code::PublishRequest;
+use aptos_framework::natives::{
+    code::PublishRequest, transaction_context::TransactionContextCapabilities,
+};
 use aptos_gas::{
     AptosGasMeter, AptosGasParameters, ChangeSetConfigs, Gas, StandardGasMeter,
     StorageGasParameters,
@@ -80,6 +82,7 @@ static NUM_PROOF_READING_THREADS: OnceCell<usize> = OnceCell::new();
 static PARANOID_TYPE_CHECKS: OnceCell<bool> = OnceCell::new();
 static PROCESSED_TRANSACTIONS_DETAILED_COUNTERS: OnceCell<bool> = OnceCell::new();
 static TIMED_FEATURE_OVERRIDE: OnceCell<TimedFeatureOverride> = OnceCell::new();
+static GET_SENDER_NATIVE_DISABLED_CHAIN_IDS: OnceCell<Vec<u8>> = OnceCell::new();
 
 /// Remove this once the bundle is removed from the code.
 static MODULE_BUNDLE_DISALLOWED: AtomicBool = AtomicBool::new(true);
@@ -146,6 +149,23 @@ impl AptosVM {
         }
     }
 
+    /// Sets the chain ids on which the transaction_context::get_sender native is not registered,
+    /// when invoked the first time.
+    pub fn set_get_sender_native_disabled_chain_ids(chain_ids: Vec<u8>) {
+        // Only the first call succeeds, due to OnceCell semantics.
+        GET_SENDER_NATIVE_DISABLED_CHAIN_IDS.set(chain_ids).ok();
+    }
+
+    /// Get the transaction context natives to register for the given chain id.
+    /// All of them are registered by default.
+    pub fn get_transaction_context_capabilities(chain_id: u8) -> TransactionContextCapabilities {
+        TransactionContextCapabilities {
+            get_sender: !GET_SENDER_NATIVE_DISABLED_CHAIN_IDS
+                .get()
+                .map_or(false, |chain_ids| chain_ids.contains(&chain_id)),
+        }
+    }
+
     // Set the override profile for timed features.
     pub fn set_timed_feature_override(profile: TimedFeatureOverride) {
         TIMED_FEATURE_OVERRIDE.set(profile).ok();
@@ -1032,7 +1052,12 @@ impl AptosVM {
     {
         // Revalidate the transaction.
         let resolver = self.0.new_move_resolver(storage);
-        let mut session = self.0.new_session(&resolver, SessionId::txn(txn));
+        let txn_data = TransactionMetadata::new(txn);
+        let mut session = self.0.new_session_with_publish_target(
+            &resolver,
+            SessionId::txn(txn),
+            txn_data.publish_target(),
+        );
         if let Err(err) = self.validate_signature_checked_transaction(
             &mut session,
             storage,
@@ -1049,11 +1074,14 @@ impl AptosVM {
             // have been previously cached in the prologue.
             //
             // TODO(Gas): Do this in a better way in the future, perhaps without forcing the data cache to be flushed.
-            session = self.0.new_session(&resolver, SessionId::txn(txn));
+            session = self.0.new_session_with_publish_target(
+                &resolver,
+                SessionId::txn(txn),
+                txn_data.publish_target(),
+            );
         }
 
         let storage_gas_params = unwrap_or_discard!(self.0.get_storage_gas_parameters(log_context));
-        let txn_data = TransactionMetadata::new(txn);
 
         // We keep track of whether any newly published modules are loaded into the Vm's loader
         // cache as part of executing transactions. This would allow us to decide whether the cache
@@ -32,6 +32,7 @@ use aptos_vm_logging::{log_schema::AdapterLogSchema, prelude::*};
 use fail::fail_point;
 use move_binary_format::{errors::VMResult, CompiledModule};
 use move_core_types::{
+    account_address::AccountAddress,
     language_storage::ModuleId,
     move_resource::MoveStructType,
     resolver::ResourceResolver,
@@ -622,6 +623,17 @@ impl AptosVMImpl {
         self.move_vm.new_session(r, session_id)
     }
 
+    /// Same as `new_session`, for a transaction publishing a package to `publish_target`.
+    pub fn new_session_with_publish_target<'r, R: MoveResolverExt>(
+        &self,
+        r: &'r R,
+        session_id: SessionId,
+        publish_target: Option<AccountAddress>,
+    ) -> SessionExt<'r, '_, R> {
+        self.move_vm
+            .new_session_with_publish_target(r, session_id, publish_target)
+    }
+
     pub fn load_module<'r, R: MoveResolverExt>(
         &self,
         module_id: &ModuleId,
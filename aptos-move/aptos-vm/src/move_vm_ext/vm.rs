@@ -10,10 +10,13 @@ use aptos_framework::natives::{
     code::NativeCodeContext,
     cryptography::{algebra::AlgebraContext, ristretto255_point::NativeRistrettoPointContext},
     state_storage::NativeStateStorageContext,
-    transaction_context::NativeTransactionContext,
+    transaction_context::{NativeTransactionContext, ReplayProtectorKind},
 };
 use aptos_gas::{AbstractValueSizeGasParameters, NativeGasParameters};
-use aptos_types::on_chain_config::{FeatureFlag, Features, TimedFeatureFlag, TimedFeatures};
+use aptos_types::{
+    account_address::AccountAddress,
+    on_chain_config::{FeatureFlag, Features, TimedFeatureFlag, TimedFeatures},
+};
 use move_binary_format::errors::VMResult;
 use move_bytecode_verifier::VerifierConfig;
 use move_table_extension::NativeTableContext;
@@ -54,6 +57,7 @@ impl MoveVmExt {
                     native_gas_params,
                     abs_val_size_gas_params,
                     gas_feature_version,
+                    crate::AptosVM::get_transaction_context_capabilities(chain_id),
                     timed_features.clone(),
                     Arc::new(features),
                 ),
@@ -71,6 +75,17 @@ impl MoveVmExt {
         &self,
         remote: &'r S,
         session_id: SessionId,
+    ) -> SessionExt<'r, '_, S> {
+        self.new_session_with_publish_target(remote, session_id, None)
+    }
+
+    /// Creates a session for a transaction publishing a package to `publish_target`,
+    /// which is exposed to Move by `transaction_context::get_publish_target`.
+    pub fn new_session_with_publish_target<'r, S: MoveResolverExt>(
+        &self,
+        remote: &'r S,
+        session_id: SessionId,
+        publish_target: Option<AccountAddress>,
     ) -> SessionExt<'r, '_, S> {
         let mut extensions = NativeContextExtensions::default();
         let txn_hash: [u8; 32] = session_id
@@ -84,16 +99,25 @@ impl MoveVmExt {
         extensions.add(AlgebraContext::new());
         extensions.add(NativeAggregatorContext::new(txn_hash, remote));
 
-        let script_hash = match session_id {
+        let (script_hash, sender) = match session_id {
             SessionId::Txn {
-                sender: _,
+                sender,
                 sequence_number: _,
                 script_hash,
-            } => script_hash,
-            _ => vec![],
+            } => (script_hash, Some(sender)),
+            _ => (vec![], None),
         };
 
-        extensions.add(NativeTransactionContext::new(script_hash, self.chain_id));
+        extensions.add(NativeTransactionContext::new(
+            script_hash,
+            self.chain_id,
+            sender,
+            // Only sequence number based transactions are supported for now
+            ReplayProtectorKind::SequenceNumber,
+            publish_target,
+            // Sponsored transactions are not supported yet, so the sender always pays
+            None,
+        ));
         extensions.add(NativeCodeContext::default());
         extensions.add(NativeStateStorageContext::new(remote));
 
@@ -4,6 +4,7 @@
 
 #[cfg(feature = "testing")]
 use aptos_framework::natives::cryptography::algebra::AlgebraContext;
+pub use aptos_framework::natives::transaction_context::TransactionContextCapabilities;
 use aptos_gas::{AbstractValueSizeGasParameters, NativeGasParameters, LATEST_GAS_FEATURE_VERSION};
 #[cfg(feature = "testing")]
 use aptos_types::chain_id::ChainId;
@@ -16,9 +17,10 @@ use std::sync::Arc;
 #[cfg(feature = "testing")]
 use {
     aptos_framework::natives::{
-        aggregator_natives::NativeAggregatorContext, code::NativeCodeContext,
+        aggregator_natives::NativeAggregatorContext,
+        code::NativeCodeContext,
         cryptography::ristretto255_point::NativeRistrettoPointContext,
-        transaction_context::NativeTransactionContext,
+        transaction_context::{NativeTransactionContext, ReplayProtectorKind},
     },
     move_vm_runtime::native_extensions::NativeContextExtensions,
     move_vm_test_utils::BlankStorage,
@@ -32,6 +34,7 @@ pub fn aptos_natives(
     gas_params: NativeGasParameters,
     abs_val_size_gas_params: AbstractValueSizeGasParameters,
     gas_feature_version: u64,
+    transaction_context_capabilities: TransactionContextCapabilities,
     timed_features: TimedFeatures,
     features: Arc<Features>,
 ) -> NativeFunctionTable {
@@ -42,6 +45,7 @@ pub fn aptos_natives(
             CORE_CODE_ADDRESS,
             gas_params.move_stdlib,
             gas_params.aptos_framework,
+            transaction_context_capabilities,
             timed_features,
             features,
             move |val| abs_val_size_gas_params.abstract_value_size(val, gas_feature_version),
@@ -70,6 +74,7 @@ pub fn assert_no_test_natives(err_msg: &str) {
             NativeGasParameters::zeros(),
             AbstractValueSizeGasParameters::zeros(),
             LATEST_GAS_FEATURE_VERSION,
+            TransactionContextCapabilities::all(),
             TimedFeatures::enable_all(),
             Arc::new(Features::default())
         )
@@ -102,7 +107,14 @@ pub fn configure_for_unit_test() {
 #[cfg(feature = "testing")]
 fn unit_test_extensions_hook(exts: &mut NativeContextExtensions) {
     exts.add(NativeCodeContext::default());
-    exts.add(NativeTransactionContext::new(vec![1], ChainId::test().id())); // We use the testing environment chain ID here
+    exts.add(NativeTransactionContext::new(
+        vec![1],
+        ChainId::test().id(),
+        None,
+        ReplayProtectorKind::SequenceNumber,
+        None,
+        None,
+    )); // We use the testing environment chain ID here
     exts.add(NativeAggregatorContext::new([0; 32], &*DUMMY_RESOLVER));
     exts.add(NativeRistrettoPointContext::new());
     exts.add(AlgebraContext::new());
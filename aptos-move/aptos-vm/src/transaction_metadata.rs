@@ -6,6 +6,7 @@ use aptos_crypto::{ed25519::Ed25519PrivateKey, HashValue, PrivateKey};
 use aptos_gas::{FeePerGasUnit, Gas, NumBytes};
 use aptos_types::{
     account_address::AccountAddress,
+    account_config::CORE_CODE_ADDRESS,
     chain_id::ChainId,
     transaction::{authenticator::AuthenticationKey, SignedTransaction, TransactionPayload},
 };
@@ -24,6 +25,8 @@ pub struct TransactionMetadata {
     pub chain_id: ChainId,
     pub script_hash: Vec<u8>,
     pub script_size: NumBytes,
+    /// Address the package is published to, for `code::publish_package_txn` transactions.
+    pub publish_target: Option<AccountAddress>,
 }
 
 impl TransactionMetadata {
@@ -56,6 +59,17 @@ impl TransactionMetadata {
                 TransactionPayload::Script(s) => (s.code().len() as u64).into(),
                 _ => NumBytes::zero(),
             },
+            publish_target: match txn.payload() {
+                // Packages are always published under the sender's account
+                TransactionPayload::EntryFunction(entry_function)
+                    if *entry_function.module().address() == CORE_CODE_ADDRESS
+                        && entry_function.module().name().as_str() == "code"
+                        && entry_function.function().as_str() == "publish_package_txn" =>
+                {
+                    Some(txn.sender())
+                },
+                _ => None,
+            },
         }
     }
 
@@ -99,6 +113,10 @@ impl TransactionMetadata {
         self.chain_id
     }
 
+    pub fn publish_target(&self) -> Option<AccountAddress> {
+        self.publish_target
+    }
+
     pub fn is_multi_agent(&self) -> bool {
         !self.secondary_signers.is_empty()
     }
@@ -122,6 +140,7 @@ impl Default for TransactionMetadata {
             chain_id: ChainId::test(),
             script_hash: vec![],
             script_size: NumBytes::zero(),
+            publish_target: None,
         }
     }
 }
@@ -25,6 +25,7 @@ mod nft_dao;
 mod offer_rotation_capability;
 mod offer_signer_capability;
 mod per_category_gas_limits;
+mod publish_target;
 mod resource_groups;
 mod rotate_auth_key;
 mod scripts;
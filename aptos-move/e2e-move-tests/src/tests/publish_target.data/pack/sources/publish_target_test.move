@@ -0,0 +1,20 @@
+module 0xcafe::publish_target_test {
+    use aptos_framework::transaction_context;
+
+    /// Publish target seen by the last transaction which stored it, read back by the test.
+    struct PublishTargetStore has key {
+        target: vector<address>,
+    }
+
+    /// Called as part of the publish transaction.
+    fun init_module(sender: &signer) {
+        move_to(sender, PublishTargetStore {
+            target: transaction_context::get_publish_target(),
+        });
+    }
+
+    public entry fun store_publish_target(_s: &signer) acquires PublishTargetStore {
+        let store = borrow_global_mut<PublishTargetStore>(@0xcafe);
+        store.target = transaction_context::get_publish_target();
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{assert_success, tests::common, MoveHarness};
+use move_core_types::{account_address::AccountAddress, parser::parse_struct_tag};
+use serde::{Deserialize, Serialize};
+
+/// Mimics `0xcafe::publish_target_test::PublishTargetStore`
+#[derive(Deserialize, Serialize)]
+struct PublishTargetStore {
+    target: Vec<AccountAddress>,
+}
+
+fn read_publish_target(harness: &MoveHarness, address: AccountAddress) -> Vec<AccountAddress> {
+    harness
+        .read_resource::<PublishTargetStore>(
+            &address,
+            parse_struct_tag("0xcafe::publish_target_test::PublishTargetStore").unwrap(),
+        )
+        .unwrap()
+        .target
+}
+
+#[test]
+fn test_publish_target() {
+    let mut harness = MoveHarness::new();
+    let account = harness.new_account_at(AccountAddress::from_hex_literal("0xcafe").unwrap());
+
+    // init_module runs as part of the publish transaction, which targets the publisher
+    assert_success!(
+        harness.publish_package(&account, &common::test_dir_path("publish_target.data/pack"))
+    );
+    assert_eq!(read_publish_target(&harness, *account.address()), vec![
+        *account.address()
+    ]);
+
+    // Any other transaction has no publish target
+    assert_success!(harness.run_entry_function(
+        &account,
+        str::parse("0xcafe::publish_target_test::store_publish_target").unwrap(),
+        vec![],
+        vec![],
+    ));
+    assert!(read_publish_target(&harness, *account.address()).is_empty());
+}
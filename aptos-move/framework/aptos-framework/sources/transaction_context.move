@@ -1,4 +1,20 @@
 module aptos_framework::transaction_context {
     /// Return the script hash of the current entry function.
     public native fun get_script_hash(): vector<u8>;
+
+    /// Return the sender of the current transaction.
+    /// Aborts if the current session is not executing a user transaction.
+    public native fun get_sender(): address;
+
+    /// Return the kind of replay protection used by the current transaction:
+    /// 0 for a sequence number, 1 for a nonce.
+    public native fun get_replay_protector_kind(): u8;
+
+    /// Return the address the current transaction publishes a package to, if it is a
+    /// `code::publish_package_txn` transaction, as a single element vector. Empty otherwise.
+    public native fun get_publish_target(): vector<address>;
+
+    /// Return the account paying the gas of the current transaction, if it is sponsored,
+    /// as a single element vector. Empty if the sender pays its own gas.
+    public native fun get_fee_payer(): vector<address>;
 }
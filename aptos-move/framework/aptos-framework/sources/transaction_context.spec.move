@@ -6,4 +6,34 @@ spec aptos_framework::transaction_context {
     }
 
     spec fun spec_get_script_hash(): vector<u8>;
+
+    spec get_sender(): address {
+        pragma opaque;
+        ensures result == spec_get_sender();
+    }
+
+    spec fun spec_get_sender(): address;
+
+    spec get_replay_protector_kind(): u8 {
+        pragma opaque;
+        ensures result == spec_get_replay_protector_kind();
+    }
+
+    spec fun spec_get_replay_protector_kind(): u8;
+
+    spec get_publish_target(): vector<address> {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_get_publish_target();
+    }
+
+    spec fun spec_get_publish_target(): vector<address>;
+
+    spec get_fee_payer(): vector<address> {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_get_fee_payer();
+    }
+
+    spec fun spec_get_fee_payer(): vector<address>;
 }
@@ -224,6 +224,13 @@ impl GasParameters {
             },
             transaction_context: transaction_context::GasParameters {
                 get_script_hash: transaction_context::GetScriptHashGasParameters { base: 0.into() },
+                get_sender: transaction_context::GetSenderGasParameters { base: 0.into() },
+                get_replay_protector_kind:
+                    transaction_context::GetReplayProtectorKindGasParameters { base: 0.into() },
+                get_publish_target: transaction_context::GetPublishTargetGasParameters {
+                    base: 0.into(),
+                },
+                get_fee_payer: transaction_context::GetFeePayerGasParameters { base: 0.into() },
             },
             code: code::GasParameters {
                 request_publish: code::RequestPublishGasParameters {
@@ -266,6 +273,7 @@ pub fn all_natives(
     framework_addr: AccountAddress,
     move_gas_params: aptos_move_stdlib::natives::GasParameters,
     gas_params: GasParameters,
+    transaction_context_capabilities: transaction_context::TransactionContextCapabilities,
     timed_features: TimedFeatures,
     features: Arc<Features>,
     calc_abstract_val_size: impl Fn(&Value) -> AbstractValueSize + Send + Sync + 'static,
@@ -377,6 +385,7 @@ pub fn all_natives(
         "transaction_context",
         transaction_context::make_all(
             gas_params.transaction_context,
+            transaction_context_capabilities,
             timed_features.clone(),
             features.clone()
         )
@@ -1,15 +1,26 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::natives::helpers::{make_safe_native, SafeNativeContext, SafeNativeResult};
+use crate::natives::helpers::{
+    make_safe_native, SafeNativeContext, SafeNativeError, SafeNativeResult,
+};
 use aptos_types::on_chain_config::{Features, TimedFeatures};
 use better_any::{Tid, TidAble};
-use move_core_types::gas_algebra::InternalGas;
+use move_core_types::{account_address::AccountAddress, gas_algebra::InternalGas};
 use move_vm_runtime::native_functions::NativeFunction;
 use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
 use smallvec::{smallvec, SmallVec};
 use std::{collections::VecDeque, fmt::Debug, sync::Arc};
 
+/// The kind of replay protection used by a transaction, as exposed to Move by
+/// `transaction_context::get_replay_protector_kind`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum ReplayProtectorKind {
+    SequenceNumber = 0,
+    Nonce = 1,
+}
+
 /// The native transaction context extension. This needs to be attached to the
 /// NativeContextExtensions value which is passed into session functions, so its accessible from
 /// natives of this extension.
@@ -17,21 +28,49 @@ use std::{collections::VecDeque, fmt::Debug, sync::Arc};
 pub struct NativeTransactionContext {
     script_hash: Vec<u8>,
     chain_id: u8,
+    sender: Option<AccountAddress>,
+    replay_protector: ReplayProtectorKind,
+    publish_target: Option<AccountAddress>,
+    fee_payer: Option<AccountAddress>,
 }
 
 impl NativeTransactionContext {
     /// Create a new instance of a native transaction context. This must be passed in via an
     /// extension into VM session functions.
-    pub fn new(script_hash: Vec<u8>, chain_id: u8) -> Self {
+    pub fn new(
+        script_hash: Vec<u8>,
+        chain_id: u8,
+        sender: Option<AccountAddress>,
+        replay_protector: ReplayProtectorKind,
+        publish_target: Option<AccountAddress>,
+        fee_payer: Option<AccountAddress>,
+    ) -> Self {
         Self {
             script_hash,
             chain_id,
+            sender,
+            replay_protector,
+            publish_target,
+            fee_payer,
         }
     }
 
     pub fn chain_id(&self) -> u8 {
         self.chain_id
     }
+
+    pub fn replay_protector(&self) -> ReplayProtectorKind {
+        self.replay_protector
+    }
+
+    pub fn publish_target(&self) -> Option<AccountAddress> {
+        self.publish_target
+    }
+
+    /// The account paying the gas of a sponsored transaction, `None` when the sender pays.
+    pub fn fee_payer(&self) -> Option<AccountAddress> {
+        self.fee_payer
+    }
 }
 
 /***************************************************************************************************
@@ -60,6 +99,116 @@ fn native_get_script_hash(
     )])
 }
 
+/***************************************************************************************************
+ * native fun get_sender
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+/// Abort code when the session is not executing a user transaction, so there is no sender.
+pub const ENO_TRANSACTION_SENDER: u64 = 1;
+
+#[derive(Clone, Debug)]
+pub struct GetSenderGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_get_sender(
+    gas_params: &GetSenderGasParameters,
+    context: &mut SafeNativeContext,
+    mut _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    context.charge(gas_params.base)?;
+
+    let transaction_context = context.extensions().get::<NativeTransactionContext>();
+
+    match transaction_context.sender {
+        Some(sender) => Ok(smallvec![Value::address(sender)]),
+        None => Err(SafeNativeError::Abort {
+            abort_code: ENO_TRANSACTION_SENDER,
+        }),
+    }
+}
+
+/***************************************************************************************************
+ * native fun get_replay_protector_kind
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Clone, Debug)]
+pub struct GetReplayProtectorKindGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_get_replay_protector_kind(
+    gas_params: &GetReplayProtectorKindGasParameters,
+    context: &mut SafeNativeContext,
+    mut _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    context.charge(gas_params.base)?;
+
+    let transaction_context = context.extensions().get::<NativeTransactionContext>();
+
+    Ok(smallvec![Value::u8(
+        transaction_context.replay_protector() as u8
+    )])
+}
+
+/***************************************************************************************************
+ * native fun get_publish_target
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Clone, Debug)]
+pub struct GetPublishTargetGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_get_publish_target(
+    gas_params: &GetPublishTargetGasParameters,
+    context: &mut SafeNativeContext,
+    mut _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    context.charge(gas_params.base)?;
+
+    let transaction_context = context.extensions().get::<NativeTransactionContext>();
+
+    Ok(smallvec![Value::vector_address(
+        transaction_context.publish_target()
+    )])
+}
+
+/***************************************************************************************************
+ * native fun get_fee_payer
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Clone, Debug)]
+pub struct GetFeePayerGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_get_fee_payer(
+    gas_params: &GetFeePayerGasParameters,
+    context: &mut SafeNativeContext,
+    mut _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    context.charge(gas_params.base)?;
+
+    let transaction_context = context.extensions().get::<NativeTransactionContext>();
+
+    Ok(smallvec![Value::vector_address(
+        transaction_context.fee_payer()
+    )])
+}
+
 /***************************************************************************************************
  * module
  *
@@ -67,22 +216,173 @@ fn native_get_script_hash(
 #[derive(Debug, Clone)]
 pub struct GasParameters {
     pub get_script_hash: GetScriptHashGasParameters,
+    pub get_sender: GetSenderGasParameters,
+    pub get_replay_protector_kind: GetReplayProtectorKindGasParameters,
+    pub get_publish_target: GetPublishTargetGasParameters,
+    pub get_fee_payer: GetFeePayerGasParameters,
+}
+
+/// Optional transaction context natives to register. Networks running an older framework,
+/// which does not declare some of these natives, can have them disabled.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TransactionContextCapabilities {
+    pub get_sender: bool,
+}
+
+impl TransactionContextCapabilities {
+    pub fn all() -> Self {
+        Self { get_sender: true }
+    }
+}
+
+impl Default for TransactionContextCapabilities {
+    fn default() -> Self {
+        Self::all()
+    }
 }
 
 pub fn make_all(
     gas_params: GasParameters,
+    capabilities: TransactionContextCapabilities,
     timed_features: TimedFeatures,
     features: Arc<Features>,
 ) -> impl Iterator<Item = (String, NativeFunction)> {
-    let natives = [(
-        "get_script_hash",
-        make_safe_native(
-            gas_params.get_script_hash,
-            timed_features,
-            features,
-            native_get_script_hash,
+    let mut natives = vec![
+        (
+            "get_script_hash",
+            make_safe_native(
+                gas_params.get_script_hash,
+                timed_features.clone(),
+                features.clone(),
+                native_get_script_hash,
+            ),
+        ),
+        (
+            "get_replay_protector_kind",
+            make_safe_native(
+                gas_params.get_replay_protector_kind,
+                timed_features.clone(),
+                features.clone(),
+                native_get_replay_protector_kind,
+            ),
+        ),
+        (
+            "get_publish_target",
+            make_safe_native(
+                gas_params.get_publish_target,
+                timed_features.clone(),
+                features.clone(),
+                native_get_publish_target,
+            ),
         ),
-    )];
+        (
+            "get_fee_payer",
+            make_safe_native(
+                gas_params.get_fee_payer,
+                timed_features.clone(),
+                features.clone(),
+                native_get_fee_payer,
+            ),
+        ),
+    ];
+
+    if capabilities.get_sender {
+        natives.push((
+            "get_sender",
+            make_safe_native(
+                gas_params.get_sender,
+                timed_features,
+                features,
+                native_get_sender,
+            ),
+        ));
+    }
 
     crate::natives::helpers::make_module_natives(natives)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn native_names(capabilities: TransactionContextCapabilities) -> Vec<String> {
+        let gas_params = GasParameters {
+            get_script_hash: GetScriptHashGasParameters { base: 0.into() },
+            get_sender: GetSenderGasParameters { base: 0.into() },
+            get_replay_protector_kind: GetReplayProtectorKindGasParameters { base: 0.into() },
+            get_publish_target: GetPublishTargetGasParameters { base: 0.into() },
+            get_fee_payer: GetFeePayerGasParameters { base: 0.into() },
+        };
+        make_all(
+            gas_params,
+            capabilities,
+            TimedFeatures::enable_all(),
+            Arc::new(Features::default()),
+        )
+        .map(|(name, _)| name)
+        .collect()
+    }
+
+    #[test]
+    fn test_get_sender_registration_follows_capabilities() {
+        let all = native_names(TransactionContextCapabilities::all());
+        let without_sender = native_names(TransactionContextCapabilities { get_sender: false });
+
+        assert_ne!(all, without_sender);
+        assert!(all.contains(&"get_sender".to_string()));
+        assert!(!without_sender.contains(&"get_sender".to_string()));
+        assert!(without_sender.contains(&"get_script_hash".to_string()));
+    }
+
+    #[test]
+    fn test_replay_protector_kind() {
+        let context = NativeTransactionContext::new(
+            vec![],
+            4,
+            None,
+            ReplayProtectorKind::SequenceNumber,
+            None,
+            None,
+        );
+        assert_eq!(
+            context.replay_protector(),
+            ReplayProtectorKind::SequenceNumber
+        );
+        assert_eq!(context.replay_protector() as u8, 0);
+
+        let context =
+            NativeTransactionContext::new(vec![], 4, None, ReplayProtectorKind::Nonce, None, None);
+        assert_eq!(context.replay_protector(), ReplayProtectorKind::Nonce);
+        assert_eq!(context.replay_protector() as u8, 1);
+    }
+
+    #[test]
+    fn test_fee_payer() {
+        let sender = AccountAddress::from_hex_literal("0xa").unwrap();
+        let sponsor = AccountAddress::from_hex_literal("0xb").unwrap();
+
+        // The sender pays its own gas
+        let context = NativeTransactionContext::new(
+            vec![],
+            4,
+            Some(sender),
+            ReplayProtectorKind::SequenceNumber,
+            None,
+            None,
+        );
+        assert_eq!(context.fee_payer(), None);
+        assert!(native_names(TransactionContextCapabilities::all())
+            .contains(&"get_fee_payer".to_string()));
+
+        // Sponsored transaction
+        let context = NativeTransactionContext::new(
+            vec![],
+            4,
+            Some(sender),
+            ReplayProtectorKind::SequenceNumber,
+            None,
+            Some(sponsor),
+        );
+        assert_eq!(context.fee_payer(), Some(sponsor));
+    }
+}
@@ -5,7 +5,7 @@
 use aptos_framework::path_in_crate;
 use aptos_gas::{AbstractValueSizeGasParameters, NativeGasParameters, LATEST_GAS_FEATURE_VERSION};
 use aptos_types::on_chain_config::{Features, TimedFeatures};
-use aptos_vm::natives;
+use aptos_vm::natives::{self, TransactionContextCapabilities};
 use move_cli::base::test::{run_move_unit_tests, UnitTestResult};
 use move_unit_test::UnitTestingConfig;
 use move_vm_runtime::native_functions::NativeFunctionTable;
@@ -42,6 +42,7 @@ pub fn aptos_test_natives() -> NativeFunctionTable {
         NativeGasParameters::zeros(),
         AbstractValueSizeGasParameters::zeros(),
         LATEST_GAS_FEATURE_VERSION,
+        TransactionContextCapabilities::all(),
         TimedFeatures::enable_all(),
         Arc::new(Features::default()),
     )
@@ -6,7 +6,7 @@ use aptos_types::{
     account_address::{create_resource_address, AccountAddress},
     on_chain_config::{Features, TimedFeatures},
 };
-use aptos_vm::natives;
+use aptos_vm::natives::{self, TransactionContextCapabilities};
 use move_cli::base::test::{run_move_unit_tests, UnitTestResult};
 use move_unit_test::UnitTestingConfig;
 use move_vm_runtime::native_functions::NativeFunctionTable;
@@ -54,6 +54,7 @@ pub fn aptos_test_natives() -> NativeFunctionTable {
         NativeGasParameters::zeros(),
         AbstractValueSizeGasParameters::zeros(),
         LATEST_GAS_FEATURE_VERSION,
+        TransactionContextCapabilities::all(),
         TimedFeatures::enable_all(),
         Arc::new(Features::default()),
     )
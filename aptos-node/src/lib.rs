@@ -416,7 +416,8 @@ pub fn setup_environment_and_start_node(
         &node_config,
         chain_id,
         &mut event_subscription_service,
-    );
+        None,
+    )?;
 
     // Start the peer monitoring service
     let peer_monitoring_service_runtime = services::start_peer_monitoring_service(
@@ -3,7 +3,7 @@
 
 use aptos_channels::{self, aptos_channel, message_queues::QueueStyle};
 use aptos_config::{
-    config::{NetworkConfig, NodeConfig},
+    config::{KeyProvider, NetworkConfig, NodeConfig},
     network_id::NetworkId,
 };
 use aptos_consensus::network_interface::{ConsensusMsg, DIRECT_SEND, RPC};
@@ -161,18 +161,21 @@ fn extract_network_configs_and_ids(
     (network_configs, network_ids)
 }
 
-/// Sets up all networks and returns the appropriate application network interfaces
+/// Sets up all networks and returns the appropriate application network interfaces. The
+/// `key_provider` fetches the identity keys of networks with an identity held in a KMS.
+/// Fails if such a key can't be fetched.
 pub fn setup_networks_and_get_interfaces(
     node_config: &NodeConfig,
     chain_id: ChainId,
     event_subscription_service: &mut EventSubscriptionService,
-) -> (
+    key_provider: Option<&dyn KeyProvider>,
+) -> anyhow::Result<(
     Vec<Runtime>,
     Option<ApplicationNetworkInterfaces<ConsensusMsg>>,
     ApplicationNetworkInterfaces<MempoolSyncMsg>,
     ApplicationNetworkInterfaces<PeerMonitoringServiceMessage>,
     ApplicationNetworkInterfaces<StorageServiceMessage>,
-) {
+)> {
     // Gather all network configs and network ids
     let (network_configs, network_ids) = extract_network_configs_and_ids(node_config);
 
@@ -200,7 +203,8 @@ pub fn setup_networks_and_get_interfaces(
             TimeService::real(),
             Some(event_subscription_service),
             peers_and_metadata.clone(),
-        );
+            key_provider,
+        )?;
 
         // Register consensus (both client and server) with the network
         let network_id = network_config.network_id;
@@ -266,13 +270,13 @@ pub fn setup_networks_and_get_interfaces(
         peers_and_metadata,
     );
 
-    (
+    Ok((
         network_runtimes,
         consensus_interfaces,
         mempool_interfaces,
         peer_monitoring_service_interfaces,
         storage_service_interfaces,
-    )
+    ))
 }
 
 /// Creates a network runtime for the given network config
@@ -2,14 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::network;
-use aptos_config::config::{NodeConfig, WaypointConfig};
+use aptos_config::config::{Error, Identity, KeyProvider, NodeConfig, WaypointConfig};
+use aptos_crypto::{x25519, Uniform};
 use aptos_event_notifications::EventSubscriptionService;
 use aptos_infallible::RwLock;
 use aptos_storage_interface::{DbReader, DbReaderWriter, DbWriter};
 use aptos_temppath::TempPath;
 use aptos_types::{
-    chain_id::ChainId, on_chain_config::ON_CHAIN_CONFIG_REGISTRY, waypoint::Waypoint,
+    chain_id::ChainId, on_chain_config::ON_CHAIN_CONFIG_REGISTRY, waypoint::Waypoint, PeerId,
 };
+use rand::{rngs::StdRng, SeedableRng};
 use std::sync::Arc;
 
 /// A mock database implementing DbReader and DbWriter
@@ -41,9 +43,53 @@ fn test_mutual_authentication_validators() {
         &node_config,
         ChainId::test(),
         &mut event_subscription_service,
+        None,
     );
 }
 
+#[test]
+fn test_kms_identity_key_provider() {
+    /// Provider serving a single key, generated from a fixed seed.
+    struct MockKeyProvider;
+
+    impl KeyProvider for MockKeyProvider {
+        fn fetch_key(&self, key_id: &str) -> Result<x25519::PrivateKey, Error> {
+            if key_id != "fullnode-key" {
+                return Err(Error::InvariantViolation(format!("Unknown key {}", key_id)));
+            }
+            Ok(x25519::PrivateKey::generate(&mut StdRng::from_seed(
+                [7u8; 32],
+            )))
+        }
+    }
+
+    // Create a public full node config, with its identity held in a KMS
+    let temp_path = TempPath::new();
+    let mut node_config = NodeConfig::default_for_public_full_node();
+    node_config.set_data_dir(temp_path.path().to_path_buf());
+    node_config.randomize_ports();
+    node_config.full_node_networks[0].identity =
+        Identity::from_kms("fullnode-key".into(), PeerId::random());
+
+    let mut event_subscription_service = EventSubscriptionService::new(
+        ON_CHAIN_CONFIG_REGISTRY,
+        Arc::new(RwLock::new(DbReaderWriter::new(MockDatabase {}))),
+    );
+    let mut setup_networks = |key_provider: Option<&dyn KeyProvider>| {
+        network::setup_networks_and_get_interfaces(
+            &node_config,
+            ChainId::test(),
+            &mut event_subscription_service,
+            key_provider,
+        )
+        .map(|_| ())
+    };
+
+    // The networks only start if the key can be fetched
+    assert!(setup_networks(None).is_err());
+    assert!(setup_networks(Some(&MockKeyProvider)).is_ok());
+}
+
 #[cfg(feature = "check-vm-features")]
 #[test]
 fn test_aptos_vm_does_not_have_test_natives() {
@@ -53,6 +53,12 @@ pub fn set_aptos_vm_configurations(node_config: &NodeConfig) {
     AptosVM::set_num_proof_reading_threads_once(
         node_config.execution.num_proof_reading_threads as usize,
     );
+    AptosVM::set_get_sender_native_disabled_chain_ids(
+        node_config
+            .execution
+            .get_sender_native_disabled_chain_ids
+            .clone(),
+    );
 
     if node_config
         .execution
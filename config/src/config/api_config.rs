@@ -2,9 +2,10 @@
 // Parts of the project are originally copyright © Meta Platforms, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::utils;
+use crate::{config::Error, utils};
+use aptos_types::transaction::TransactionPayload;
 use serde::{Deserialize, Serialize};
-use std::net::SocketAddr;
+use std::{collections::HashSet, net::SocketAddr};
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default, deny_unknown_fields)]
@@ -46,6 +47,30 @@ pub struct ApiConfig {
     // Performance functionality
     pub max_runtime_workers: Option<usize>, // The maximum number of workers to use for the API runtime
     pub runtime_worker_multiplier: usize, // If max_runtime_workers is None, use runtime_worker_multiplier * num CPU cores
+
+    /// Payload types accepted for submission, all types are accepted if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_payload_types: Option<Vec<PayloadTypeFilter>>,
+}
+
+/// Transaction payload types, to restrict the transactions accepted for submission.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum PayloadTypeFilter {
+    Script,
+    ModuleBundle,
+    EntryFunction,
+    Multisig,
+}
+
+impl PayloadTypeFilter {
+    pub fn of(payload: &TransactionPayload) -> Self {
+        match payload {
+            TransactionPayload::Script(_) => PayloadTypeFilter::Script,
+            TransactionPayload::ModuleBundle(_) => PayloadTypeFilter::ModuleBundle,
+            TransactionPayload::EntryFunction(_) => PayloadTypeFilter::EntryFunction,
+            TransactionPayload::Multisig(_) => PayloadTypeFilter::Multisig,
+        }
+    }
 }
 
 pub const DEFAULT_ADDRESS: &str = "127.0.0.1";
@@ -89,6 +114,7 @@ impl Default for ApiConfig {
             max_gas_view_function: DEFAULT_MAX_VIEW_GAS,
             max_runtime_workers: None,
             runtime_worker_multiplier: 2,
+            allowed_payload_types: None,
         }
     }
 }
@@ -104,4 +130,29 @@ impl ApiConfig {
             None => DEFAULT_REQUEST_CONTENT_LENGTH_LIMIT,
         }
     }
+
+    /// Whether transactions with the given payload can be submitted through the API.
+    pub fn is_payload_allowed(&self, payload: &TransactionPayload) -> bool {
+        match &self.allowed_payload_types {
+            Some(allowed_payload_types) => {
+                allowed_payload_types.contains(&PayloadTypeFilter::of(payload))
+            },
+            None => true,
+        }
+    }
+
+    pub fn verify_allowed_payload_types(&self) -> Result<(), Error> {
+        if let Some(allowed_payload_types) = &self.allowed_payload_types {
+            let mut seen = HashSet::new();
+            for payload_type in allowed_payload_types {
+                if !seen.insert(payload_type) {
+                    return Err(Error::InvariantViolation(format!(
+                        "Payload type {:?} is listed more than once in allowed_payload_types",
+                        payload_type
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
 }
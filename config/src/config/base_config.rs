@@ -16,6 +16,9 @@ pub struct BaseConfig {
     pub working_dir: Option<PathBuf>,
     pub role: RoleType,
     pub waypoint: WaypointConfig,
+    /// Flags the node as being in maintenance mode. Not acted on by the node yet, so it still
+    /// participates in consensus, and the API still accepts writes.
+    pub maintenance_mode: bool,
 }
 
 impl Default for BaseConfig {
@@ -25,6 +28,7 @@ impl Default for BaseConfig {
             working_dir: None,
             role: RoleType::Validator,
             waypoint: WaypointConfig::None,
+            maintenance_mode: false,
         }
     }
 }
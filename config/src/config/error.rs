@@ -14,6 +14,9 @@ pub enum Error {
     BCS(&'static str, #[source] bcs::Error),
     #[error("Error (de)serializing {0}: {1}")]
     Yaml(String, #[source] serde_yaml::Error),
+    #[cfg(feature = "toml")]
+    #[error("Error deserializing {0}: {1}")]
+    Toml(String, #[source] toml::de::Error),
     #[error("Config is missing expected value: {0}")]
     Missing(&'static str),
     #[error("Unexpected error: {0}")]
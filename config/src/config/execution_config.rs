@@ -8,11 +8,16 @@ use serde::{Deserialize, Serialize};
 use std::{
     fs::File,
     io::{Read, Write},
+    ops::RangeInclusive,
     path::PathBuf,
 };
 
 const GENESIS_DEFAULT: &str = "genesis.blob";
 
+/// Gas feature versions the VM knows about, that an override may pin.
+/// The upper bound must be kept in sync with `aptos_gas::LATEST_GAS_FEATURE_VERSION`.
+pub const GAS_FEATURE_VERSIONS: RangeInclusive<u64> = 0..=9;
+
 #[derive(Clone, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct ExecutionConfig {
@@ -24,6 +29,12 @@ pub struct ExecutionConfig {
     pub paranoid_type_verification: bool,
     pub paranoid_hot_potato_verification: bool,
     pub processed_transactions_detailed_counters: bool,
+    /// Chain ids of networks whose framework does not declare the
+    /// transaction_context::get_sender native, so it must not be registered
+    pub get_sender_native_disabled_chain_ids: Vec<u8>,
+    /// Gas feature version to use instead of the one of the on-chain gas schedule,
+    /// for test networks that need to pin an older gas schedule version
+    pub gas_feature_version_override: Option<u64>,
 }
 
 impl std::fmt::Debug for ExecutionConfig {
@@ -53,12 +64,16 @@ impl Default for ExecutionConfig {
             paranoid_type_verification: true,
             paranoid_hot_potato_verification: true,
             processed_transactions_detailed_counters: false,
+            get_sender_native_disabled_chain_ids: vec![],
+            gas_feature_version_override: None,
         }
     }
 }
 
 impl ExecutionConfig {
     pub fn load(&mut self, root_dir: &RootPath) -> Result<(), Error> {
+        self.verify_gas_feature_version_override()?;
+
         if !self.genesis_file_location.as_os_str().is_empty() {
             // Ensure the genesis file exists
             let genesis_path = root_dir.full_path(&self.genesis_file_location);
@@ -100,6 +115,22 @@ impl ExecutionConfig {
         Ok(())
     }
 
+    pub fn gas_feature_version_override(&self) -> Option<u64> {
+        self.gas_feature_version_override
+    }
+
+    fn verify_gas_feature_version_override(&self) -> Result<(), Error> {
+        match self.gas_feature_version_override {
+            Some(version) if !GAS_FEATURE_VERSIONS.contains(&version) => {
+                Err(Error::InvariantViolation(format!(
+                    "Gas feature version override {} is not within the known versions {:?}",
+                    version, GAS_FEATURE_VERSIONS
+                )))
+            },
+            _ => Ok(()),
+        }
+    }
+
     pub fn save(&mut self, root_dir: &RootPath) -> Result<(), Error> {
         if let Some(genesis) = &self.genesis {
             if self.genesis_file_location.as_os_str().is_empty() {
@@ -157,6 +188,26 @@ mod test {
         assert_eq!(config.genesis, Some(fake_genesis));
     }
 
+    #[test]
+    fn test_gas_feature_version_override() {
+        let (mut config, path) = generate_config();
+        let root_dir = RootPath::new_path(path.path());
+        assert_eq!(config.gas_feature_version_override(), None);
+
+        config.gas_feature_version_override = Some(*GAS_FEATURE_VERSIONS.end());
+        config.load(&root_dir).unwrap();
+        assert_eq!(
+            config.gas_feature_version_override(),
+            Some(*GAS_FEATURE_VERSIONS.end())
+        );
+
+        config.gas_feature_version_override = Some(GAS_FEATURE_VERSIONS.end() + 1);
+        assert!(matches!(
+            config.load(&root_dir),
+            Err(Error::InvariantViolation(_))
+        ));
+    }
+
     fn generate_config() -> (ExecutionConfig, TempPath) {
         let temp_dir = TempPath::new();
         temp_dir.create_as_dir().expect("error creating tempdir");
@@ -1,6 +1,7 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::config::Error;
 use aptos_crypto::{bls12381, ed25519::Ed25519PrivateKey, x25519};
 use aptos_types::account_address::AccountAddress;
 use serde::{Deserialize, Serialize};
@@ -32,3 +33,10 @@ impl IdentityBlob {
         Ok(file.write_all(serde_yaml::to_string(self)?.as_bytes())?)
     }
 }
+
+/// Fetches identity keys held outside of the node (e.g. in a KMS), referenced by
+/// `Identity::FromKms`. Keys are fetched when the network is started, and are never
+/// stored in the config.
+pub trait KeyProvider: Send + Sync {
+    fn fetch_key(&self, key_id: &str) -> Result<x25519::PrivateKey, Error>;
+}
@@ -33,6 +33,13 @@ pub struct IndexerConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub starting_version: Option<u64>,
 
+    /// The processors to run, each with its own starting version. Replaces `processor` and
+    /// `starting_version`, which are mapped to a single processor if this is not set.
+    /// The starting version of a processor can be set with the `STARTING_VERSION_<NAME>`
+    /// env var, ex: `STARTING_VERSION_TOKEN_PROCESSOR`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processors: Option<Vec<ProcessorSpec>>,
+
     ///////////////////
     ///////////////////
     ///////////////////
@@ -76,6 +83,35 @@ pub struct IndexerConfig {
     pub nft_points_contract: Option<String>,
 }
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessorSpec {
+    /// The processor to run, ex: "token_processor"
+    pub name: String,
+
+    /// If set, will ignore database contents and start processing from the specified version
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starting_version: Option<u64>,
+}
+
+impl IndexerConfig {
+    /// The processors to run, either as configured in `processors`, or as the single
+    /// processor configured by `processor` and `starting_version`.
+    pub fn processor_specs(&self) -> Vec<ProcessorSpec> {
+        match &self.processors {
+            Some(processors) => processors.clone(),
+            None => self
+                .processor
+                .iter()
+                .map(|name| ProcessorSpec {
+                    name: name.clone(),
+                    starting_version: self.starting_version,
+                })
+                .collect(),
+        }
+    }
+}
+
 impl Debug for IndexerConfig {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let postgres_uri = self.postgres_uri.as_ref().map(|u| {
@@ -90,6 +126,7 @@ impl Debug for IndexerConfig {
             .field("postgres_uri", &postgres_uri)
             .field("processor", &self.processor)
             .field("starting_version", &self.starting_version)
+            .field("processors", &self.processors)
             .field("skip_migrations", &self.skip_migrations)
             .field("check_chain_id", &self.check_chain_id)
             .field("batch_size", &self.batch_size)
@@ -121,6 +158,22 @@ pub fn env_or_default<T: std::str::FromStr>(
     }
 }
 
+/// Reads a starting version from the given env var, falling back to `default` if the var is
+/// not set or invalid.
+pub fn starting_version_from_env(env_var: &str, default: Option<u64>) -> Option<u64> {
+    match std::env::var(env_var).ok() {
+        None => default,
+        Some(s) => match s.parse::<u64>() {
+            Ok(version) => Some(version),
+            Err(_) => {
+                // Doing this instead of failing. This will allow a processor to have STARTING_VERSION: undefined when deploying
+                aptos_logger::warn!("Invalid {}: {}, using {:?} instead", env_var, s, default);
+                default
+            },
+        },
+    }
+}
+
 pub fn default_if_zero_u8(value: Option<u8>, default: u8) -> Option<u8> {
     default_if_zero(value.map(|v| v as u64), default as u64).map(|v| v as u8)
 }
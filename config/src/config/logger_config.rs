@@ -2,11 +2,12 @@
 // Parts of the project are originally copyright © Meta Platforms, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::utils;
+use crate::{config::Error, utils};
 use aptos_logger::{Level, CHANNEL_SIZE};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct LoggerConfig {
     // channel size for the asynchronous channel for node logging.
@@ -17,6 +18,8 @@ pub struct LoggerConfig {
     pub is_async: bool,
     // The default logging level for slog.
     pub level: Level,
+    // Logging levels overriding the default one for specific targets (e.g., mempool: debug)
+    pub target_levels: Option<HashMap<String, String>>,
     // tokio-console port
     pub console_port: Option<u16>,
     pub enable_telemetry_remote_log: bool,
@@ -31,6 +34,7 @@ impl Default for LoggerConfig {
             enable_backtrace: false,
             is_async: true,
             level: Level::Info,
+            target_levels: None,
             // This is the default port used by tokio-console
             // setting console_port to None will disable tokio console even if aptos-console
             // feature is enabled
@@ -43,6 +47,22 @@ impl Default for LoggerConfig {
 }
 
 impl LoggerConfig {
+    /// Parses the per-target logging levels, for the logger to filter on.
+    /// Fails if any of the levels isn't a known logging level.
+    pub fn parse_target_levels(&self) -> Result<BTreeMap<String, Level>, Error> {
+        let mut target_levels = BTreeMap::new();
+        for (target, level) in self.target_levels.iter().flatten() {
+            let level = level.parse::<Level>().map_err(|_| {
+                Error::InvariantViolation(format!(
+                    "Unknown logging level {:?} for target {:?}!",
+                    level, target
+                ))
+            })?;
+            target_levels.insert(target.clone(), level);
+        }
+        Ok(target_levels)
+    }
+
     pub fn disable_console(&mut self) {
         self.console_port = None;
     }
@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    config::{Error, IdentityBlob, SecureBackend},
+    config::{Error, IdentityBlob, KeyProvider, SecureBackend},
     keys::ConfigKey,
     network_id::NetworkId,
     utils,
@@ -25,6 +25,7 @@ use std::{
     convert::TryFrom,
     fmt,
     path::PathBuf,
+    str::FromStr,
     string::ToString,
 };
 
@@ -113,6 +114,10 @@ pub struct NetworkConfig {
     pub outbound_rate_limit_config: Option<RateLimitConfig>,
     // The maximum size of an inbound or outbound message (it may be divided into multiple frame)
     pub max_message_size: usize,
+    // Peers (by peer id or network address) allowed to connect. If set, all others are rejected
+    pub seed_peers_allowlist: Option<Vec<String>>,
+    // Peers (by peer id or network address) that are never allowed to connect
+    pub seed_peers_denylist: Option<Vec<String>>,
 }
 
 impl Default for NetworkConfig {
@@ -153,6 +158,8 @@ impl NetworkConfig {
             inbound_tx_buffer_size_bytes: Some(INBOUND_TCP_TX_BUFFER_SIZE),
             outbound_rx_buffer_size_bytes: Some(OUTBOUND_TCP_RX_BUFFER_SIZE),
             outbound_tx_buffer_size_bytes: Some(OUTBOUND_TCP_TX_BUFFER_SIZE),
+            seed_peers_allowlist: None,
+            seed_peers_denylist: None,
         };
         config.prepare_identity();
         config
@@ -176,11 +183,53 @@ impl NetworkConfig {
                 let identity_blob: IdentityBlob = IdentityBlob::from_file(&config.path).unwrap();
                 Some(identity_blob.network_private_key)
             },
+            // Only fetched when the network is started, see `resolve_identity_key`
+            Identity::FromKms(_) => None,
             Identity::None => None,
         };
         key.expect("identity key should be present")
     }
 
+    /// Identity key, fetched through `key_provider` if it's held in a KMS. The fetched key
+    /// is not stored in the config, so it's never written out when the config is saved.
+    pub fn resolve_identity_key(
+        &self,
+        key_provider: Option<&dyn KeyProvider>,
+    ) -> Result<x25519::PrivateKey, Error> {
+        match &self.identity {
+            Identity::FromKms(config) => {
+                let key_provider = key_provider.ok_or_else(|| {
+                    Error::InvariantViolation(format!(
+                        "The {} network identity is held in a KMS, but no key provider is set",
+                        self.network_id
+                    ))
+                })?;
+                key_provider.fetch_key(&config.key_id)
+            },
+            _ => Ok(self.identity_key()),
+        }
+    }
+
+    /// Public identity key, if the identity is held in the config or in a file, or None if
+    /// it is only resolved when the network starts. Unlike `identity_key`, an identity file
+    /// that can't be read is reported as an error.
+    pub fn config_identity_public_key(&self) -> Result<Option<x25519::PublicKey>, Error> {
+        match &self.identity {
+            Identity::FromConfig(config) => Ok(Some(config.key.public_key())),
+            Identity::FromFile(config) => {
+                let identity_blob = IdentityBlob::from_file(&config.path).map_err(|e| {
+                    Error::Unexpected(format!(
+                        "Unable to read identity file {}: {}",
+                        config.path.display(),
+                        e
+                    ))
+                })?;
+                Ok(Some(identity_blob.network_private_key.public_key()))
+            },
+            Identity::FromStorage(_) | Identity::FromKms(_) | Identity::None => Ok(None),
+        }
+    }
+
     pub fn identity_from_storage(&self) -> IdentityFromStorage {
         if let Identity::FromStorage(identity) = self.identity.clone() {
             identity
@@ -225,10 +274,25 @@ impl NetworkConfig {
                 .ok_or_else(|| Error::InvariantViolation("No local IP".to_string()))?;
         }
 
+        self.verify_seed_peer_filters()?;
+        self.verify_identity_fetchable()?;
         self.prepare_identity();
         Ok(())
     }
 
+    /// The node has no `KeyProvider` to fetch identity keys held in a KMS with yet, so these
+    /// are only usable by callers passing their own provider when starting the network.
+    fn verify_identity_fetchable(&self) -> Result<(), Error> {
+        if let Identity::FromKms(config) = &self.identity {
+            return Err(Error::InvariantViolation(format!(
+                "The {} network identity is held in a KMS (key {}), but the node has no key \
+                provider to fetch it with!",
+                self.network_id, config.key_id
+            )));
+        }
+        Ok(())
+    }
+
     pub fn peer_id(&self) -> PeerId {
         match &self.identity {
             Identity::FromConfig(config) => Some(config.peer_id),
@@ -252,6 +316,7 @@ impl NetworkConfig {
                     ))
                 }
             },
+            Identity::FromKms(config) => Some(config.peer_id),
             Identity::None => None,
         }
         .expect("peer id should be present")
@@ -272,6 +337,7 @@ impl NetworkConfig {
                 }
             },
             Identity::FromFile(_) => (),
+            Identity::FromKms(_) => (),
         };
     }
 
@@ -302,6 +368,46 @@ impl NetworkConfig {
         )
     }
 
+    /// Returns the parsed `seed_peers_allowlist`, or None if no allowlist is set
+    pub fn seed_peers_allowlist(&self) -> Result<Option<HashSet<PeerFilter>>, Error> {
+        Self::parse_peer_filters(&self.seed_peers_allowlist)
+    }
+
+    /// Returns the parsed `seed_peers_denylist`, or None if no denylist is set
+    pub fn seed_peers_denylist(&self) -> Result<Option<HashSet<PeerFilter>>, Error> {
+        Self::parse_peer_filters(&self.seed_peers_denylist)
+    }
+
+    fn parse_peer_filters(
+        peer_filters: &Option<Vec<String>>,
+    ) -> Result<Option<HashSet<PeerFilter>>, Error> {
+        peer_filters
+            .as_ref()
+            .map(|peer_filters| {
+                peer_filters
+                    .iter()
+                    .map(|peer_filter| peer_filter.parse())
+                    .collect()
+            })
+            .transpose()
+    }
+
+    // Verifies that all entries of the seed peer allowlist and denylist parse,
+    // and that no peer is in both of them
+    pub fn verify_seed_peer_filters(&self) -> Result<(), Error> {
+        let allowlist = self.seed_peers_allowlist()?;
+        let denylist = self.seed_peers_denylist()?;
+        if let (Some(allowlist), Some(denylist)) = (allowlist, denylist) {
+            if let Some(peer_filter) = allowlist.intersection(&denylist).next() {
+                return Err(Error::InvariantViolation(format!(
+                    "Peer {} is in both the seed peers allowlist and denylist",
+                    peer_filter
+                )));
+            }
+        }
+        Ok(())
+    }
+
     // Verifies both the `seed_addrs` and `seeds` before they're merged
     pub fn verify_seeds(&self) -> Result<(), Error> {
         for (peer_id, addrs) in self.seed_addrs.iter() {
@@ -325,6 +431,49 @@ impl NetworkConfig {
     }
 }
 
+/// An entry of the seed peer allowlist or denylist, identifying a peer
+/// either by its peer id, or by a network address (starting with `/`).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum PeerFilter {
+    PeerId(PeerId),
+    Address(NetworkAddress),
+}
+
+impl FromStr for PeerFilter {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        if s.starts_with('/') {
+            NetworkAddress::from_str(s)
+                .map(PeerFilter::Address)
+                .map_err(|error| {
+                    Error::InvariantViolation(format!(
+                        "Invalid network address in seed peer filters: '{}': {}",
+                        s, error
+                    ))
+                })
+        } else {
+            PeerId::from_str(s)
+                .map(PeerFilter::PeerId)
+                .map_err(|error| {
+                    Error::InvariantViolation(format!(
+                        "Invalid peer id in seed peer filters: '{}': {}",
+                        s, error
+                    ))
+                })
+        }
+    }
+}
+
+impl fmt::Display for PeerFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PeerFilter::PeerId(peer_id) => write!(f, "{}", peer_id),
+            PeerFilter::Address(address) => write!(f, "{}", address),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct PeerMonitoringServiceConfig {
@@ -436,6 +585,7 @@ pub enum Identity {
     FromConfig(IdentityFromConfig),
     FromStorage(IdentityFromStorage),
     FromFile(IdentityFromFile),
+    FromKms(IdentityFromKms),
     None,
 }
 
@@ -456,6 +606,10 @@ impl Identity {
     pub fn from_file(path: PathBuf) -> Self {
         Identity::FromFile(IdentityFromFile { path })
     }
+
+    pub fn from_kms(key_id: String, peer_id: PeerId) -> Self {
+        Identity::FromKms(IdentityFromKms { key_id, peer_id })
+    }
 }
 
 /// The identity is stored within the config.
@@ -482,6 +636,17 @@ pub struct IdentityFromFile {
     pub path: PathBuf,
 }
 
+/// The identity key is held in an external KMS, and fetched through a `KeyProvider` when
+/// the network is started. Rejected when loading the config of the node, which has no
+/// provider yet.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct IdentityFromKms {
+    pub key_id: String,
+    /// Can't be derived from the key, as the key isn't available from the config
+    pub peer_id: PeerId,
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct RateLimitConfig {
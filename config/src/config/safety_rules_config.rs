@@ -44,6 +44,14 @@ impl Default for SafetyRulesConfig {
 }
 
 impl SafetyRulesConfig {
+    /// Returns true iff the config carries settings that only a validator can make use of,
+    /// i.e., an initial identity, test keys or a remote safety rules service.
+    pub fn has_validator_settings(&self) -> bool {
+        self.initial_safety_rules_config != InitialSafetyRulesConfig::None
+            || self.test.is_some()
+            || matches!(self.service, SafetyRulesService::Process(_))
+    }
+
     pub fn set_data_dir(&mut self, data_dir: PathBuf) {
         if let SecureBackend::OnDiskStorage(backend) = &mut self.backend {
             backend.set_data_dir(data_dir);
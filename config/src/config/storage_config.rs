@@ -2,7 +2,7 @@
 // Parts of the project are originally copyright © Meta Platforms, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::utils;
+use crate::{config::Error, utils};
 use serde::{Deserialize, Serialize};
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr},
@@ -14,6 +14,9 @@ pub const DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD: usize = 1 << 13;
 
 pub const BUFFERED_STATE_TARGET_ITEMS: usize = 100_000;
 
+// A state cache smaller than this is too small to be useful, and most likely a misconfiguration.
+pub const MIN_STATE_CACHE_BYTES: usize = 64 << 20;
+
 /// Port selected RocksDB options for tuning underlying rocksdb instance of AptosDB.
 /// see <https://github.com/facebook/rocksdb/blob/master/include/rocksdb/options.h>
 /// for detailed explanations.
@@ -101,6 +104,9 @@ pub struct StorageConfig {
     /// since genesis. To recover operation after data loss, or to bootstrap a node in fast sync
     /// mode, the indexer db needs to be copied in from another node.
     pub enable_indexer: bool,
+    /// Upper bound of the memory used by the in-memory state cache, in bytes. If not set, the
+    /// cache size is not bounded explicitly. Must be at least `MIN_STATE_CACHE_BYTES`.
+    pub state_cache_bytes: Option<usize>,
 }
 
 pub const NO_OP_STORAGE_PRUNER_CONFIG: PrunerConfig = PrunerConfig {
@@ -184,6 +190,41 @@ pub struct PrunerConfig {
     pub epoch_snapshot_pruner_config: EpochSnapshotPrunerConfig,
 }
 
+impl PrunerConfig {
+    /// Checks that no enabled pruner keeps a state snapshot that the others prune the data
+    /// around: the state merkle window must fit in both the ledger and the epoch snapshot
+    /// windows, otherwise there are versions with a state, but without the transactions
+    /// (or the epoch ending snapshots) leading to it.
+    pub fn verify_prune_windows(&self) -> Result<(), Error> {
+        let state_merkle_pruner_config = &self.state_merkle_pruner_config;
+        if !state_merkle_pruner_config.enable {
+            return Ok(());
+        }
+
+        let ledger_pruner_config = &self.ledger_pruner_config;
+        if ledger_pruner_config.enable
+            && ledger_pruner_config.prune_window < state_merkle_pruner_config.prune_window
+        {
+            return Err(Error::InvariantViolation(format!(
+                "The ledger prune window ({}) is smaller than the state merkle prune window ({})!",
+                ledger_pruner_config.prune_window, state_merkle_pruner_config.prune_window,
+            )));
+        }
+
+        let epoch_snapshot_pruner_config = &self.epoch_snapshot_pruner_config;
+        if epoch_snapshot_pruner_config.enable
+            && epoch_snapshot_pruner_config.prune_window < state_merkle_pruner_config.prune_window
+        {
+            return Err(Error::InvariantViolation(format!(
+                "The epoch snapshot prune window ({}) is smaller than the state merkle prune \
+                window ({})!",
+                epoch_snapshot_pruner_config.prune_window, state_merkle_pruner_config.prune_window,
+            )));
+        }
+        Ok(())
+    }
+}
+
 impl Default for LedgerPrunerConfig {
     fn default() -> Self {
         LedgerPrunerConfig {
@@ -249,6 +290,7 @@ impl Default for StorageConfig {
             enable_indexer: false,
             buffered_state_target_items: BUFFERED_STATE_TARGET_ITEMS,
             max_num_nodes_per_lru_cache_shard: DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+            state_cache_bytes: None,
         }
     }
 }
@@ -270,6 +312,16 @@ impl StorageConfig {
         self.backup_service_address
             .set_port(utils::get_available_port());
     }
+
+    pub fn state_cache_bytes(&self) -> Option<usize> {
+        self.state_cache_bytes
+    }
+
+    /// Returns true if the state cache cap is larger than the given amount of memory.
+    pub fn state_cache_exceeds_memory(&self, total_memory_bytes: u64) -> bool {
+        self.state_cache_bytes
+            .map_or(false, |bytes| bytes as u64 > total_memory_bytes)
+    }
 }
 
 #[cfg(test)]
@@ -99,6 +99,11 @@ impl TestConfig {
     pub fn temp_dir(&self) -> Option<&Path> {
         self.temp_dir.as_ref().map(|temp_dir| temp_dir.path())
     }
+
+    /// Takes over the temp dir of `other`, so that it outlives `other`
+    pub(crate) fn take_temp_dir(&mut self, other: &mut TestConfig) {
+        self.temp_dir = other.temp_dir.take();
+    }
 }
 
 #[cfg(test)]
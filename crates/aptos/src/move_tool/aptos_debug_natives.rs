@@ -3,7 +3,7 @@
 
 use aptos_gas::{AbstractValueSizeGasParameters, NativeGasParameters, LATEST_GAS_FEATURE_VERSION};
 use aptos_types::on_chain_config::{Features, TimedFeatures};
-use aptos_vm::natives;
+use aptos_vm::natives::{self, TransactionContextCapabilities};
 use move_vm_runtime::native_functions::NativeFunctionTable;
 use std::sync::Arc;
 
@@ -20,6 +20,7 @@ pub fn aptos_debug_natives(
         gas_parameters,
         abs_val_size_gas_params,
         LATEST_GAS_FEATURE_VERSION,
+        TransactionContextCapabilities::all(),
         TimedFeatures::enable_all(),
         Arc::new(Features::default()),
     )
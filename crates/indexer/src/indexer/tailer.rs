@@ -30,6 +30,14 @@ use tokio::{sync::Mutex, task::JoinHandle};
 
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!();
 
+pub fn run_migrations(connection_pool: &PgDbPool) {
+    let _ = connection_pool
+        .get()
+        .expect("Could not get connection for migrations")
+        .run_pending_migrations(MIGRATIONS)
+        .expect("migrations failed!");
+}
+
 #[derive(Clone)]
 pub struct Tailer {
     pub transaction_fetcher: Arc<Mutex<dyn TransactionFetcherTrait>>,
@@ -55,12 +63,7 @@ impl Tailer {
     }
 
     pub fn run_migrations(&self) {
-        let _ = &self
-            .connection_pool
-            .get()
-            .expect("Could not get connection for migrations")
-            .run_pending_migrations(MIGRATIONS)
-            .expect("migrations failed!");
+        run_migrations(&self.connection_pool);
     }
 
     /// If chain id doesn't exist, save it. Otherwise, make sure that we're indexing the same chain
@@ -4,7 +4,9 @@
 use crate::{
     database::new_db_pool,
     indexer::{
-        fetcher::TransactionFetcherOptions, processing_result::ProcessingResult, tailer::Tailer,
+        fetcher::TransactionFetcherOptions,
+        processing_result::ProcessingResult,
+        tailer::{run_migrations, Tailer},
         transaction_processor::TransactionProcessor,
     },
     processors::{
@@ -86,12 +88,38 @@ pub fn bootstrap(
 
     let runtime = aptos_runtimes::spawn_named_runtime("indexer".into(), None);
 
-    let indexer_config = config.indexer.clone();
-    let node_config = config.clone();
+    // Every processor runs its own tailer
+    let tailers = config
+        .indexer
+        .processor_specs()
+        .into_iter()
+        .map(|processor| {
+            let mut indexer_config = config.indexer.clone();
+            indexer_config.processor = Some(processor.name);
+            indexer_config.starting_version = processor.starting_version;
+            // Run once below, as the migrations of concurrent tailers would race
+            indexer_config.skip_migrations = Some(true);
+            let context = Arc::new(Context::new(
+                chain_id,
+                db.clone(),
+                mp_sender.clone(),
+                config.clone(),
+            ));
+            (indexer_config, context)
+        })
+        .collect::<Vec<_>>();
 
+    let indexer_config = config.indexer.clone();
     runtime.spawn(async move {
-        let context = Arc::new(Context::new(chain_id, db, mp_sender, node_config));
-        run_forever(indexer_config, context).await;
+        if !indexer_config.skip_migrations.unwrap() {
+            info!("Running migrations...");
+            let conn_pool = new_db_pool(&indexer_config.postgres_uri.unwrap())
+                .expect("Failed to create connection pool");
+            run_migrations(&conn_pool);
+        }
+        for (indexer_config, context) in tailers {
+            tokio::spawn(run_forever(indexer_config, context));
+        }
     });
 
     Some(Ok(runtime))
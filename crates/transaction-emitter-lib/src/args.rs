@@ -108,10 +108,32 @@ pub enum TransactionTypeArg {
     AccountGeneration,
     AccountGenerationLargePool,
     NftMintAndTransfer,
+    NftDrop,
+    Marketplace,
     PublishPackage,
     CustomFunctionLargeModuleWorkingSet,
     CreateNewResource,
     NoOp,
+    VectorGrowth,
+    CustomCoinLifecycle,
+    MarkedEvents,
+    FlashLoan,
+    UniqueNoOp,
+    SharedCounter,
+    OracleUpdate,
+    ResourceGroupOps,
+    GasExhaustion,
+    ManySigners,
+    FrameworkUpgradeTransfer,
+    WideReadSet,
+    SelfTransfer,
+    ExpiringCoinTransfer,
+    IdenticalBurst,
+    CircularTransfer,
+    HeavyRead,
+    CreateEmptyAccount,
+    SkewedAccountSize,
+    DexSwap,
 }
 
 impl Default for TransactionTypeArg {
@@ -22,14 +22,17 @@ use aptos_sdk::{
     transaction_builder::{aptos_stdlib, TransactionFactory},
     types::{transaction::SignedTransaction, LocalAccount},
 };
-use aptos_transaction_generator_lib::{create_txn_generator_creator, TransactionType};
+use aptos_transaction_generator_lib::{
+    create_txn_generator_creator, gas_price_distribution::GasPriceDistribution,
+    type_counters::TransactionTypeCounters, MixMode, TransactionType,
+};
 use futures::future::{try_join_all, FutureExt};
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
 use rand::{rngs::StdRng, seq::IteratorRandom, Rng};
 use rand_core::SeedableRng;
 use std::{
     cmp::{max, min},
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
@@ -120,9 +123,11 @@ pub struct EmitJobRequest {
     mode: EmitJobMode,
 
     transaction_mix_per_phase: Vec<Vec<(TransactionType, usize)>>,
+    mix_mode: MixMode,
 
     max_gas_per_txn: u64,
     gas_price: u64,
+    gas_price_distribution: Option<GasPriceDistribution>,
     init_gas_price_multiplier: u64,
 
     reuse_accounts: bool,
@@ -150,8 +155,10 @@ impl Default for EmitJobRequest {
                 mempool_backlog: 3000,
             },
             transaction_mix_per_phase: vec![vec![(TransactionType::default(), 1)]],
+            mix_mode: MixMode::default(),
             max_gas_per_txn: aptos_global_constants::MAX_GAS_AMOUNT,
             gas_price: aptos_global_constants::GAS_UNIT_PRICE,
+            gas_price_distribution: None,
             init_gas_price_multiplier: 10,
             reuse_accounts: false,
             mint_to_root: false,
@@ -182,6 +189,15 @@ impl EmitJobRequest {
         self
     }
 
+    /// Sample gas unit price of each transaction from the distribution,
+    /// never going below `gas_price`.
+    /// Accounts are still funded based on `gas_price`, so `expected_gas_per_txn`
+    /// needs to account for the higher prices sampled.
+    pub fn gas_price_distribution(mut self, gas_price_distribution: GasPriceDistribution) -> Self {
+        self.gas_price_distribution = Some(gas_price_distribution);
+        self
+    }
+
     pub fn max_gas_per_txn(mut self, max_gas_per_txn: u64) -> Self {
         self.max_gas_per_txn = max_gas_per_txn;
         self
@@ -225,6 +241,13 @@ impl EmitJobRequest {
         self
     }
 
+    /// Exact splits every batch across the transaction types by weight,
+    /// instead of picking a single type per batch, at random by weight.
+    pub fn mix_mode(mut self, mix_mode: MixMode) -> Self {
+        self.mix_mode = mix_mode;
+        self
+    }
+
     pub fn get_num_phases(&self) -> usize {
         self.transaction_mix_per_phase.len()
     }
@@ -459,6 +482,7 @@ pub struct EmitJob {
     stop: Arc<AtomicBool>,
     stats: Arc<DynamicStatsTracking>,
     phase_starts: Vec<Instant>,
+    type_counters: TransactionTypeCounters,
 }
 
 impl EmitJob {
@@ -481,10 +505,19 @@ impl EmitJob {
                 .await
                 .expect("TxnEmitter worker thread failed");
         }
+        info!(
+            "Transactions generated per type: {:?}",
+            self.type_counters.snapshot()
+        );
 
         self.stats.accumulate(&self.phase_starts)
     }
 
+    /// Transactions generated so far, per transaction type of the mix.
+    pub fn transaction_type_counts(&self) -> BTreeMap<&'static str, u64> {
+        self.type_counters.snapshot()
+    }
+
     pub fn accumulate(&self) -> Vec<TxnStats> {
         self.stats.accumulate(&self.phase_starts)
     }
@@ -562,6 +595,7 @@ impl TxnEmitter {
             rest_clients: req.rest_clients.clone(),
             max_retries: init_retries,
             retry_after: req.init_retry_interval,
+            min_gas_unit_price: OnceCell::new(),
         };
         let mut all_accounts = account_minter
             .create_accounts(&txn_executor, &req, &mode_params, num_accounts)
@@ -570,16 +604,25 @@ impl TxnEmitter {
         let stats = Arc::new(DynamicStatsTracking::new(stats_tracking_phases));
         let tokio_handle = Handle::current();
 
-        let mut txn_generator_creator = create_txn_generator_creator(
+        let master_seed = self.rng.gen();
+        let (mut txn_generator_creator, type_counters, manifest) = create_txn_generator_creator(
             &req.transaction_mix_per_phase,
             num_workers,
             &mut all_accounts,
             &txn_executor,
             &txn_factory,
             &init_txn_factory,
+            req.gas_price_distribution,
             stats.get_cur_phase_obj(),
+            req.mix_mode,
+            master_seed,
+            None,
         )
-        .await;
+        .await?;
+        info!(
+            "Run manifest (can be passed in to recreate the generators): {}",
+            manifest.to_json()
+        );
 
         if !req.coordination_delay_between_instances.is_zero() {
             info!(
@@ -641,6 +684,7 @@ impl TxnEmitter {
             stop,
             stats,
             phase_starts: vec![Instant::now()],
+            type_counters,
         })
     }
 
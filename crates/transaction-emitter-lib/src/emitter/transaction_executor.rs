@@ -2,15 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::RETRY_POLICY;
-use anyhow::{Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
+use aptos_crypto::ed25519::Ed25519Signature;
 use aptos_logger::{debug, sample, sample::SampleRate, warn};
-use aptos_rest_client::Client as RestClient;
+use aptos_rest_client::{aptos_api_types::MoveModule, Client as RestClient};
 use aptos_sdk::{
-    move_types::account_address::AccountAddress, types::transaction::SignedTransaction,
+    move_types::{account_address::AccountAddress, language_storage::StructTag},
+    types::{
+        on_chain_config::GasScheduleV2,
+        transaction::{authenticator::TransactionAuthenticator, SignedTransaction},
+    },
+};
+use aptos_transaction_generator_lib::{
+    aptos_coin_struct_tag, CounterState, TransactionExecutor, TxnStatus,
 };
-use aptos_transaction_generator_lib::{CounterState, TransactionExecutor};
 use async_trait::async_trait;
-use futures::future::join_all;
+use futures::future::{join_all, try_join_all};
+use once_cell::sync::OnceCell;
 use rand::{rngs::StdRng, seq::SliceRandom, thread_rng, Rng, SeedableRng};
 use std::{
     collections::HashMap,
@@ -23,6 +31,8 @@ pub struct RestApiTransactionExecutor {
     pub rest_clients: Vec<RestClient>,
     pub max_retries: usize,
     pub retry_after: Duration,
+    // fetched on first use, the gas schedule is not expected to change during a run
+    pub(crate) min_gas_unit_price: OnceCell<u64>,
 }
 
 impl RestApiTransactionExecutor {
@@ -174,14 +184,23 @@ async fn submit_and_check(
 #[async_trait]
 impl TransactionExecutor for RestApiTransactionExecutor {
     async fn get_account_balance(&self, account_address: AccountAddress) -> Result<u64> {
+        self.get_coin_balance(account_address, aptos_coin_struct_tag())
+            .await
+    }
+
+    async fn get_coin_balance(
+        &self,
+        account_address: AccountAddress,
+        coin_type: StructTag,
+    ) -> Result<u64> {
+        let coin_type = &coin_type.to_string();
         Ok(RETRY_POLICY
             .retry(move || {
                 self.random_rest_client()
-                    .get_account_balance(account_address)
+                    .get_account_balance_bcs(account_address, coin_type)
             })
             .await?
-            .into_inner()
-            .get())
+            .into_inner())
     }
 
     async fn query_sequence_number(&self, account_address: AccountAddress) -> Result<u64> {
@@ -192,6 +211,16 @@ impl TransactionExecutor for RestApiTransactionExecutor {
             .sequence_number())
     }
 
+    async fn query_sequence_numbers(&self, addresses: &[AccountAddress]) -> Result<Vec<u64>> {
+        // Queried concurrently, instead of one account at a time
+        try_join_all(
+            addresses
+                .iter()
+                .map(|address| self.query_sequence_number(*address)),
+        )
+        .await
+    }
+
     async fn execute_transactions(&self, txns: &[SignedTransaction]) -> Result<()> {
         self.execute_transactions_with_counter(txns, &CounterState {
             submit_failures: vec![AtomicUsize::new(0)],
@@ -227,6 +256,100 @@ impl TransactionExecutor for RestApiTransactionExecutor {
         Ok(())
     }
 
+    async fn get_module(&self, address: AccountAddress, module_name: &str) -> Result<MoveModule> {
+        RETRY_POLICY
+            .retry(move || {
+                self.random_rest_client()
+                    .get_account_module(address, module_name)
+            })
+            .await?
+            .into_inner()
+            .try_parse_abi()?
+            .abi
+            .ok_or_else(|| anyhow!("Failed to parse ABI of {}::{}", address, module_name))
+    }
+
+    async fn estimate_gas(&self, txn: &SignedTransaction) -> Result<u64> {
+        // Simulation rejects transactions with a valid signature, so replace it with a dummy one
+        let public_key = match txn.authenticator() {
+            TransactionAuthenticator::Ed25519 { public_key, .. } => public_key,
+            _ => bail!("Gas estimation is only supported for single Ed25519 signer transactions"),
+        };
+        let simulated = SignedTransaction::new(
+            txn.clone().into_raw_transaction(),
+            public_key,
+            Ed25519Signature::try_from([0u8; 64].as_ref())?,
+        );
+        let simulated = &simulated;
+
+        let info = RETRY_POLICY
+            .retry(move || self.random_rest_client().simulate_bcs(simulated))
+            .await?
+            .into_inner()
+            .info;
+        if !info.status().is_success() {
+            bail!("Simulated transaction failed: {:?}", info.status());
+        }
+        Ok(info.gas_used())
+    }
+
+    async fn min_gas_unit_price(&self) -> Result<u64> {
+        if let Some(min_gas_unit_price) = self.min_gas_unit_price.get() {
+            return Ok(*min_gas_unit_price);
+        }
+        let gas_schedule = RETRY_POLICY
+            .retry(move || {
+                self.random_rest_client()
+                    .get_account_resource_bcs::<GasScheduleV2>(
+                        AccountAddress::ONE,
+                        "0x1::gas_schedule::GasScheduleV2",
+                    )
+            })
+            .await?
+            .into_inner();
+        let min_gas_unit_price = gas_schedule
+            .entries
+            .iter()
+            .find(|(name, _)| name == "txn.min_price_per_gas_unit")
+            .map(|(_, value)| *value)
+            .ok_or_else(|| anyhow!("Gas schedule has no minimum gas unit price"))?;
+        Ok(*self.min_gas_unit_price.get_or_init(|| min_gas_unit_price))
+    }
+
+    async fn submit_and_await(&self, txns: &[SignedTransaction]) -> Result<Vec<TxnStatus>> {
+        if let Err(err) = self.execute_transactions(txns).await {
+            sample!(
+                SampleRate::Duration(Duration::from_secs(60)),
+                warn!(
+                    "Failed executing {} txns, checking which got committed: {}",
+                    txns.len(),
+                    err
+                )
+            );
+        }
+
+        Ok(join_all(txns.iter().map(|txn| async move {
+            let hash = txn.clone().committed_hash();
+            match self
+                .random_rest_client()
+                .wait_for_signed_transaction_bcs(txn)
+                .await
+            {
+                Ok(response) => TxnStatus {
+                    hash,
+                    committed: true,
+                    vm_status: Some(format!("{:?}", response.into_inner().info.status())),
+                },
+                Err(_) => TxnStatus {
+                    hash,
+                    committed: false,
+                    vm_status: None,
+                },
+            }
+        }))
+        .await)
+    }
+
     fn create_counter_state(&self) -> CounterState {
         CounterState {
             submit_failures: std::iter::repeat_with(|| AtomicUsize::new(0))
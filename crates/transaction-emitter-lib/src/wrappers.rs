@@ -11,7 +11,9 @@ use crate::{
 use anyhow::{bail, Context, Result};
 use aptos_logger::{error, info};
 use aptos_sdk::transaction_builder::TransactionFactory;
-use aptos_transaction_generator_lib::{EntryPoints, TransactionType};
+use aptos_transaction_generator_lib::{
+    AccountSizeDistribution, EntryPoints, GasOverrides, TransactionType, SEND_AMOUNT,
+};
 use rand::{rngs::StdRng, SeedableRng};
 use std::time::{Duration, Instant};
 
@@ -90,24 +92,43 @@ pub async fn emit_transactions_with_cluster(
         .iter()
         .map(|t| match t {
             TransactionTypeArg::CoinTransfer => TransactionType::CoinTransfer {
+                amount_range: (1, 1),
                 invalid_transaction_ratio: args.invalid_tx,
+                invalid_kinds: Vec::new(),
                 sender_use_account_pool: false,
+                account_pool: None,
+                gas_overrides: GasOverrides::default(),
             },
             TransactionTypeArg::AccountGeneration => TransactionType::default_account_generation(),
             TransactionTypeArg::AccountGenerationLargePool => TransactionType::AccountGeneration {
                 add_created_accounts_to_pool: true,
                 max_account_working_set: 50_000_000,
                 creation_balance: 200_000_000,
+                account_pool: None,
+                max_pool_size: None,
+            },
+            TransactionTypeArg::NftMintAndTransfer => TransactionType::default_nft(),
+            TransactionTypeArg::NftDrop => TransactionType::NftDrop {
+                mint_spike: 1000,
+                trade_phase: 1000,
+            },
+            TransactionTypeArg::Marketplace => TransactionType::Marketplace {
+                listings_per_round: 100,
             },
-            TransactionTypeArg::NftMintAndTransfer => TransactionType::NftMintAndTransfer,
             TransactionTypeArg::PublishPackage => TransactionType::PublishPackage {
                 use_account_pool: false,
+                account_pool: None,
+                num_modules: 1,
+                module_bytes_padding: 0,
             },
             TransactionTypeArg::CustomFunctionLargeModuleWorkingSet => {
                 TransactionType::CallCustomModules {
                     entry_point: EntryPoints::Nop,
                     num_modules: 1000,
+                    module_address_space: 0,
                     use_account_pool: false,
+                    account_pool: None,
+                    gas_overrides: GasOverrides::default(),
                 }
             },
             TransactionTypeArg::CreateNewResource => TransactionType::CallCustomModules {
@@ -115,13 +136,76 @@ pub async fn emit_transactions_with_cluster(
                     data_length: Some(32),
                 },
                 num_modules: 1,
+                module_address_space: 0,
                 use_account_pool: true,
+                account_pool: None,
+                gas_overrides: GasOverrides::default(),
             },
             TransactionTypeArg::NoOp => TransactionType::CallCustomModules {
                 entry_point: EntryPoints::Nop,
                 num_modules: 1,
+                module_address_space: 0,
+                use_account_pool: false,
+                account_pool: None,
+                gas_overrides: GasOverrides::default(),
+            },
+            TransactionTypeArg::VectorGrowth => {
+                TransactionType::VectorGrowth { pushes_per_txn: 10 }
+            },
+            TransactionTypeArg::CustomCoinLifecycle => TransactionType::CustomCoinLifecycle,
+            TransactionTypeArg::MarkedEvents => TransactionType::MarkedEvents,
+            TransactionTypeArg::FlashLoan => TransactionType::FlashLoan { steps: 10 },
+            TransactionTypeArg::UniqueNoOp => TransactionType::UniqueNoOp { num_modules: 1 },
+            TransactionTypeArg::SharedCounter => TransactionType::SharedCounter {
+                increments_per_txn: 1,
+            },
+            TransactionTypeArg::OracleUpdate => TransactionType::OracleUpdate { feeds: 10 },
+            TransactionTypeArg::ResourceGroupOps => {
+                TransactionType::ResourceGroupOps { members_touched: 4 }
+            },
+            TransactionTypeArg::GasExhaustion => TransactionType::GasExhaustion,
+            TransactionTypeArg::ManySigners => TransactionType::ManySigners { num_signers: 16 },
+            TransactionTypeArg::FrameworkUpgradeTransfer => {
+                TransactionType::FrameworkUpgradeTransfer {
+                    legacy_percentage: 50,
+                }
+            },
+            TransactionTypeArg::WideReadSet => TransactionType::WideReadSet {
+                resources_read: 100,
+            },
+            TransactionTypeArg::SelfTransfer => TransactionType::SelfTransfer {
+                amount: SEND_AMOUNT,
+            },
+            TransactionTypeArg::ExpiringCoinTransfer => TransactionType::ExpiringCoinTransfer {
+                seconds_until_expiry: 5,
+            },
+            TransactionTypeArg::IdenticalBurst => {
+                TransactionType::IdenticalBurst { burst_size: 10 }
+            },
+            TransactionTypeArg::CircularTransfer => {
+                TransactionType::CircularTransfer { chain_length: 4 }
+            },
+            TransactionTypeArg::HeavyRead => TransactionType::CallCustomModules {
+                entry_point: EntryPoints::HeavyRead { iterations: 1000 },
+                num_modules: 1,
+                module_address_space: 0,
                 use_account_pool: false,
+                account_pool: None,
+                gas_overrides: GasOverrides::default(),
+            },
+            TransactionTypeArg::CreateEmptyAccount => TransactionType::CreateEmptyAccount {
+                add_created_addresses_to_pool: true,
+                max_working_set: 1_000_000,
+            },
+            TransactionTypeArg::SkewedAccountSize => TransactionType::SkewedAccountSize {
+                distribution: AccountSizeDistribution {
+                    min_size: 1,
+                    max_size: 10_000,
+                    shape: 1.16,
+                },
+                pushes_per_txn: 100,
             },
+            TransactionTypeArg::DexSwap => TransactionType::DexSwap { pairs: 4 },
         })
         .collect::<Vec<_>>();
 
@@ -1,31 +1,36 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
-use crate::{TransactionGenerator, TransactionGeneratorCreator};
+use crate::{
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    TransactionGenerator, TransactionGeneratorCreator,
+};
 use aptos_infallible::RwLock;
 use aptos_logger::{info, sample, sample::SampleRate};
 use aptos_sdk::{
     move_types::account_address::AccountAddress,
-    transaction_builder::{aptos_stdlib, TransactionFactory},
+    transaction_builder::aptos_stdlib,
     types::{transaction::SignedTransaction, LocalAccount},
 };
 use async_trait::async_trait;
-use rand::{rngs::StdRng, Rng, SeedableRng};
-use std::{sync::Arc, time::Duration};
+use rand::{Rng, SeedableRng};
+use std::{collections::HashSet, sync::Arc, time::Duration};
 
 pub struct AccountGenerator {
-    rng: StdRng,
-    txn_factory: TransactionFactory,
+    rng: ResumableRng,
+    txn_factory: SharedTransactionFactory,
     addresses_pool: Arc<RwLock<Vec<AccountAddress>>>,
     accounts_pool: Arc<RwLock<Vec<LocalAccount>>>,
     add_created_accounts_to_pool: bool,
     max_working_set: usize,
     creation_balance: u64,
+    max_pool_size: Option<usize>,
 }
 
 impl AccountGenerator {
     pub fn new(
-        rng: StdRng,
-        txn_factory: TransactionFactory,
+        rng: ResumableRng,
+        txn_factory: SharedTransactionFactory,
         addresses_pool: Arc<RwLock<Vec<AccountAddress>>>,
         accounts_pool: Arc<RwLock<Vec<LocalAccount>>>,
         add_created_accounts_to_pool: bool,
@@ -40,30 +45,31 @@ impl AccountGenerator {
             add_created_accounts_to_pool,
             max_working_set,
             creation_balance,
+            max_pool_size: None,
         }
     }
 
-    fn gen_single_txn(
-        &self,
-        from: &mut LocalAccount,
-        to: AccountAddress,
-        txn_factory: &TransactionFactory,
-    ) -> SignedTransaction {
-        from.sign_with_transaction_builder(txn_factory.payload(
-            if self.creation_balance > 0 {
-                aptos_stdlib::aptos_account_transfer(to, self.creation_balance)
-            } else {
-                aptos_stdlib::aptos_account_create_account(to)
-            },
-        ))
+    /// Caps the accounts pool, see `AccountGeneratorCreator::with_max_pool_size`.
+    pub fn with_max_pool_size(mut self, max_pool_size: usize) -> Self {
+        self.max_pool_size = Some(max_pool_size);
+        self
+    }
+
+    fn gen_single_txn(&mut self, from: &mut LocalAccount, to: AccountAddress) -> SignedTransaction {
+        let payload = if self.creation_balance > 0 {
+            aptos_stdlib::aptos_account_transfer(to, self.creation_balance)
+        } else {
+            aptos_stdlib::aptos_account_create_account(to)
+        };
+        from.sign_with_transaction_builder(self.txn_factory.payload(payload, &mut self.rng))
     }
 }
 
-fn add_to_sized_pool<T>(
+pub(crate) fn add_to_sized_pool<T>(
     pool: &RwLock<Vec<T>>,
     mut addition: Vec<T>,
     max_working_set: usize,
-    rng: &mut StdRng,
+    rng: &mut ResumableRng,
 ) {
     let mut current = pool.write();
     if current.len() < max_working_set {
@@ -87,6 +93,44 @@ fn add_to_sized_pool<T>(
     }
 }
 
+/// Appends the accounts to the pool, and evicts the oldest ones (at the front of the pool)
+/// beyond `max_pool_size`. Addresses of the evicted accounts are removed from the addresses
+/// pool, so that no transactions are sent to accounts that can no longer be picked as senders.
+fn add_to_capped_pool(
+    accounts_pool: &RwLock<Vec<LocalAccount>>,
+    addresses_pool: &RwLock<Vec<AccountAddress>>,
+    mut addition: Vec<LocalAccount>,
+    max_pool_size: usize,
+) {
+    let new_addresses = addition
+        .iter()
+        .map(|account| account.address())
+        .collect::<Vec<_>>();
+    let evicted = {
+        let mut accounts = accounts_pool.write();
+        accounts.append(&mut addition);
+        let num_evicted = accounts.len().saturating_sub(max_pool_size);
+        accounts
+            .drain(..num_evicted)
+            .map(|account| account.address())
+            .collect::<HashSet<_>>()
+    };
+
+    let mut addresses = addresses_pool.write();
+    addresses.extend(new_addresses);
+    if !evicted.is_empty() {
+        addresses.retain(|address| !evicted.contains(address));
+        sample!(
+            SampleRate::Duration(Duration::from_secs(120)),
+            info!(
+                "Already at limit {} of the accounts pool, so evicted the {} oldest accounts",
+                max_pool_size,
+                evicted.len()
+            )
+        );
+    }
+}
+
 #[async_trait]
 impl TransactionGenerator for AccountGenerator {
     fn generate_transactions(
@@ -102,7 +146,7 @@ impl TransactionGenerator for AccountGenerator {
             for _ in 0..transactions_per_account {
                 let receiver = LocalAccount::generate(&mut self.rng);
                 let receiver_address = receiver.address();
-                let request = self.gen_single_txn(account, receiver_address, &self.txn_factory);
+                let request = self.gen_single_txn(account, receiver_address);
                 requests.push(request);
                 new_accounts.push(receiver);
                 new_account_addresses.push(receiver_address);
@@ -110,35 +154,54 @@ impl TransactionGenerator for AccountGenerator {
         }
 
         if self.add_created_accounts_to_pool {
-            add_to_sized_pool(
-                self.accounts_pool.as_ref(),
-                new_accounts,
-                self.max_working_set,
-                &mut self.rng,
-            );
-            add_to_sized_pool(
-                self.addresses_pool.as_ref(),
-                new_account_addresses,
-                self.max_working_set,
-                &mut self.rng,
-            );
+            match self.max_pool_size {
+                Some(max_pool_size) => add_to_capped_pool(
+                    self.accounts_pool.as_ref(),
+                    self.addresses_pool.as_ref(),
+                    new_accounts,
+                    max_pool_size,
+                ),
+                None => {
+                    add_to_sized_pool(
+                        self.accounts_pool.as_ref(),
+                        new_accounts,
+                        self.max_working_set,
+                        &mut self.rng,
+                    );
+                    add_to_sized_pool(
+                        self.addresses_pool.as_ref(),
+                        new_account_addresses,
+                        self.max_working_set,
+                        &mut self.rng,
+                    );
+                },
+            }
         }
         requests
     }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
 }
 
 pub struct AccountGeneratorCreator {
-    txn_factory: TransactionFactory,
+    txn_factory: SharedTransactionFactory,
     addresses_pool: Arc<RwLock<Vec<AccountAddress>>>,
     accounts_pool: Arc<RwLock<Vec<LocalAccount>>>,
     add_created_accounts_to_pool: bool,
     max_working_set: usize,
     creation_balance: u64,
+    max_pool_size: Option<usize>,
 }
 
 impl AccountGeneratorCreator {
     pub fn new(
-        txn_factory: TransactionFactory,
+        txn_factory: SharedTransactionFactory,
         addresses_pool: Arc<RwLock<Vec<AccountAddress>>>,
         accounts_pool: Arc<RwLock<Vec<LocalAccount>>>,
         add_created_accounts_to_pool: bool,
@@ -157,21 +220,118 @@ impl AccountGeneratorCreator {
             add_created_accounts_to_pool,
             max_working_set,
             creation_balance,
+            max_pool_size: None,
         }
     }
+
+    /// Caps the accounts pool at `max_pool_size` accounts, evicting the oldest ones (FIFO)
+    /// once exceeded, along with their addresses. This replaces the `max_working_set` policy,
+    /// which only starts exchanging random accounts once the pool holds `max_working_set`
+    /// accounts, and does so independently in the accounts and addresses pools.
+    pub fn with_max_pool_size(mut self, max_pool_size: usize) -> Self {
+        self.max_pool_size = Some(max_pool_size);
+        self
+    }
 }
 
 #[async_trait]
 impl TransactionGeneratorCreator for AccountGeneratorCreator {
     async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
-        Box::new(AccountGenerator::new(
-            StdRng::from_entropy(),
+        let generator = AccountGenerator::new(
+            ResumableRng::from_entropy(),
             self.txn_factory.clone(),
             self.addresses_pool.clone(),
             self.accounts_pool.clone(),
             self.add_created_accounts_to_pool,
             self.max_working_set,
             self.creation_balance,
-        ))
+        );
+        Box::new(match self.max_pool_size {
+            Some(max_pool_size) => generator.with_max_pool_size(max_pool_size),
+            None => generator,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{bcs, transaction_builder::TransactionFactory, types::chain_id::ChainId};
+
+    #[test]
+    fn test_creates_fresh_accounts() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut creator_account = LocalAccount::generate(&mut rng);
+        let accounts_pool = Arc::new(RwLock::new(Vec::new()));
+        let mut generator = AccountGenerator::new(
+            rng,
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            Arc::new(RwLock::new(Vec::new())),
+            accounts_pool.clone(),
+            true,
+            100,
+            1_000,
+        );
+
+        let txns = generator.generate_transactions(vec![&mut creator_account], 3);
+        assert_eq!(txns.len(), 3);
+        let pool = accounts_pool.read();
+        assert_eq!(pool.len(), txns.len());
+        for (txn, created) in txns.iter().zip(pool.iter()) {
+            assert_eq!(txn.sender(), creator_account.address());
+
+            // Transfer creates the fresh account, whose key is kept to sign later on
+            let entry_function = txn.payload().clone().into_entry_function();
+            assert_eq!(entry_function.function().as_str(), "transfer");
+            assert_eq!(entry_function.args(), &[
+                bcs::to_bytes(&created.address()).unwrap(),
+                bcs::to_bytes(&1_000u64).unwrap(),
+            ]);
+            assert_ne!(created.address(), creator_account.address());
+            assert_eq!(
+                created.authentication_key().derived_address(),
+                created.address()
+            );
+            assert_eq!(created.sequence_number(), 0);
+        }
+    }
+
+    #[test]
+    fn test_pool_capped_fifo() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut creator_account = LocalAccount::generate(&mut rng);
+        let initial_address = AccountAddress::random();
+        let addresses_pool = Arc::new(RwLock::new(vec![initial_address]));
+        let accounts_pool = Arc::new(RwLock::new(Vec::new()));
+        let mut generator = AccountGenerator::new(
+            rng,
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            addresses_pool.clone(),
+            accounts_pool.clone(),
+            true,
+            1_000_000,
+            0,
+        )
+        .with_max_pool_size(5);
+
+        let created = (0..3)
+            .flat_map(|_| generator.generate_transactions(vec![&mut creator_account], 3))
+            .map(|txn| {
+                let entry_function = txn.payload().clone().into_entry_function();
+                bcs::from_bytes::<AccountAddress>(&entry_function.args()[0]).unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(created.len(), 9);
+
+        // The 4 oldest accounts got evicted, and their addresses with them
+        let pool = accounts_pool
+            .read()
+            .iter()
+            .map(|account| account.address())
+            .collect::<Vec<_>>();
+        assert_eq!(pool, created[4..]);
+        let mut expected_addresses = vec![initial_address];
+        expected_addresses.extend_from_slice(&created[4..]);
+        assert_eq!(*addresses_pool.read(), expected_addresses);
     }
 }
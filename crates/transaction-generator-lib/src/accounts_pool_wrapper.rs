@@ -2,16 +2,91 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{get_account_to_burn_from_pool, TransactionGenerator, TransactionGeneratorCreator};
+use anyhow::{Context, Result};
+use aptos_crypto::ed25519::Ed25519PrivateKey;
 use aptos_infallible::RwLock;
-use aptos_sdk::types::{transaction::SignedTransaction, LocalAccount};
+use aptos_sdk::{
+    move_types::account_address::AccountAddress,
+    types::{transaction::SignedTransaction, LocalAccount},
+};
 use async_trait::async_trait;
-use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::Path, sync::Arc};
+
+/// Pools of accounts, filled by account creation and burned by the pool consumers.
+/// Transaction types naming the same pool share it, and the ones not naming any
+/// share the default pool, so that types in different pools don't drain each other.
+#[derive(Default)]
+pub struct AccountPools {
+    default_pool: Arc<RwLock<Vec<LocalAccount>>>,
+    named_pools: HashMap<String, Arc<RwLock<Vec<LocalAccount>>>>,
+}
+
+impl AccountPools {
+    pub fn get(&mut self, name: Option<&str>) -> Arc<RwLock<Vec<LocalAccount>>> {
+        match name {
+            Some(name) => self
+                .named_pools
+                .entry(name.to_string())
+                .or_default()
+                .clone(),
+            None => self.default_pool.clone(),
+        }
+    }
+}
+
+/// Serialized state of a pool account, enough to keep sending from it in a later run.
+#[derive(Deserialize, Serialize)]
+struct DumpedAccount<K> {
+    address: AccountAddress,
+    private_key: K,
+    sequence_number: u64,
+}
+
+/// Drains the pool, and writes its accounts (with their private keys and sequence numbers)
+/// to `path` as JSON, so that a follow-up run can skip creating them, via `load_accounts_pool`.
+pub fn dump_accounts_pool(pool: &Arc<RwLock<Vec<LocalAccount>>>, path: &Path) -> Result<()> {
+    let accounts = pool.write().drain(..).collect::<Vec<_>>();
+    let dumped = accounts
+        .iter()
+        .map(|account| DumpedAccount {
+            address: account.address(),
+            private_key: account.private_key(),
+            sequence_number: account.sequence_number(),
+        })
+        .collect::<Vec<_>>();
+    let json = serde_json::to_vec_pretty(&dumped).context("Failed serializing accounts pool")?;
+    std::fs::write(path, json)
+        .with_context(|| format!("Failed writing accounts pool to {}", path.display()))
+}
+
+/// Reads accounts written by `dump_accounts_pool`, into a new pool.
+pub fn load_accounts_pool(path: &Path) -> Result<Arc<RwLock<Vec<LocalAccount>>>> {
+    let json = std::fs::read(path)
+        .with_context(|| format!("Failed reading accounts pool from {}", path.display()))?;
+    let dumped: Vec<DumpedAccount<Ed25519PrivateKey>> = serde_json::from_slice(&json)
+        .with_context(|| format!("Failed decoding accounts pool from {}", path.display()))?;
+    Ok(Arc::new(RwLock::new(
+        dumped
+            .into_iter()
+            .map(|account| {
+                LocalAccount::new(
+                    account.address,
+                    account.private_key,
+                    account.sequence_number,
+                )
+            })
+            .collect(),
+    )))
+}
 
 /// Wrapper that allows inner transaction generator to have unique accounts
 /// for all transactions (instead of having 5-20 transactions per account, as default)
 /// This is achieved via using accounts from the pool that account creatin can fill,
 /// and burning (removing accounts from the pool) them - basically using them only once.
 /// (we cannot use more as sequence number is not updated on failure)
+/// Accounts are drained from the pool under its write lock, so concurrent generators
+/// sharing the pool never get the same account.
 pub struct AccountsPoolWrapperGenerator {
     creator: Box<dyn TransactionGenerator>,
     accounts_pool: Arc<RwLock<Vec<LocalAccount>>>,
@@ -73,3 +148,159 @@ impl TransactionGeneratorCreator for AccountsPoolWrapperCreator {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{
+        transaction_builder::{aptos_stdlib, TransactionFactory},
+        types::chain_id::ChainId,
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::collections::HashSet;
+
+    struct TransferGenerator;
+
+    impl TransactionGenerator for TransferGenerator {
+        fn generate_transactions(
+            &mut self,
+            accounts: Vec<&mut LocalAccount>,
+            transactions_per_account: usize,
+        ) -> Vec<SignedTransaction> {
+            let txn_factory = TransactionFactory::new(ChainId::test());
+            let mut requests = Vec::new();
+            for account in accounts {
+                for _ in 0..transactions_per_account {
+                    requests.push(
+                        account
+                            .sign_with_transaction_builder(txn_factory.payload(
+                                aptos_stdlib::aptos_coin_transfer(AccountAddress::ONE, 1),
+                            )),
+                    );
+                }
+            }
+            requests
+        }
+    }
+
+    struct TransferCreator;
+
+    #[async_trait]
+    impl TransactionGeneratorCreator for TransferCreator {
+        async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+            Box::new(TransferGenerator)
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_generators_use_distinct_accounts() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let pool = Arc::new(RwLock::new(
+            (0..200)
+                .map(|_| LocalAccount::generate(&mut rng))
+                .collect::<Vec<_>>(),
+        ));
+        let mut creator = AccountsPoolWrapperCreator::new(Box::new(TransferCreator), pool.clone());
+
+        let mut handles = Vec::new();
+        for _ in 0..8 {
+            let mut generator = creator.create_transaction_generator().await;
+            handles.push(tokio::spawn(async move {
+                let mut accounts = vec![LocalAccount::generate(&mut rand::thread_rng())];
+                let mut senders = Vec::new();
+                for _ in 0..5 {
+                    senders.extend(
+                        generator
+                            .generate_transactions(accounts.iter_mut().collect(), 5)
+                            .iter()
+                            .map(|txn| (txn.sender(), txn.sequence_number())),
+                    );
+                    tokio::task::yield_now().await;
+                }
+                senders
+            }));
+        }
+
+        let mut senders = Vec::new();
+        for handle in handles {
+            senders.extend(handle.await.unwrap());
+        }
+        // Every account was lent exactly once, and sent a single transaction
+        assert_eq!(senders.len(), 200);
+        assert!(senders
+            .iter()
+            .all(|(_, sequence_number)| *sequence_number == 0));
+        assert_eq!(
+            senders
+                .iter()
+                .map(|(sender, _)| sender)
+                .collect::<HashSet<_>>()
+                .len(),
+            200
+        );
+        assert!(pool.read().is_empty());
+    }
+
+    #[test]
+    fn test_named_pools_isolated() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut account_pools = AccountPools::default();
+        let transfers_pool = account_pools.get(Some("transfers"));
+        let publishing_pool = account_pools.get(Some("publishing"));
+        transfers_pool
+            .write()
+            .extend((0..10).map(|_| LocalAccount::generate(&mut rng)));
+
+        assert!(Arc::ptr_eq(
+            &transfers_pool,
+            &account_pools.get(Some("transfers"))
+        ));
+        assert_eq!(
+            get_account_to_burn_from_pool(&account_pools.get(Some("transfers")), 4).len(),
+            4
+        );
+        assert!(get_account_to_burn_from_pool(&publishing_pool, 4).is_empty());
+        assert!(get_account_to_burn_from_pool(&account_pools.get(None), 4).is_empty());
+        assert_eq!(transfers_pool.read().len(), 6);
+    }
+
+    #[test]
+    fn test_dump_and_load_accounts_pool() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let pool = Arc::new(RwLock::new(
+            (0..5)
+                .map(|_| LocalAccount::generate(&mut rng))
+                .collect::<Vec<_>>(),
+        ));
+        *pool.write()[2].sequence_number_mut() = 7;
+        let expected = pool
+            .read()
+            .iter()
+            .map(|account| {
+                (
+                    account.address(),
+                    account.public_key().clone(),
+                    account.sequence_number(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let path = aptos_temppath::TempPath::new();
+        dump_accounts_pool(&pool, path.path()).unwrap();
+        assert!(pool.read().is_empty());
+
+        let loaded = load_accounts_pool(path.path()).unwrap();
+        let loaded = loaded
+            .read()
+            .iter()
+            .map(|account| {
+                (
+                    account.address(),
+                    account.public_key().clone(),
+                    account.sequence_number(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(loaded, expected);
+    }
+}
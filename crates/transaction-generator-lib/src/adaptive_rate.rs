@@ -0,0 +1,162 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{TransactionExecutor, TransactionGenerator, TransactionGeneratorCreator};
+use anyhow::Result;
+use aptos_infallible::Mutex;
+use aptos_sdk::types::{transaction::SignedTransaction, LocalAccount};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Parameters of the AIMD controller driving `AdaptiveRateGenerator`.
+#[derive(Clone, Copy, Debug)]
+pub struct AimdConfig {
+    /// Mempool fullness (between 0 and 1) above which the rate is decreased.
+    pub target_load: f64,
+    /// Fraction of the full rate added, while the mempool is below the target load.
+    pub additive_increase: f64,
+    /// Factor the rate is multiplied with, while the mempool is above the target load.
+    pub multiplicative_decrease: f64,
+    /// Fraction of the full rate that is always generated, so that the load keeps being observed.
+    pub min_rate: f64,
+}
+
+impl Default for AimdConfig {
+    fn default() -> Self {
+        Self {
+            target_load: 0.8,
+            additive_increase: 0.1,
+            multiplicative_decrease: 0.5,
+            min_rate: 0.05,
+        }
+    }
+}
+
+/// Fraction of the full rate to generate at, shared by all generators of a creator.
+struct AdaptiveRate {
+    config: AimdConfig,
+    rate: Mutex<f64>,
+}
+
+impl AdaptiveRate {
+    fn observe(&self, load: f64) -> f64 {
+        let mut rate = self.rate.lock();
+        *rate = if load > self.config.target_load {
+            (*rate * self.config.multiplicative_decrease).max(self.config.min_rate)
+        } else {
+            (*rate + self.config.additive_increase).min(1.0)
+        };
+        *rate
+    }
+}
+
+/// Wrapper that scales down the transactions generated by the inner generator, as
+/// the mempool fills up. Only a fraction (the current rate) of the passed accounts
+/// is handed to the inner generator.
+pub struct AdaptiveRateGenerator {
+    generator: Box<dyn TransactionGenerator>,
+    rate: Arc<AdaptiveRate>,
+}
+
+impl TransactionGenerator for AdaptiveRateGenerator {
+    fn generate_transactions(
+        &mut self,
+        mut accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let rate = *self.rate.rate.lock();
+        let num_accounts = (accounts.len() as f64 * rate).ceil() as usize;
+        accounts.truncate(num_accounts);
+        self.generator
+            .generate_transactions(accounts, transactions_per_account)
+    }
+}
+
+pub struct AdaptiveRateGeneratorCreator {
+    creator: Box<dyn TransactionGeneratorCreator>,
+    rate: Arc<AdaptiveRate>,
+}
+
+impl AdaptiveRateGeneratorCreator {
+    pub fn new(creator: Box<dyn TransactionGeneratorCreator>, config: AimdConfig) -> Self {
+        Self {
+            creator,
+            rate: Arc::new(AdaptiveRate {
+                config,
+                rate: Mutex::new(1.0),
+            }),
+        }
+    }
+
+    /// Queries the mempool fullness, and adjusts the rate of all generators accordingly.
+    /// Meant to be called periodically by the driver. Returns the new rate.
+    pub async fn update_rate(&self, txn_executor: &dyn TransactionExecutor) -> Result<f64> {
+        let load = txn_executor.mempool_load().await?;
+        Ok(self.rate.observe(load))
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for AdaptiveRateGeneratorCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(AdaptiveRateGenerator {
+            generator: self.creator.create_transaction_generator().await,
+            rate: self.rate.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        in_memory_executor::InMemoryExecutor,
+        p2p_transaction_generator::P2PTransactionGeneratorCreator, resumable_rng::ResumableRng,
+        shared_txn_factory::SharedTransactionFactory,
+    };
+    use aptos_infallible::RwLock;
+    use aptos_sdk::{
+        move_types::account_address::AccountAddress, transaction_builder::TransactionFactory,
+        types::chain_id::ChainId,
+    };
+    use rand::SeedableRng;
+
+    #[tokio::test]
+    async fn test_rate_decreases_as_mempool_fills_up() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let all_addresses = Arc::new(RwLock::new(
+            (0..20)
+                .map(|_| AccountAddress::random())
+                .collect::<Vec<_>>(),
+        ));
+        let mut creator = AdaptiveRateGeneratorCreator::new(
+            Box::new(P2PTransactionGeneratorCreator::new(
+                SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+                (1, 1),
+                all_addresses,
+                0,
+                None,
+            )),
+            AimdConfig::default(),
+        );
+        let mut generator = creator.create_transaction_generator().await;
+        let mut accounts = (0..40)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let executor = InMemoryExecutor::default();
+
+        // The mempool fills up by 10% between updates
+        let mut counts = Vec::new();
+        for load in [0.6, 0.7, 0.8, 0.9, 1.0, 1.0] {
+            executor.set_mempool_load(load);
+            creator.update_rate(&executor).await.unwrap();
+            counts.push(
+                generator
+                    .generate_transactions(accounts.iter_mut().collect(), 2)
+                    .len(),
+            );
+        }
+        // Loads up to 0.8 keep the full rate, every higher load halves it
+        assert_eq!(counts, vec![80, 80, 80, 40, 20, 10]);
+    }
+}
@@ -0,0 +1,269 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    TransactionExecutor, TransactionGenerator, TransactionGeneratorCreator,
+};
+use anyhow::{Context, Result};
+use aptos_sdk::{
+    bcs,
+    move_types::{ident_str, language_storage::ModuleId},
+    types::{
+        account_address::AccountAddress,
+        transaction::{EntryFunction, SignedTransaction, TransactionPayload},
+        LocalAccount,
+    },
+};
+use async_trait::async_trait;
+use rand::{distributions::Alphanumeric, seq::SliceRandom, Rng, SeedableRng};
+use std::collections::HashMap;
+
+/// Address of the Aptos Names Service deployment on mainnet and testnet.
+/// ANS is not part of the framework, so on other networks (e.g. local testnets or forge)
+/// it needs to be deployed at this address before running the workload.
+pub const ANS_ADDRESS: &str = "0x867ed1f6bf916171b1de3ee92849b8978b7d1b9e0a8cc982a3d19d535dfd9c0c";
+
+const SECONDS_PER_YEAR: u64 = 60 * 60 * 24 * 365;
+
+/// Registers new, unique domains through the ANS router, at most `domains_per_round`
+/// per call. Registered domains are tracked per owner, so that (if enabled) the remaining
+/// transactions in a round renew domains previously registered by the same account.
+pub struct AnsRegisterGenerator {
+    rng: ResumableRng,
+    txn_factory: SharedTransactionFactory,
+    router: ModuleId,
+    domains_per_round: usize,
+    renew: bool,
+    // names are `<prefix><counter>`, prefix is random to not collide with other generators
+    prefix: String,
+    counter: u64,
+    registered: HashMap<AccountAddress, Vec<String>>,
+}
+
+impl AnsRegisterGenerator {
+    pub fn new(
+        mut rng: ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        ans_address: AccountAddress,
+        domains_per_round: usize,
+        renew: bool,
+    ) -> Self {
+        let prefix = (&mut rng)
+            .sample_iter(&Alphanumeric)
+            .take(10)
+            .map(|c| c.to_ascii_lowercase())
+            .chain(std::iter::once('-'))
+            .collect();
+        Self {
+            rng,
+            txn_factory,
+            router: ModuleId::new(ans_address, ident_str!("router").to_owned()),
+            domains_per_round,
+            renew,
+            prefix,
+            counter: 0,
+            registered: HashMap::new(),
+        }
+    }
+
+    fn next_domain_name(&mut self) -> String {
+        let name = format!("{}{}", self.prefix, self.counter);
+        self.counter += 1;
+        name
+    }
+
+    fn register_domain(&self, domain_name: &str) -> TransactionPayload {
+        TransactionPayload::EntryFunction(EntryFunction::new(
+            self.router.clone(),
+            ident_str!("register_domain").to_owned(),
+            vec![],
+            vec![
+                bcs::to_bytes(domain_name).unwrap(),
+                bcs::to_bytes(&SECONDS_PER_YEAR).unwrap(),
+                // target_addr and to_addr, both default to the sender
+                bcs::to_bytes(&None::<AccountAddress>).unwrap(),
+                bcs::to_bytes(&None::<AccountAddress>).unwrap(),
+            ],
+        ))
+    }
+
+    fn renew_domain(&self, domain_name: &str) -> TransactionPayload {
+        TransactionPayload::EntryFunction(EntryFunction::new(
+            self.router.clone(),
+            ident_str!("renew_domain").to_owned(),
+            vec![],
+            vec![
+                bcs::to_bytes(domain_name).unwrap(),
+                bcs::to_bytes(&SECONDS_PER_YEAR).unwrap(),
+            ],
+        ))
+    }
+}
+
+impl TransactionGenerator for AnsRegisterGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
+        let mut num_registered = 0;
+        for account in accounts {
+            for _ in 0..transactions_per_account {
+                let payload = if num_registered < self.domains_per_round {
+                    num_registered += 1;
+                    let domain_name = self.next_domain_name();
+                    let payload = self.register_domain(&domain_name);
+                    self.registered
+                        .entry(account.address())
+                        .or_default()
+                        .push(domain_name);
+                    payload
+                } else if self.renew {
+                    match self
+                        .registered
+                        .get(&account.address())
+                        .and_then(|names| names.choose(&mut self.rng))
+                    {
+                        Some(domain_name) => self.renew_domain(domain_name),
+                        None => continue,
+                    }
+                } else {
+                    break;
+                };
+                requests.push(account.sign_with_transaction_builder(
+                    self.txn_factory.payload(payload, &mut self.rng),
+                ));
+            }
+        }
+        requests
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
+}
+
+pub struct AnsRegisterGeneratorCreator {
+    txn_factory: SharedTransactionFactory,
+    ans_address: AccountAddress,
+    domains_per_round: usize,
+    renew: bool,
+}
+
+impl AnsRegisterGeneratorCreator {
+    /// Fails if ANS is not deployed at `ans_address`, instead of generating transactions
+    /// that would all abort.
+    pub async fn new(
+        txn_factory: SharedTransactionFactory,
+        txn_executor: &dyn TransactionExecutor,
+        ans_address: AccountAddress,
+        domains_per_round: usize,
+        renew: bool,
+    ) -> Result<Self> {
+        txn_executor
+            .get_module(ans_address, "router")
+            .await
+            .with_context(|| format!("ANS is not deployed at {}", ans_address))?;
+        Ok(Self {
+            txn_factory,
+            ans_address,
+            domains_per_round,
+            renew,
+        })
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for AnsRegisterGeneratorCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(AnsRegisterGenerator::new(
+            ResumableRng::from_entropy(),
+            self.txn_factory.clone(),
+            self.ans_address,
+            self.domains_per_round,
+            self.renew,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::in_memory_executor::InMemoryExecutor;
+    use aptos_rest_client::aptos_api_types::MoveModule;
+    use aptos_sdk::{transaction_builder::TransactionFactory, types::chain_id::ChainId};
+    use std::collections::HashSet;
+
+    fn ans_address() -> AccountAddress {
+        AccountAddress::from_hex_literal(ANS_ADDRESS).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_requires_ans_deployed() {
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let executor = InMemoryExecutor::default();
+        assert!(AnsRegisterGeneratorCreator::new(
+            txn_factory.clone(),
+            &executor,
+            ans_address(),
+            10,
+            false
+        )
+        .await
+        .is_err());
+
+        executor.publish_module(MoveModule {
+            address: ans_address().into(),
+            name: ident_str!("router").to_owned().into(),
+            friends: vec![],
+            exposed_functions: vec![],
+            structs: vec![],
+        });
+        assert!(
+            AnsRegisterGeneratorCreator::new(txn_factory, &executor, ans_address(), 10, false)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_domain_names_unique() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut accounts = (0..4)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+
+        // Across rounds, and across generators running concurrently
+        let mut names = HashSet::new();
+        let mut num_registered = 0;
+        for seed in 0..2 {
+            let mut generator = AnsRegisterGenerator::new(
+                ResumableRng::seed_from_u64(seed),
+                txn_factory.clone(),
+                ans_address(),
+                3,
+                false,
+            );
+            for _ in 0..5 {
+                let txns = generator.generate_transactions(accounts.iter_mut().collect(), 2);
+                assert_eq!(txns.len(), 3);
+                for txn in txns {
+                    let entry_function = txn.payload().clone().into_entry_function();
+                    assert_eq!(entry_function.function().as_str(), "register_domain");
+                    let name: String = bcs::from_bytes(&entry_function.args()[0]).unwrap();
+                    assert!(names.insert(name));
+                    num_registered += 1;
+                }
+            }
+        }
+        assert_eq!(num_registered, 2 * 5 * 3);
+    }
+}
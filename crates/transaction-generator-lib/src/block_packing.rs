@@ -0,0 +1,171 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{TransactionGenerator, TransactionGeneratorCreator};
+use aptos_sdk::types::{
+    transaction::{SignedTransaction, TransactionPayload},
+    LocalAccount,
+};
+use async_trait::async_trait;
+use std::collections::{HashMap, VecDeque};
+
+/// Key under which the gas estimate of a transaction is looked up:
+/// `<module>::<function>` for entry functions, and the payload kind otherwise.
+pub fn gas_estimate_key(payload: &TransactionPayload) -> String {
+    match payload {
+        TransactionPayload::EntryFunction(entry_function) => format!(
+            "{}::{}",
+            entry_function.module().name(),
+            entry_function.function()
+        ),
+        TransactionPayload::Script(_) => "script".to_string(),
+        TransactionPayload::ModuleBundle(_) => "module_bundle".to_string(),
+        TransactionPayload::Multisig(_) => "multisig".to_string(),
+    }
+}
+
+/// Splits transactions (in order) into blocks, starting a new block whenever the next
+/// transaction would take the estimated gas of the current block over `block_gas_limit`.
+/// A transaction estimated over the limit on its own is put into a block by itself.
+pub fn pack_into_blocks(
+    txns: Vec<SignedTransaction>,
+    block_gas_limit: u64,
+    estimate_gas: impl Fn(&SignedTransaction) -> u64,
+) -> Vec<Vec<SignedTransaction>> {
+    let mut blocks = Vec::new();
+    let mut block = Vec::new();
+    let mut block_gas = 0u64;
+    for txn in txns {
+        let gas = estimate_gas(&txn);
+        if !block.is_empty() && block_gas.saturating_add(gas) > block_gas_limit {
+            blocks.push(std::mem::take(&mut block));
+            block_gas = 0;
+        }
+        block_gas = block_gas.saturating_add(gas);
+        block.push(txn);
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+    blocks
+}
+
+/// Wrapper that groups the transactions of the inner generator into blocks,
+/// each within the configured block gas limit, and returns a single block per call.
+/// Gas used is estimated per transaction type, from `gas_estimates` (see `gas_estimate_key`),
+/// falling back to the max gas amount of the transaction for unknown types.
+///
+/// Inner generator is only called once all blocks from the previous call were returned,
+/// so no transactions are signed (and no sequence numbers are used) ahead of time.
+pub struct BlockPackingGenerator {
+    generator: Box<dyn TransactionGenerator>,
+    block_gas_limit: u64,
+    gas_estimates: HashMap<String, u64>,
+    pending_blocks: VecDeque<Vec<SignedTransaction>>,
+}
+
+impl BlockPackingGenerator {
+    pub fn new(
+        generator: Box<dyn TransactionGenerator>,
+        block_gas_limit: u64,
+        gas_estimates: HashMap<String, u64>,
+    ) -> Self {
+        Self {
+            generator,
+            block_gas_limit,
+            gas_estimates,
+            pending_blocks: VecDeque::new(),
+        }
+    }
+
+    pub fn estimate_gas(&self, txn: &SignedTransaction) -> u64 {
+        self.gas_estimates
+            .get(&gas_estimate_key(txn.payload()))
+            .copied()
+            .unwrap_or_else(|| txn.max_gas_amount())
+    }
+}
+
+impl TransactionGenerator for BlockPackingGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        if self.pending_blocks.is_empty() {
+            let txns = self
+                .generator
+                .generate_transactions(accounts, transactions_per_account);
+            self.pending_blocks =
+                pack_into_blocks(txns, self.block_gas_limit, |txn| self.estimate_gas(txn)).into();
+        }
+        self.pending_blocks.pop_front().unwrap_or_default()
+    }
+}
+
+pub struct BlockPackingGeneratorCreator {
+    creator: Box<dyn TransactionGeneratorCreator>,
+    block_gas_limit: u64,
+    gas_estimates: HashMap<String, u64>,
+}
+
+impl BlockPackingGeneratorCreator {
+    pub fn new(
+        creator: Box<dyn TransactionGeneratorCreator>,
+        block_gas_limit: u64,
+        gas_estimates: HashMap<String, u64>,
+    ) -> Self {
+        Self {
+            creator,
+            block_gas_limit,
+            gas_estimates,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for BlockPackingGeneratorCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(BlockPackingGenerator::new(
+            self.creator.create_transaction_generator().await,
+            self.block_gas_limit,
+            self.gas_estimates.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{
+        transaction_builder::{aptos_stdlib, TransactionFactory},
+        types::chain_id::ChainId,
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_blocks_within_gas_limit() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let txn_factory = TransactionFactory::new(ChainId::test());
+        let mut account = LocalAccount::generate(&mut rng);
+        let receiver = LocalAccount::generate(&mut rng).address();
+        let txns = (0..50)
+            .map(|i| {
+                account.sign_with_transaction_builder(
+                    txn_factory
+                        .payload(aptos_stdlib::aptos_coin_transfer(receiver, 1))
+                        .max_gas_amount(100 + i * 10),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let block_gas_limit = 2_000;
+        let blocks = pack_into_blocks(txns, block_gas_limit, |txn| txn.max_gas_amount());
+        assert!(blocks.len() > 1);
+        assert_eq!(blocks.iter().map(|block| block.len()).sum::<usize>(), 50);
+        for block in &blocks {
+            let block_gas: u64 = block.iter().map(|txn| txn.max_gas_amount()).sum();
+            assert!(block_gas <= block_gas_limit);
+        }
+    }
+}
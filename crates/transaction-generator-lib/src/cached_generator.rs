@@ -0,0 +1,168 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    shared_txn_factory::SharedTransactionFactory, TransactionGenerator, TransactionGeneratorCreator,
+};
+use aptos_sdk::{
+    move_types::account_address::AccountAddress,
+    types::{transaction::SignedTransaction, LocalAccount},
+};
+use async_trait::async_trait;
+use rand::thread_rng;
+use std::collections::HashMap;
+
+/// Wrapper that generates transactions with the inner generator only once,
+/// and replays the cached transactions on every subsequent call.
+/// Useful for replay-heavy benchmarks (i.e. cache warming), where the same
+/// transactions are submitted repeatedly, and re-signing them each round is wasted work.
+///
+/// If `advance_sequence_numbers` is set, cached payloads are re-signed on replay
+/// with the current sequence numbers of the passed accounts, so they can be committed again.
+/// Multi-agent transactions (and transactions whose sender is not in the passed accounts)
+/// cannot be re-signed, and are always replayed as they are.
+pub struct CachedGenerator {
+    generator: Box<dyn TransactionGenerator>,
+    txn_factory: SharedTransactionFactory,
+    advance_sequence_numbers: bool,
+    cached: Option<Vec<SignedTransaction>>,
+}
+
+impl CachedGenerator {
+    pub fn new(
+        generator: Box<dyn TransactionGenerator>,
+        txn_factory: SharedTransactionFactory,
+        advance_sequence_numbers: bool,
+    ) -> Self {
+        Self {
+            generator,
+            txn_factory,
+            advance_sequence_numbers,
+            cached: None,
+        }
+    }
+
+    fn resign(
+        &self,
+        cached: &[SignedTransaction],
+        accounts: Vec<&mut LocalAccount>,
+    ) -> Vec<SignedTransaction> {
+        let mut accounts_by_address = accounts
+            .into_iter()
+            .map(|account| (account.address(), account))
+            .collect::<HashMap<AccountAddress, &mut LocalAccount>>();
+
+        cached
+            .iter()
+            .map(|txn| match accounts_by_address.get_mut(&txn.sender()) {
+                Some(account) if !txn.is_multi_agent() => account.sign_with_transaction_builder(
+                    self.txn_factory
+                        .payload(txn.payload().clone(), &mut thread_rng())
+                        .max_gas_amount(txn.max_gas_amount())
+                        .gas_unit_price(txn.gas_unit_price())
+                        .chain_id(txn.chain_id()),
+                ),
+                _ => txn.clone(),
+            })
+            .collect()
+    }
+}
+
+impl TransactionGenerator for CachedGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        if let Some(cached) = &self.cached {
+            return if self.advance_sequence_numbers {
+                self.resign(cached, accounts)
+            } else {
+                cached.clone()
+            };
+        }
+
+        let txns = self
+            .generator
+            .generate_transactions(accounts, transactions_per_account);
+        self.cached = Some(txns.clone());
+        txns
+    }
+}
+
+pub struct CachedGeneratorCreator {
+    creator: Box<dyn TransactionGeneratorCreator>,
+    txn_factory: SharedTransactionFactory,
+    advance_sequence_numbers: bool,
+}
+
+impl CachedGeneratorCreator {
+    pub fn new(
+        creator: Box<dyn TransactionGeneratorCreator>,
+        txn_factory: SharedTransactionFactory,
+        advance_sequence_numbers: bool,
+    ) -> Self {
+        Self {
+            creator,
+            txn_factory,
+            advance_sequence_numbers,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for CachedGeneratorCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(CachedGenerator::new(
+            self.creator.create_transaction_generator().await,
+            self.txn_factory.clone(),
+            self.advance_sequence_numbers,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{p2p_transaction_generator::P2PTransactionGenerator, resumable_rng::ResumableRng};
+    use aptos_infallible::RwLock;
+    use aptos_sdk::{bcs, transaction_builder::TransactionFactory, types::chain_id::ChainId};
+    use rand::SeedableRng;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_replays_identical_bytes() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut accounts = (0..4)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let mut generator = CachedGenerator::new(
+            Box::new(P2PTransactionGenerator::new(
+                rng,
+                (1, 100),
+                txn_factory.clone(),
+                Arc::new(RwLock::new(
+                    accounts.iter().map(|account| account.address()).collect(),
+                )),
+                0,
+            )),
+            txn_factory,
+            false,
+        );
+
+        let generated =
+            bcs::to_bytes(&generator.generate_transactions(accounts.iter_mut().collect(), 2))
+                .unwrap();
+        for _ in 0..2 {
+            let replayed =
+                bcs::to_bytes(&generator.generate_transactions(accounts.iter_mut().collect(), 2))
+                    .unwrap();
+            assert_eq!(replayed, generated);
+        }
+        // Sequence numbers only advanced for the generated transactions
+        assert!(accounts
+            .iter()
+            .all(|account| account.sequence_number() == 2));
+    }
+}
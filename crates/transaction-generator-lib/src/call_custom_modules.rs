@@ -6,36 +6,210 @@ use super::{
     TransactionExecutor,
 };
 use crate::{
-    publishing::publish_util::PackageHandler, TransactionGenerator, TransactionGeneratorCreator,
+    build_raw_transaction,
+    publishing::publish_util::PackageHandler,
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    source_accounts::{execute_across_source_accounts, sign_across_source_accounts},
+    TransactionGenerator, TransactionGeneratorCreator,
 };
-use aptos_logger::info;
+use anyhow::Result;
+use aptos_gas::{AptosGasParameters, InitialGasSchedule};
+use aptos_infallible::RwLock;
+use aptos_logger::{info, warn};
+use aptos_rest_client::aptos_api_types::{MoveModule, MoveStructTag, MoveType};
 use aptos_sdk::{
-    transaction_builder::TransactionFactory,
-    types::{transaction::SignedTransaction, LocalAccount},
+    bcs,
+    move_types::{
+        account_address::AccountAddress, identifier::Identifier, language_storage::ModuleId,
+    },
+    types::{
+        transaction::{EntryFunction, RawTransaction, SignedTransaction, TransactionPayload},
+        LocalAccount,
+    },
 };
 use async_trait::async_trait;
-use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
-use std::sync::Arc;
+use rand::{distributions::Alphanumeric, Rng, SeedableRng};
+use std::sync::{
+    atomic::{AtomicU64, AtomicUsize, Ordering},
+    Arc,
+};
+
+/// Iterations of the `loopy` call made by GasExhaustion transactions.
+pub const GAS_EXHAUSTION_LOOP_COUNT: u64 = 100_000_000;
+/// Lower bound of the gas units the GasExhaustion loop needs, under the initial gas schedule:
+/// every iteration executes at least a `gt` and a `sub`.
+/// GasExhaustion transactions get a max gas amount just below it, so they always run out of
+/// gas during execution (and are committed), instead of being rejected at validation.
+pub fn gas_exhaustion_min_gas_units() -> u64 {
+    let gas_params = AptosGasParameters::initial();
+    let internal_gas_per_iteration =
+        u64::from(gas_params.instr.gt) + u64::from(gas_params.instr.sub);
+    GAS_EXHAUSTION_LOOP_COUNT * internal_gas_per_iteration
+        / u64::from(gas_params.txn.gas_unit_scaling_factor)
+}
+
+/// Entry function call built from the on-chain ABI of a module. Used instead of the
+/// entry point payload, once the module is upgraded to a version with a different signature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntryFunctionTemplate {
+    module_id: ModuleId,
+    function: Identifier,
+    // parameter types, without the leading signer
+    params: Vec<MoveType>,
+}
+
+impl EntryFunctionTemplate {
+    pub fn from_abi(module_id: ModuleId, abi: &MoveModule, function: &str) -> Option<Self> {
+        let function = abi
+            .exposed_functions
+            .iter()
+            .find(|f| f.is_entry && f.name.as_str() == function)?;
+        Some(Self {
+            module_id,
+            function: function.name.0.clone(),
+            params: function
+                .params
+                .iter()
+                .skip_while(|param| is_signer(param))
+                .cloned()
+                .collect(),
+        })
+    }
+
+    pub fn params(&self) -> &[MoveType] {
+        &self.params
+    }
+
+    /// Creates a payload with random arguments, or returns None if arguments
+    /// cannot be generated for some of the parameter types.
+    pub fn create_payload(&self, rng: &mut ResumableRng) -> Option<TransactionPayload> {
+        let args = self
+            .params
+            .iter()
+            .map(|param| random_arg(rng, param))
+            .collect::<Option<Vec<_>>>()?;
+        Some(TransactionPayload::EntryFunction(EntryFunction::new(
+            self.module_id.clone(),
+            self.function.clone(),
+            vec![],
+            args,
+        )))
+    }
+}
+
+fn is_signer(param: &MoveType) -> bool {
+    match param {
+        MoveType::Signer => true,
+        MoveType::Reference { to, .. } => **to == MoveType::Signer,
+        _ => false,
+    }
+}
+
+fn is_string(tag: &MoveStructTag) -> bool {
+    tag.address.inner() == &AccountAddress::ONE
+        && tag.module.as_str() == "string"
+        && tag.name.as_str() == "String"
+}
+
+fn random_arg(rng: &mut ResumableRng, param: &MoveType) -> Option<Vec<u8>> {
+    let arg = match param {
+        MoveType::Bool => bcs::to_bytes(&rng.gen::<bool>()),
+        MoveType::U8 => bcs::to_bytes(&rng.gen::<u8>()),
+        MoveType::U16 => bcs::to_bytes(&rng.gen::<u16>()),
+        MoveType::U32 => bcs::to_bytes(&rng.gen::<u32>()),
+        MoveType::U64 => bcs::to_bytes(&rng.gen::<u64>()),
+        MoveType::U128 => bcs::to_bytes(&rng.gen::<u128>()),
+        MoveType::Address => bcs::to_bytes(&AccountAddress::new(rng.gen())),
+        MoveType::Vector { items } if **items == MoveType::U8 => {
+            let len = rng.gen_range(0usize, 100usize);
+            bcs::to_bytes(&(0..len).map(|_| rng.gen::<u8>()).collect::<Vec<_>>())
+        },
+        MoveType::Struct(tag) if is_string(tag) => {
+            let len = rng.gen_range(0usize, 100usize);
+            let value: String = rng
+                .sample_iter(&Alphanumeric)
+                .take(len)
+                .map(char::from)
+                .collect();
+            bcs::to_bytes(&value)
+        },
+        _ => return None,
+    };
+    Some(arg.unwrap())
+}
 
 pub struct CallCustomModulesGenerator {
-    rng: StdRng,
-    txn_factory: TransactionFactory,
+    rng: ResumableRng,
+    txn_factory: SharedTransactionFactory,
     packages: Arc<Vec<Package>>,
     entry_point: EntryPoints,
+    // per package, template to use instead of the entry point, if the module got upgraded
+    templates: Arc<RwLock<Vec<Option<EntryFunctionTemplate>>>>,
+    // counter shared by all generators of the creator, if payloads need to be unique
+    nonces: Option<Arc<AtomicU64>>,
+    // cursor shared by all generators of the creator, if modules are called in rotation
+    module_cursor: Option<Arc<AtomicUsize>>,
 }
 
 impl CallCustomModulesGenerator {
     pub fn new(
-        rng: StdRng,
-        txn_factory: TransactionFactory,
+        rng: ResumableRng,
+        txn_factory: SharedTransactionFactory,
         packages: Arc<Vec<Package>>,
         entry_point: EntryPoints,
+        templates: Arc<RwLock<Vec<Option<EntryFunctionTemplate>>>>,
+        nonces: Option<Arc<AtomicU64>>,
     ) -> Self {
         Self {
             rng,
             txn_factory,
             packages,
             entry_point,
+            templates,
+            nonces,
+            module_cursor: None,
+        }
+    }
+
+    /// Calls the modules in rotation, instead of picking them at random. Generators sharing
+    /// the cursor together scan all modules cyclically, which is the worst case for an LRU cache.
+    pub fn with_module_rotation(mut self, module_cursor: Arc<AtomicUsize>) -> Self {
+        self.module_cursor = Some(module_cursor);
+        self
+    }
+
+    fn next_package_idx(&mut self) -> usize {
+        match &self.module_cursor {
+            Some(module_cursor) => {
+                module_cursor.fetch_add(1, Ordering::Relaxed) % self.packages.len()
+            },
+            None => self.rng.gen_range(0, self.packages.len()),
+        }
+    }
+
+    fn next_entry_point(&self) -> EntryPoints {
+        self.nonces
+            .as_ref()
+            .and_then(|nonces| {
+                self.entry_point
+                    .with_nonce(nonces.fetch_add(1, Ordering::Relaxed))
+            })
+            .unwrap_or(self.entry_point)
+    }
+
+    fn next_payload(&mut self, templates: &[Option<EntryFunctionTemplate>]) -> TransactionPayload {
+        let idx = self.next_package_idx();
+        let payload = templates
+            .get(idx)
+            .and_then(|template| template.as_ref())
+            .and_then(|template| template.create_payload(&mut self.rng));
+        match payload {
+            Some(payload) => payload,
+            None => {
+                let entry_point = self.next_entry_point();
+                self.packages[idx].specific_payload(entry_point, Some(&mut self.rng), None)
+            },
         }
     }
 }
@@ -49,50 +223,75 @@ impl TransactionGenerator for CallCustomModulesGenerator {
     ) -> Vec<SignedTransaction> {
         let needed = accounts.len() * transactions_per_account;
         let mut requests = Vec::with_capacity(needed);
+        let templates = self.templates.read().clone();
 
         for account in accounts {
             for _ in 0..transactions_per_account {
-                let request = self
-                    .packages
-                    .choose(&mut self.rng)
-                    .unwrap()
-                    .use_specific_transaction(
-                        self.entry_point,
-                        account,
-                        &self.txn_factory,
-                        Some(&mut self.rng),
-                        None,
-                    );
-                requests.push(request);
+                let payload = self.next_payload(&templates);
+                requests.push(account.sign_with_transaction_builder(
+                    self.txn_factory.payload(payload, &mut self.rng),
+                ));
             }
         }
         requests
     }
+
+    fn generate_raw_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<RawTransaction> {
+        let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
+        let templates = self.templates.read().clone();
+
+        for account in accounts {
+            for _ in 0..transactions_per_account {
+                let payload = self.next_payload(&templates);
+                requests.push(build_raw_transaction(
+                    account,
+                    self.txn_factory.payload(payload, &mut self.rng),
+                ));
+            }
+        }
+        requests
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
 }
 
 pub struct CallCustomModulesCreator {
-    txn_factory: TransactionFactory,
+    txn_factory: SharedTransactionFactory,
     packages: Arc<Vec<Package>>,
     entry_point: EntryPoints,
+    refetch_abi: bool,
+    templates: Arc<RwLock<Vec<Option<EntryFunctionTemplate>>>>,
+    nonces: Option<Arc<AtomicU64>>,
+    module_cursor: Option<Arc<AtomicUsize>>,
 }
 
 impl CallCustomModulesCreator {
     pub async fn new(
-        txn_factory: TransactionFactory,
-        init_txn_factory: TransactionFactory,
+        rng: &mut ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        init_txn_factory: SharedTransactionFactory,
         accounts: &mut [LocalAccount],
         txn_executor: &dyn TransactionExecutor,
         entry_point: EntryPoints,
         num_modules: usize,
     ) -> Self {
-        let mut rng = StdRng::from_entropy();
         assert!(accounts.len() >= num_modules);
         let mut requests = Vec::with_capacity(accounts.len());
         let mut package_handler = PackageHandler::new();
         let mut packages = Vec::new();
         for account in accounts.iter_mut().take(num_modules) {
-            let package = package_handler.pick_package(&mut rng, account);
-            let txn = package.publish_transaction(account, &init_txn_factory);
+            let package = package_handler.pick_package(rng, account);
+            let txn = package.publish_transaction(rng, account, &init_txn_factory);
             requests.push(txn);
             packages.push(package);
         }
@@ -104,18 +303,454 @@ impl CallCustomModulesCreator {
             txn_factory,
             packages: Arc::new(packages),
             entry_point,
+            refetch_abi: false,
+            templates: Arc::new(RwLock::new(Vec::new())),
+            nonces: None,
+            module_cursor: None,
+        }
+    }
+
+    /// Publishes additional, distinct modules (from the accounts that published the first
+    /// `num_modules`), up to `module_address_space` modules in total, and calls all of them
+    /// in rotation. Used to benchmark module cache eviction, by forcing cache misses.
+    pub async fn with_module_address_space(
+        mut self,
+        rng: &mut ResumableRng,
+        init_txn_factory: &SharedTransactionFactory,
+        accounts: &mut [LocalAccount],
+        txn_executor: &dyn TransactionExecutor,
+        module_address_space: usize,
+    ) -> Self {
+        let num_publishers = self.packages.len();
+        let num_extra = module_address_space.saturating_sub(num_publishers);
+        if num_extra == 0 {
+            return self;
+        }
+
+        let mut package_handler = PackageHandler::new();
+        let mut packages = Vec::with_capacity(num_extra);
+        let txns = sign_across_source_accounts(
+            &mut accounts[..num_publishers],
+            0..num_extra,
+            |publisher, _| {
+                let package = package_handler.pick_new_package(rng, publisher);
+                let txn = package.publish_transaction(rng, publisher, init_txn_factory);
+                packages.push(package);
+                txn
+            },
+        );
+        info!("Publishing {} additional packages", txns.len());
+        execute_across_source_accounts(txn_executor, &txns, num_publishers)
+            .await
+            .unwrap();
+        info!("Done publishing {} additional packages", txns.len());
+
+        let mut all_packages = self.packages.as_ref().clone();
+        all_packages.extend(packages);
+        self.packages = Arc::new(all_packages);
+        self.module_cursor = Some(Arc::new(AtomicUsize::new(0)));
+        self
+    }
+
+    /// Makes every generated payload unique, for entry points that support it
+    /// (see `EntryPoints::with_nonce`), by passing a nonce from a counter shared
+    /// by all generators. Used to defeat caching in the execution pipeline.
+    pub fn with_unique_payloads(mut self) -> Self {
+        self.nonces = Some(Arc::new(AtomicU64::new(0)));
+        self
+    }
+
+    /// Enables re-fetching the ABI of the called modules in `refresh_templates`,
+    /// so that arguments keep matching the entry function after a module upgrade.
+    pub fn with_abi_refresh(mut self) -> Self {
+        self.refetch_abi = true;
+        self
+    }
+
+    /// Re-fetches the ABI of each called module, and rebuilds the entry function templates
+    /// for the modules whose entry function signature no longer matches the published package.
+    /// Meant to be called at phase boundaries, generators pick up the templates immediately.
+    /// Returns the number of modules called through a template (instead of the entry point).
+    pub async fn refresh_templates(&self, txn_executor: &dyn TransactionExecutor) -> Result<usize> {
+        if !self.refetch_abi {
+            return Ok(0);
+        }
+
+        let function = self.entry_point.function_name();
+        let mut templates = Vec::with_capacity(self.packages.len());
+        for package in self.packages.iter() {
+            let module_id = package.module_id();
+            let abi = txn_executor
+                .get_module(*module_id.address(), module_id.name().as_str())
+                .await?;
+            let published =
+                EntryFunctionTemplate::from_abi(module_id.clone(), &package.module_abi(), function);
+            let current = EntryFunctionTemplate::from_abi(module_id.clone(), &abi, function);
+            if current.is_none() {
+                warn!(
+                    "Entry function {} not found in {}, using the published signature",
+                    function, module_id
+                );
+            }
+            templates.push(current.filter(|current| Some(current) != published.as_ref()));
         }
+
+        let num_rebuilt = templates.iter().flatten().count();
+        info!(
+            "Rebuilt {} entry function templates for {}",
+            num_rebuilt, function
+        );
+        *self.templates.write() = templates;
+        Ok(num_rebuilt)
     }
 }
 
 #[async_trait]
 impl TransactionGeneratorCreator for CallCustomModulesCreator {
     async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
-        Box::new(CallCustomModulesGenerator::new(
-            StdRng::from_entropy(),
+        let generator = CallCustomModulesGenerator::new(
+            ResumableRng::from_entropy(),
             self.txn_factory.clone(),
             self.packages.clone(),
             self.entry_point,
-        ))
+            self.templates.clone(),
+            self.nonces.clone(),
+        );
+        Box::new(match &self.module_cursor {
+            Some(module_cursor) => generator.with_module_rotation(module_cursor.clone()),
+            None => generator,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::in_memory_executor::InMemoryExecutor;
+    use aptos_crypto::HashValue;
+    use aptos_sdk::{transaction_builder::TransactionFactory, types::chain_id::ChainId};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_flash_loan_single_transaction() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let mut accounts = (0..3)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let mut generator = CallCustomModulesGenerator::new(
+            rng,
+            txn_factory,
+            Arc::new(vec![Package::simple()]),
+            EntryPoints::FlashLoan { steps: 5 },
+            Arc::new(RwLock::new(Vec::new())),
+            None,
+        );
+
+        // borrow, steps and repay all happen within a single entry function call
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 1);
+        assert_eq!(txns.len(), accounts.len());
+        for txn in txns {
+            let entry_function = txn.payload().clone().into_entry_function();
+            assert_eq!(entry_function.function().as_str(), "flash_loan");
+            assert_eq!(entry_function.args(), &[bcs::to_bytes(&5u64).unwrap()]);
+        }
+    }
+
+    #[test]
+    fn test_raw_transactions_match_signed() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut publishers = (0..2)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let mut package_handler = PackageHandler::new();
+        let packages = Arc::new(
+            (0..4)
+                .map(|i| package_handler.pick_new_package(&mut rng, &mut publishers[i % 2]))
+                .collect::<Vec<_>>(),
+        );
+        let new_generator = || {
+            CallCustomModulesGenerator::new(
+                ResumableRng::seed_from_u64(1),
+                SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+                packages.clone(),
+                EntryPoints::BytesMakeOrChange {
+                    data_length: Some(32),
+                },
+                Arc::new(RwLock::new(Vec::new())),
+                None,
+            )
+        };
+        let new_accounts = || {
+            let mut rng = ResumableRng::seed_from_u64(2);
+            (0..3)
+                .map(|_| LocalAccount::generate(&mut rng))
+                .collect::<Vec<_>>()
+        };
+
+        let mut accounts = new_accounts();
+        let signed = new_generator()
+            .generate_transactions(accounts.iter_mut().collect(), 4)
+            .into_iter()
+            .map(|txn| (txn.sender(), txn.payload().clone()))
+            .collect::<Vec<_>>();
+        let mut raw_accounts = new_accounts();
+        let raw = new_generator()
+            .generate_raw_transactions(raw_accounts.iter_mut().collect(), 4)
+            .into_iter()
+            .map(|txn| (txn.sender(), txn.into_payload()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(raw.len(), 12);
+        assert_eq!(raw, signed);
+        assert!(raw_accounts
+            .iter()
+            .all(|account| account.sequence_number() == 4));
+    }
+
+    #[test]
+    fn test_module_rotation_touches_all_modules() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let mut publishers = (0..2)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let mut package_handler = PackageHandler::new();
+        let packages = (0..10)
+            .map(|i| package_handler.pick_new_package(&mut rng, &mut publishers[i % 2]))
+            .collect::<Vec<_>>();
+        let module_ids = packages
+            .iter()
+            .map(|package| package.module_id())
+            .collect::<Vec<_>>();
+        assert_eq!(module_ids.iter().collect::<HashSet<_>>().len(), 10);
+
+        let mut accounts = (0..4)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let mut generator = CallCustomModulesGenerator::new(
+            rng,
+            txn_factory,
+            Arc::new(packages),
+            EntryPoints::Nop,
+            Arc::new(RwLock::new(Vec::new())),
+            None,
+        )
+        .with_module_rotation(Arc::new(AtomicUsize::new(0)));
+
+        // Modules are called in a cycle, so each is evicted from an LRU cache before reuse
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 5);
+        assert_eq!(txns.len(), 20);
+        for (i, txn) in txns.iter().enumerate() {
+            let entry_function = txn.payload().clone().into_entry_function();
+            assert_eq!(entry_function.module(), &module_ids[i % 10]);
+        }
+    }
+
+    #[test]
+    fn test_gas_exhaustion_below_loop_requirement() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()))
+            .with_max_gas_amount(gas_exhaustion_min_gas_units() - 1);
+        let mut accounts = (0..3)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let mut generator = CallCustomModulesGenerator::new(
+            rng,
+            txn_factory,
+            Arc::new(vec![Package::simple()]),
+            EntryPoints::Loopy {
+                loop_count: Some(GAS_EXHAUSTION_LOOP_COUNT),
+            },
+            Arc::new(RwLock::new(Vec::new())),
+            None,
+        );
+
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 1);
+        assert_eq!(txns.len(), accounts.len());
+        for txn in txns {
+            let entry_function = txn.payload().clone().into_entry_function();
+            assert_eq!(entry_function.function().as_str(), "loopy");
+            assert_eq!(entry_function.args(), &[bcs::to_bytes(
+                &GAS_EXHAUSTION_LOOP_COUNT
+            )
+            .unwrap()]);
+            assert!(txn.max_gas_amount() < gas_exhaustion_min_gas_units());
+        }
+
+        // The max gas amount passes validation, so the transactions run out of gas in execution
+        let gas_params = AptosGasParameters::initial();
+        let max_gas_amount = gas_exhaustion_min_gas_units() - 1;
+        assert!(
+            max_gas_amount * u64::from(gas_params.txn.gas_unit_scaling_factor)
+                >= u64::from(gas_params.txn.min_transaction_gas_units)
+        );
+        assert!(max_gas_amount <= u64::from(gas_params.txn.maximum_number_of_gas_units));
+    }
+
+    #[test]
+    fn test_shared_counter_single_module() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let package = Package::simple();
+        let module_id = package.module_id();
+        let mut accounts = (0..3)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let mut generator = CallCustomModulesGenerator::new(
+            rng,
+            txn_factory,
+            Arc::new(vec![package]),
+            EntryPoints::IncrementSharedCounter { increments: 7 },
+            Arc::new(RwLock::new(Vec::new())),
+            None,
+        );
+
+        // All senders call into the same module, writing the counter of its publisher
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 2);
+        assert_eq!(txns.len(), 2 * accounts.len());
+        for txn in txns {
+            let entry_function = txn.payload().clone().into_entry_function();
+            assert_eq!(entry_function.module(), &module_id);
+            assert_eq!(
+                entry_function.function().as_str(),
+                "increment_shared_counter"
+            );
+            assert_eq!(entry_function.args(), &[bcs::to_bytes(&7u64).unwrap()]);
+        }
+    }
+
+    #[test]
+    fn test_resource_group_members_touched() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let mut accounts = (0..3)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let mut generator = CallCustomModulesGenerator::new(
+            rng,
+            txn_factory,
+            Arc::new(vec![Package::simple()]),
+            EntryPoints::ResourceGroupOps { members_touched: 3 },
+            Arc::new(RwLock::new(Vec::new())),
+            None,
+        );
+
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 1);
+        assert_eq!(txns.len(), accounts.len());
+        for txn in txns {
+            let entry_function = txn.payload().clone().into_entry_function();
+            assert_eq!(entry_function.function().as_str(), "touch_resource_group");
+            assert_eq!(entry_function.args(), &[bcs::to_bytes(&3u64).unwrap()]);
+        }
+    }
+
+    #[test]
+    fn test_heavy_read_iterations_argument() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let mut accounts = (0..3)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let mut generator = CallCustomModulesGenerator::new(
+            rng,
+            txn_factory,
+            Arc::new(vec![Package::simple()]),
+            EntryPoints::HeavyRead { iterations: 500 },
+            Arc::new(RwLock::new(Vec::new())),
+            None,
+        );
+
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 1);
+        assert_eq!(txns.len(), accounts.len());
+        for txn in txns {
+            let entry_function = txn.payload().clone().into_entry_function();
+            assert_eq!(entry_function.function().as_str(), "heavy_read");
+            assert_eq!(entry_function.args(), &[bcs::to_bytes(&500u64).unwrap()]);
+        }
+    }
+
+    #[test]
+    fn test_unique_payload_hashes() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let packages = Arc::new(vec![Package::simple()]);
+        let nonces = Arc::new(AtomicU64::new(0));
+        let mut accounts = (0..5)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+
+        // Generators share the nonce counter, so payloads are unique across them
+        let mut generators = (0..2)
+            .map(|i| {
+                CallCustomModulesGenerator::new(
+                    ResumableRng::seed_from_u64(i),
+                    txn_factory.clone(),
+                    packages.clone(),
+                    EntryPoints::Nop,
+                    Arc::new(RwLock::new(Vec::new())),
+                    Some(nonces.clone()),
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut payload_hashes = HashSet::new();
+        let mut num_txns = 0;
+        for generator in generators.iter_mut() {
+            for txn in generator.generate_transactions(accounts.iter_mut().collect(), 4) {
+                num_txns += 1;
+                payload_hashes.insert(HashValue::sha3_256_of(
+                    &bcs::to_bytes(txn.payload()).unwrap(),
+                ));
+            }
+        }
+
+        assert_eq!(num_txns, 2 * 5 * 4);
+        assert_eq!(payload_hashes.len(), num_txns);
+    }
+
+    #[tokio::test]
+    async fn test_template_rebuilt_on_upgrade() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let mut accounts = vec![LocalAccount::generate(&mut rng)];
+        let executor = InMemoryExecutor::default();
+        let mut creator = CallCustomModulesCreator::new(
+            &mut rng,
+            txn_factory.clone(),
+            txn_factory,
+            &mut accounts,
+            &executor,
+            EntryPoints::SetId,
+            1,
+        )
+        .await
+        .with_abi_refresh();
+
+        // Unchanged module keeps using the entry point
+        let mut abi = creator.packages[0].module_abi();
+        executor.publish_module(abi.clone());
+        assert_eq!(creator.refresh_templates(&executor).await.unwrap(), 0);
+        assert!(creator.templates.read()[0].is_none());
+
+        // Upgraded module, with an additional parameter
+        let set_id = abi
+            .exposed_functions
+            .iter_mut()
+            .find(|f| f.name.as_str() == "set_id")
+            .unwrap();
+        let num_params = set_id.params.len();
+        set_id.params.push(MoveType::Bool);
+        executor.publish_module(abi);
+        assert_eq!(creator.refresh_templates(&executor).await.unwrap(), 1);
+        let template = creator.templates.read()[0].clone().unwrap();
+        assert_eq!(template.params().len(), num_params);
+        assert_eq!(template.params().last(), Some(&MoveType::Bool));
+
+        let txns = creator
+            .create_transaction_generator()
+            .await
+            .generate_transactions(accounts.iter_mut().collect(), 1);
+        let payload = txns[0].payload().clone().into_entry_function();
+        assert_eq!(payload.args().len(), template.params().len());
     }
 }
@@ -0,0 +1,234 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{CounterState, TransactionExecutor};
+use anyhow::{bail, Result};
+use aptos_infallible::Mutex;
+use aptos_rest_client::aptos_api_types::MoveModule;
+use aptos_sdk::{
+    move_types::{account_address::AccountAddress, language_storage::StructTag},
+    types::transaction::SignedTransaction,
+};
+use async_trait::async_trait;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{sync::atomic::Ordering, time::Duration};
+
+/// Probabilities (between 0 and 1) with which `ChaosExecutor` disturbs a call.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ChaosConfig {
+    /// Probability of delaying the call by up to `max_delay`, before anything else.
+    pub delay_probability: f64,
+    pub max_delay: Duration,
+    /// Probability of silently dropping all transactions of the call.
+    pub drop_probability: f64,
+    /// Probability of failing the call, without passing it on.
+    pub failure_probability: f64,
+}
+
+enum Fault {
+    Drop,
+    Fail,
+}
+
+/// Wrapper that injects latency, dropped transactions and failures into the
+/// `execute_transactions_with_counter` calls, per `ChaosConfig`, to test how robust
+/// the driver is. Dropped transactions are counted as wait failures, as they never
+/// commit, and failed calls as submit failures. The RNG is seeded, so runs are
+/// reproducible. Queries, and setup through `execute_transactions`, are delegated unchanged.
+pub struct ChaosExecutor<E> {
+    inner: E,
+    config: ChaosConfig,
+    rng: Mutex<StdRng>,
+}
+
+impl<E: TransactionExecutor> ChaosExecutor<E> {
+    pub fn new(inner: E, config: ChaosConfig, seed: u64) -> Self {
+        Self {
+            inner,
+            config,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+        }
+    }
+
+    fn roll(&self) -> (Option<Duration>, Option<Fault>) {
+        let mut rng = self.rng.lock();
+        let delay = if rng.gen_bool(self.config.delay_probability) {
+            Some(self.config.max_delay.mul_f64(rng.gen::<f64>()))
+        } else {
+            None
+        };
+        let roll = rng.gen::<f64>();
+        let fault = if roll < self.config.failure_probability {
+            Some(Fault::Fail)
+        } else if roll < self.config.failure_probability + self.config.drop_probability {
+            Some(Fault::Drop)
+        } else {
+            None
+        };
+        (delay, fault)
+    }
+}
+
+#[async_trait]
+impl<E: TransactionExecutor> TransactionExecutor for ChaosExecutor<E> {
+    async fn get_account_balance(&self, account_address: AccountAddress) -> Result<u64> {
+        self.inner.get_account_balance(account_address).await
+    }
+
+    async fn get_coin_balance(
+        &self,
+        account_address: AccountAddress,
+        coin_type: StructTag,
+    ) -> Result<u64> {
+        self.inner
+            .get_coin_balance(account_address, coin_type)
+            .await
+    }
+
+    async fn query_sequence_number(&self, account_address: AccountAddress) -> Result<u64> {
+        self.inner.query_sequence_number(account_address).await
+    }
+
+    async fn query_sequence_numbers(&self, addresses: &[AccountAddress]) -> Result<Vec<u64>> {
+        self.inner.query_sequence_numbers(addresses).await
+    }
+
+    async fn execute_transactions(&self, txns: &[SignedTransaction]) -> Result<()> {
+        self.inner.execute_transactions(txns).await
+    }
+
+    async fn execute_transactions_with_counter(
+        &self,
+        txns: &[SignedTransaction],
+        state: &CounterState,
+    ) -> Result<()> {
+        let (delay, fault) = self.roll();
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+        match fault {
+            Some(Fault::Fail) => {
+                state.submit_failures[0].fetch_add(txns.len(), Ordering::Relaxed);
+                bail!("Injected failure executing {} txns", txns.len());
+            },
+            Some(Fault::Drop) => {
+                state.wait_failures[0].fetch_add(txns.len(), Ordering::Relaxed);
+                Ok(())
+            },
+            None => {
+                self.inner
+                    .execute_transactions_with_counter(txns, state)
+                    .await
+            },
+        }
+    }
+
+    fn create_counter_state(&self) -> CounterState {
+        self.inner.create_counter_state()
+    }
+
+    async fn get_module(&self, address: AccountAddress, module_name: &str) -> Result<MoveModule> {
+        self.inner.get_module(address, module_name).await
+    }
+
+    async fn estimate_gas(&self, txn: &SignedTransaction) -> Result<u64> {
+        self.inner.estimate_gas(txn).await
+    }
+
+    async fn mempool_load(&self) -> Result<f64> {
+        self.inner.mempool_load().await
+    }
+
+    async fn min_gas_unit_price(&self) -> Result<u64> {
+        self.inner.min_gas_unit_price().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::in_memory_executor::InMemoryExecutor;
+    use aptos_sdk::{
+        transaction_builder::{aptos_stdlib, TransactionFactory},
+        types::{chain_id::ChainId, LocalAccount},
+    };
+
+    fn transfers(num_txns: usize) -> Vec<SignedTransaction> {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut account = LocalAccount::generate(&mut rng);
+        let txn_factory = TransactionFactory::new(ChainId::test());
+        (0..num_txns)
+            .map(|_| {
+                account.sign_with_transaction_builder(
+                    txn_factory.payload(aptos_stdlib::aptos_coin_transfer(AccountAddress::ONE, 1)),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_giving_up() {
+        let txns = transfers(5);
+        let executor = ChaosExecutor::new(
+            InMemoryExecutor::default().with_sequence_number(AccountAddress::ONE, 3),
+            ChaosConfig {
+                failure_probability: 1.0,
+                ..ChaosConfig::default()
+            },
+            0,
+        );
+        let err = executor
+            .execute_transactions_with_retries(&txns, 2, Duration::from_millis(1))
+            .await
+            .unwrap_err();
+        // Failures of all three attempts are counted
+        assert!(format!("{:#}", err).contains("after 3 attempts, success 0, failed submit [15]"));
+
+        let executor = ChaosExecutor::new(InMemoryExecutor::default(), ChaosConfig::default(), 0);
+        assert!(executor
+            .execute_transactions_with_retries(&txns, 0, Duration::from_millis(1))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_failure_probability() {
+        let txns = transfers(5);
+        let executor = ChaosExecutor::new(
+            InMemoryExecutor::default().with_sequence_number(AccountAddress::ONE, 3),
+            ChaosConfig {
+                failure_probability: 1.0,
+                ..ChaosConfig::default()
+            },
+            0,
+        );
+        let state = executor.create_counter_state();
+        for _ in 0..3 {
+            assert!(executor
+                .execute_transactions_with_counter(&txns, &state)
+                .await
+                .is_err());
+        }
+        assert_eq!(state.submit_failures[0].load(Ordering::Relaxed), 15);
+        assert_eq!(state.successes.load(Ordering::Relaxed), 0);
+        assert_eq!(
+            executor
+                .query_sequence_number(AccountAddress::ONE)
+                .await
+                .unwrap(),
+            3
+        );
+
+        let executor = ChaosExecutor::new(InMemoryExecutor::default(), ChaosConfig::default(), 0);
+        let state = executor.create_counter_state();
+        for _ in 0..3 {
+            executor
+                .execute_transactions_with_counter(&txns, &state)
+                .await
+                .unwrap();
+        }
+        assert_eq!(state.submit_failures[0].load(Ordering::Relaxed), 0);
+        assert_eq!(state.wait_failures[0].load(Ordering::Relaxed), 0);
+        assert_eq!(state.successes.load(Ordering::Relaxed), 15);
+    }
+}
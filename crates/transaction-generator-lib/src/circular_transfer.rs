@@ -0,0 +1,142 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    shared_txn_factory::SharedTransactionFactory, TransactionGenerator,
+    TransactionGeneratorCreator, SEND_AMOUNT,
+};
+use aptos_logger::{sample, sample::SampleRate, warn};
+use aptos_sdk::{
+    transaction_builder::aptos_stdlib,
+    types::{transaction::SignedTransaction, LocalAccount},
+};
+use async_trait::async_trait;
+use rand::thread_rng;
+use std::time::Duration;
+
+/// Generates transfers along rings of `chain_length` accounts, where every account sends to
+/// the next one, and the last one back to the first. All transfers of a ring are generated
+/// together, so that they land in the same block, and each reads the balance the previous
+/// one writes, maximizing the conflicts the parallel executor has to resolve.
+/// Passed accounts are split into rings, and accounts left over after the last full ring
+/// don't send anything, so nothing is generated with fewer than `chain_length` accounts.
+pub struct CircularTransferGenerator {
+    txn_factory: SharedTransactionFactory,
+    chain_length: usize,
+}
+
+impl CircularTransferGenerator {
+    pub fn new(txn_factory: SharedTransactionFactory, chain_length: usize) -> Self {
+        assert!(chain_length >= 2, "Invalid chain length {}", chain_length);
+        Self {
+            txn_factory,
+            chain_length,
+        }
+    }
+}
+
+impl TransactionGenerator for CircularTransferGenerator {
+    fn generate_transactions(
+        &mut self,
+        mut accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        if accounts.len() % self.chain_length != 0 {
+            sample!(
+                SampleRate::Duration(Duration::from_secs(60)),
+                warn!(
+                    "{} accounts can't be arranged into rings of {}, {} are unused",
+                    accounts.len(),
+                    self.chain_length,
+                    accounts.len() % self.chain_length
+                )
+            );
+        }
+
+        let mut requests = Vec::with_capacity(
+            accounts.len() / self.chain_length * self.chain_length * transactions_per_account,
+        );
+        for ring in accounts.chunks_exact_mut(self.chain_length) {
+            let addresses = ring
+                .iter()
+                .map(|account| account.address())
+                .collect::<Vec<_>>();
+            for _ in 0..transactions_per_account {
+                for (i, sender) in ring.iter_mut().enumerate() {
+                    let receiver = addresses[(i + 1) % addresses.len()];
+                    let payload = aptos_stdlib::aptos_coin_transfer(receiver, SEND_AMOUNT);
+                    requests.push(sender.sign_with_transaction_builder(
+                        self.txn_factory.payload(payload, &mut thread_rng()),
+                    ));
+                }
+            }
+        }
+        requests
+    }
+}
+
+pub struct CircularTransferCreator {
+    txn_factory: SharedTransactionFactory,
+    chain_length: usize,
+}
+
+impl CircularTransferCreator {
+    pub fn new(txn_factory: SharedTransactionFactory, chain_length: usize) -> Self {
+        Self {
+            txn_factory,
+            chain_length,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for CircularTransferCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(CircularTransferGenerator::new(
+            self.txn_factory.clone(),
+            self.chain_length,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resumable_rng::ResumableRng;
+    use aptos_sdk::{
+        bcs, move_types::account_address::AccountAddress, transaction_builder::TransactionFactory,
+        types::chain_id::ChainId,
+    };
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_transfers_form_rings() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut accounts = (0..7)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let addresses = accounts
+            .iter()
+            .map(|account| account.address())
+            .collect::<Vec<_>>();
+        let mut generator = CircularTransferGenerator::new(
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            3,
+        );
+
+        // Two rings of three, the last account is left over
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 2);
+        assert_eq!(txns.len(), 12);
+        for (i, txn) in txns.iter().enumerate() {
+            let ring = &addresses[i / 6 * 3..i / 6 * 3 + 3];
+            let entry_function = txn.payload().clone().into_entry_function();
+            let receiver: AccountAddress = bcs::from_bytes(&entry_function.args()[0]).unwrap();
+            assert_eq!(txn.sender(), ring[i % 3]);
+            assert_eq!(receiver, ring[(i + 1) % 3]);
+        }
+
+        // Not enough accounts for a single ring
+        let txns = generator.generate_transactions(accounts.iter_mut().take(2).collect(), 2);
+        assert!(txns.is_empty());
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    account_generator::add_to_sized_pool,
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    TransactionGenerator, TransactionGeneratorCreator,
+};
+use aptos_infallible::RwLock;
+use aptos_sdk::{
+    move_types::account_address::AccountAddress,
+    transaction_builder::aptos_stdlib,
+    types::{transaction::SignedTransaction, LocalAccount},
+};
+use async_trait::async_trait;
+use rand::{Rng, SeedableRng};
+use std::sync::Arc;
+
+/// Generates explicit account creations (`aptos_account::create_account`, which goes through
+/// `0x1::account::create_account`) for fresh addresses, without any funding.
+/// The created accounts have no coin balance, and nobody holds their keys, so they can only
+/// receive transactions. If `add_created_addresses_to_pool` is set, their addresses are added
+/// to the addresses pool (up to `max_working_set`), for later transfers to target them.
+pub struct CreateEmptyAccountGenerator {
+    rng: ResumableRng,
+    txn_factory: SharedTransactionFactory,
+    addresses_pool: Arc<RwLock<Vec<AccountAddress>>>,
+    add_created_addresses_to_pool: bool,
+    max_working_set: usize,
+}
+
+impl CreateEmptyAccountGenerator {
+    pub fn new(
+        rng: ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        addresses_pool: Arc<RwLock<Vec<AccountAddress>>>,
+        add_created_addresses_to_pool: bool,
+        max_working_set: usize,
+    ) -> Self {
+        Self {
+            rng,
+            txn_factory,
+            addresses_pool,
+            add_created_addresses_to_pool,
+            max_working_set,
+        }
+    }
+}
+
+impl TransactionGenerator for CreateEmptyAccountGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
+        let mut new_addresses = Vec::with_capacity(accounts.len() * transactions_per_account);
+        for account in accounts {
+            for _ in 0..transactions_per_account {
+                let address = AccountAddress::new(self.rng.gen());
+                requests.push(
+                    account.sign_with_transaction_builder(self.txn_factory.payload(
+                        aptos_stdlib::aptos_account_create_account(address),
+                        &mut self.rng,
+                    )),
+                );
+                new_addresses.push(address);
+            }
+        }
+
+        if self.add_created_addresses_to_pool {
+            add_to_sized_pool(
+                self.addresses_pool.as_ref(),
+                new_addresses,
+                self.max_working_set,
+                &mut self.rng,
+            );
+        }
+        requests
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
+}
+
+pub struct CreateEmptyAccountCreator {
+    txn_factory: SharedTransactionFactory,
+    addresses_pool: Arc<RwLock<Vec<AccountAddress>>>,
+    add_created_addresses_to_pool: bool,
+    max_working_set: usize,
+}
+
+impl CreateEmptyAccountCreator {
+    pub fn new(
+        txn_factory: SharedTransactionFactory,
+        addresses_pool: Arc<RwLock<Vec<AccountAddress>>>,
+        add_created_addresses_to_pool: bool,
+        max_working_set: usize,
+    ) -> Self {
+        if add_created_addresses_to_pool {
+            addresses_pool.write().reserve(max_working_set);
+        }
+
+        Self {
+            txn_factory,
+            addresses_pool,
+            add_created_addresses_to_pool,
+            max_working_set,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for CreateEmptyAccountCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(CreateEmptyAccountGenerator::new(
+            ResumableRng::from_entropy(),
+            self.txn_factory.clone(),
+            self.addresses_pool.clone(),
+            self.add_created_addresses_to_pool,
+            self.max_working_set,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{bcs, transaction_builder::TransactionFactory, types::chain_id::ChainId};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_creates_fresh_addresses() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut accounts = (0..2)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let addresses_pool = Arc::new(RwLock::new(Vec::new()));
+        let mut generator = CreateEmptyAccountGenerator::new(
+            rng,
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            addresses_pool.clone(),
+            true,
+            100,
+        );
+
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 3);
+        assert_eq!(txns.len(), 6);
+        let created = txns
+            .iter()
+            .map(|txn| {
+                let entry_function = txn.payload().clone().into_entry_function();
+                assert_eq!(entry_function.function().as_str(), "create_account");
+                bcs::from_bytes::<AccountAddress>(&entry_function.args()[0]).unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(created.iter().collect::<HashSet<_>>().len(), 6);
+        assert!(created
+            .iter()
+            .all(|address| accounts.iter().all(|account| account.address() != *address)));
+        assert_eq!(*addresses_pool.read(), created);
+
+        // Without adding to the pool, the created addresses are not targeted later on
+        let mut generator = CreateEmptyAccountGenerator::new(
+            ResumableRng::seed_from_u64(1),
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            addresses_pool.clone(),
+            false,
+            100,
+        );
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 3);
+        assert_eq!(txns.len(), 6);
+        assert_eq!(*addresses_pool.read(), created);
+    }
+}
@@ -0,0 +1,289 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::TransactionExecutor;
+use crate::{
+    publishing::publish_util::PackageHandler,
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    TransactionGenerator, TransactionGeneratorCreator,
+};
+use aptos_logger::info;
+use aptos_sdk::{
+    move_types::{account_address::AccountAddress, language_storage::TypeTag},
+    transaction_builder::aptos_stdlib,
+    types::{transaction::SignedTransaction, LocalAccount},
+};
+use async_trait::async_trait;
+use rand::{seq::SliceRandom, SeedableRng};
+use std::{collections::HashSet, sync::Arc};
+
+const INITIAL_COIN_BALANCE: u64 = 1_000_000_000;
+
+/// Transfers a custom coin between accounts registered for it.
+/// The coin is initialized, and the accounts are registered and funded, during setup.
+/// Accounts that were not registered during setup are registered first,
+/// and no transfers are generated from them in the same batch.
+pub struct CustomCoinGenerator {
+    rng: ResumableRng,
+    txn_factory: SharedTransactionFactory,
+    coin_type: TypeTag,
+    receivers: Arc<Vec<AccountAddress>>,
+    registered: HashSet<AccountAddress>,
+}
+
+impl CustomCoinGenerator {
+    pub fn new(
+        rng: ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        coin_type: TypeTag,
+        receivers: Arc<Vec<AccountAddress>>,
+    ) -> Self {
+        let registered = receivers.iter().cloned().collect();
+        Self {
+            rng,
+            txn_factory,
+            coin_type,
+            receivers,
+            registered,
+        }
+    }
+
+    pub fn is_registered(&self, address: &AccountAddress) -> bool {
+        self.registered.contains(address)
+    }
+}
+
+impl TransactionGenerator for CustomCoinGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
+        for account in accounts {
+            if !self.registered.contains(&account.address()) {
+                requests.push(
+                    account.sign_with_transaction_builder(self.txn_factory.payload(
+                        aptos_stdlib::managed_coin_register(self.coin_type.clone()),
+                        &mut self.rng,
+                    )),
+                );
+                self.registered.insert(account.address());
+                continue;
+            }
+            for _ in 0..transactions_per_account {
+                let receiver = *self.receivers.choose(&mut self.rng).unwrap();
+                requests.push(
+                    account.sign_with_transaction_builder(self.txn_factory.payload(
+                        aptos_stdlib::coin_transfer(self.coin_type.clone(), receiver, 1),
+                        &mut self.rng,
+                    )),
+                );
+            }
+        }
+        requests
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
+}
+
+pub struct CustomCoinGeneratorCreator {
+    txn_factory: SharedTransactionFactory,
+    coin_type: TypeTag,
+    registered: Arc<Vec<AccountAddress>>,
+}
+
+impl CustomCoinGeneratorCreator {
+    pub async fn new(
+        rng: &mut ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        init_txn_factory: SharedTransactionFactory,
+        accounts: &mut [LocalAccount],
+        txn_executor: &dyn TransactionExecutor,
+    ) -> Self {
+        let publisher = accounts.get_mut(0).expect("Must have accounts");
+
+        // Coin type must be defined under the account initializing it
+        let package = PackageHandler::new().pick_package(rng, publisher);
+        let coin_type = package.coin_type();
+        let publish_txn = package.publish_transaction(rng, publisher, &init_txn_factory);
+        let initialize_txn = publisher.sign_with_transaction_builder(init_txn_factory.payload(
+            aptos_stdlib::managed_coin_initialize(
+                coin_type.clone(),
+                b"Custom Coin".to_vec(),
+                b"CC".to_vec(),
+                8,
+                false,
+            ),
+            rng,
+        ));
+        info!("Initializing custom coin {}", coin_type);
+        txn_executor
+            .execute_transactions(&[publish_txn, initialize_txn])
+            .await
+            .unwrap();
+
+        let register_txns = accounts
+            .iter_mut()
+            .map(|account| {
+                account.sign_with_transaction_builder(
+                    init_txn_factory
+                        .payload(aptos_stdlib::managed_coin_register(coin_type.clone()), rng),
+                )
+            })
+            .collect::<Vec<_>>();
+        info!(
+            "Registering {} accounts for custom coin",
+            register_txns.len()
+        );
+        txn_executor
+            .execute_transactions(&register_txns)
+            .await
+            .unwrap();
+
+        let registered = accounts
+            .iter()
+            .map(|account| account.address())
+            .collect::<Vec<_>>();
+        let publisher = &mut accounts[0];
+        let mint_txns = registered
+            .iter()
+            .map(|address| {
+                publisher.sign_with_transaction_builder(init_txn_factory.payload(
+                    aptos_stdlib::managed_coin_mint(
+                        coin_type.clone(),
+                        *address,
+                        INITIAL_COIN_BALANCE,
+                    ),
+                    rng,
+                ))
+            })
+            .collect::<Vec<_>>();
+        // per account limit is 100
+        for chunk in mint_txns.chunks(100) {
+            txn_executor.execute_transactions(chunk).await.unwrap();
+        }
+        info!("Done minting custom coin to {} accounts", registered.len());
+
+        Self {
+            txn_factory,
+            coin_type,
+            registered: Arc::new(registered),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for CustomCoinGeneratorCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(CustomCoinGenerator::new(
+            ResumableRng::from_entropy(),
+            self.txn_factory.clone(),
+            self.coin_type.clone(),
+            self.registered.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::in_memory_executor::InMemoryExecutor;
+    use aptos_sdk::{
+        bcs,
+        transaction_builder::TransactionFactory,
+        types::{chain_id::ChainId, transaction::TransactionPayload},
+    };
+
+    fn function(txn: &SignedTransaction) -> String {
+        match txn.payload() {
+            TransactionPayload::EntryFunction(entry_function) => {
+                entry_function.function().to_string()
+            },
+            _ => panic!("Custom coin must only call entry functions"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registered_before_transfers() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut accounts = (0..4)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let executor = InMemoryExecutor::default();
+        let creator = CustomCoinGeneratorCreator::new(
+            &mut rng,
+            txn_factory.clone(),
+            txn_factory.clone(),
+            &mut accounts,
+            &executor,
+        )
+        .await;
+
+        // Setup registers all accounts, before minting to them
+        let functions = executor
+            .executed
+            .lock()
+            .iter()
+            .map(function)
+            .collect::<Vec<_>>();
+        assert_eq!(functions[..2], ["publish_package_txn", "initialize"]);
+        assert!(functions[2..6]
+            .iter()
+            .all(|function| function == "register"));
+        assert!(functions[6..].iter().all(|function| function == "mint"));
+        assert_eq!(functions.len(), 2 + 2 * accounts.len());
+
+        let mut generator = CustomCoinGenerator::new(
+            rng.clone(),
+            txn_factory,
+            creator.coin_type.clone(),
+            creator.registered.clone(),
+        );
+        let mut new_accounts = (0..2)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let new_addresses = new_accounts
+            .iter()
+            .map(|account| account.address())
+            .collect::<Vec<_>>();
+        assert!(new_addresses
+            .iter()
+            .all(|address| !generator.is_registered(address)));
+
+        // Accounts not registered during setup only register in their first batch
+        let txns = generator.generate_transactions(
+            accounts.iter_mut().chain(new_accounts.iter_mut()).collect(),
+            3,
+        );
+        assert_eq!(txns.len(), 3 * accounts.len() + new_accounts.len());
+        for txn in &txns {
+            if new_addresses.contains(&txn.sender()) {
+                assert_eq!(function(txn), "register");
+            } else {
+                assert_eq!(function(txn), "transfer");
+            }
+        }
+        assert!(new_addresses
+            .iter()
+            .all(|address| generator.is_registered(address)));
+
+        // And only transfer afterwards, to accounts registered during setup
+        let txns = generator.generate_transactions(new_accounts.iter_mut().collect(), 3);
+        assert_eq!(txns.len(), 3 * new_accounts.len());
+        for txn in txns {
+            assert_eq!(function(&txn), "transfer");
+            let receiver: AccountAddress =
+                bcs::from_bytes(&txn.payload().clone().into_entry_function().args()[0]).unwrap();
+            assert!(creator.registered.contains(&receiver));
+        }
+    }
+}
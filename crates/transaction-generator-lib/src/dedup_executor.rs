@@ -0,0 +1,181 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{CounterState, TransactionExecutor};
+use anyhow::Result;
+use aptos_crypto::HashValue;
+use aptos_rest_client::aptos_api_types::MoveModule;
+use aptos_sdk::{
+    move_types::{account_address::AccountAddress, language_storage::StructTag},
+    types::transaction::SignedTransaction,
+};
+use async_trait::async_trait;
+use dashmap::DashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Wrapper that makes sure the same transaction (by hash) is never in flight
+/// more than once at the inner executor. Duplicates submitted while the original
+/// is still being executed are dropped (and counted), instead of being passed on.
+/// Queries are delegated unchanged.
+pub struct DedupExecutor<E> {
+    inner: E,
+    in_flight: DashMap<HashValue, ()>,
+    num_dropped: AtomicUsize,
+}
+
+impl<E: TransactionExecutor> DedupExecutor<E> {
+    pub fn new(inner: E) -> Self {
+        Self {
+            inner,
+            in_flight: DashMap::new(),
+            num_dropped: AtomicUsize::new(0),
+        }
+    }
+
+    /// Number of transactions dropped so far, because they were already in flight.
+    pub fn num_dropped(&self) -> usize {
+        self.num_dropped.load(Ordering::Relaxed)
+    }
+
+    fn claim(&self, txns: &[SignedTransaction]) -> InFlightGuard<'_> {
+        let mut guard = InFlightGuard {
+            in_flight: &self.in_flight,
+            hashes: Vec::with_capacity(txns.len()),
+            txns: Vec::with_capacity(txns.len()),
+        };
+        for txn in txns {
+            let hash = txn.clone().committed_hash();
+            if self.in_flight.insert(hash, ()).is_some() {
+                self.num_dropped.fetch_add(1, Ordering::Relaxed);
+            } else {
+                guard.hashes.push(hash);
+                guard.txns.push(txn.clone());
+            }
+        }
+        guard
+    }
+}
+
+/// Transactions claimed for execution, released from the in-flight set once resolved
+/// (or if the execution is cancelled).
+struct InFlightGuard<'a> {
+    in_flight: &'a DashMap<HashValue, ()>,
+    hashes: Vec<HashValue>,
+    txns: Vec<SignedTransaction>,
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        for hash in &self.hashes {
+            self.in_flight.remove(hash);
+        }
+    }
+}
+
+#[async_trait]
+impl<E: TransactionExecutor> TransactionExecutor for DedupExecutor<E> {
+    async fn get_account_balance(&self, account_address: AccountAddress) -> Result<u64> {
+        self.inner.get_account_balance(account_address).await
+    }
+
+    async fn get_coin_balance(
+        &self,
+        account_address: AccountAddress,
+        coin_type: StructTag,
+    ) -> Result<u64> {
+        self.inner
+            .get_coin_balance(account_address, coin_type)
+            .await
+    }
+
+    async fn query_sequence_number(&self, account_address: AccountAddress) -> Result<u64> {
+        self.inner.query_sequence_number(account_address).await
+    }
+
+    async fn query_sequence_numbers(&self, addresses: &[AccountAddress]) -> Result<Vec<u64>> {
+        self.inner.query_sequence_numbers(addresses).await
+    }
+
+    async fn execute_transactions(&self, txns: &[SignedTransaction]) -> Result<()> {
+        let guard = self.claim(txns);
+        if guard.txns.is_empty() {
+            return Ok(());
+        }
+        self.inner.execute_transactions(&guard.txns).await
+    }
+
+    async fn execute_transactions_with_counter(
+        &self,
+        txns: &[SignedTransaction],
+        state: &CounterState,
+    ) -> Result<()> {
+        let guard = self.claim(txns);
+        if guard.txns.is_empty() {
+            return Ok(());
+        }
+        self.inner
+            .execute_transactions_with_counter(&guard.txns, state)
+            .await
+    }
+
+    fn create_counter_state(&self) -> CounterState {
+        self.inner.create_counter_state()
+    }
+
+    async fn get_module(&self, address: AccountAddress, module_name: &str) -> Result<MoveModule> {
+        self.inner.get_module(address, module_name).await
+    }
+
+    async fn estimate_gas(&self, txn: &SignedTransaction) -> Result<u64> {
+        self.inner.estimate_gas(txn).await
+    }
+
+    async fn mempool_load(&self) -> Result<f64> {
+        self.inner.mempool_load().await
+    }
+
+    async fn min_gas_unit_price(&self) -> Result<u64> {
+        self.inner.min_gas_unit_price().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::in_memory_executor::InMemoryExecutor;
+    use aptos_sdk::{
+        transaction_builder::{aptos_stdlib, TransactionFactory},
+        types::{chain_id::ChainId, LocalAccount},
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_concurrent_duplicates_dropped() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut account = LocalAccount::generate(&mut rng);
+        let txn = account.sign_with_transaction_builder(
+            TransactionFactory::new(ChainId::test())
+                .payload(aptos_stdlib::aptos_coin_transfer(AccountAddress::ONE, 1)),
+        );
+        let executor = DedupExecutor::new(
+            InMemoryExecutor::default().with_execution_delay(Duration::from_millis(100)),
+        );
+
+        // Second submission arrives while the first one is still executing
+        let txns = [txn.clone()];
+        let (first, second) = tokio::join!(
+            executor.execute_transactions(&txns),
+            executor.execute_transactions(&txns),
+        );
+        first.unwrap();
+        second.unwrap();
+        assert_eq!(*executor.inner.executed.lock(), vec![txn.clone()]);
+        assert_eq!(executor.num_dropped(), 1);
+
+        // Once resolved, the same transaction can be submitted again
+        executor.execute_transactions(&[txn]).await.unwrap();
+        assert_eq!(executor.inner.executed.lock().len(), 2);
+        assert_eq!(executor.num_dropped(), 1);
+    }
+}
@@ -0,0 +1,353 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::TransactionExecutor;
+use crate::{
+    nft_mint_and_transfer::create_and_fund_account_request,
+    publishing::publish_util::PackageHandler,
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    source_accounts::{execute_across_source_accounts, sign_across_source_accounts},
+    TransactionGenerator, TransactionGeneratorCreator,
+};
+use aptos_logger::info;
+use aptos_sdk::{
+    bcs,
+    move_types::{
+        account_address::AccountAddress,
+        ident_str,
+        language_storage::{ModuleId, TypeTag},
+    },
+    transaction_builder::aptos_stdlib,
+    types::{
+        transaction::{EntryFunction, SignedTransaction, TransactionPayload},
+        utility_coin::APTOS_COIN_TYPE,
+        LocalAccount,
+    },
+};
+use async_trait::async_trait;
+use rand::{Rng, SeedableRng};
+use std::{collections::HashMap, sync::Arc};
+
+/// Initial reserves of both coins of every pool.
+const POOL_RESERVE: u64 = 10_000_000;
+/// Balance the pool accounts are funded with, for the APT reserve and the setup gas.
+const POOL_ACCOUNT_BALANCE: u64 = 2 * POOL_RESERVE;
+/// Amount of the input coin of every swap.
+const SWAP_AMOUNT: u64 = 1_000;
+/// Share of the expected output a swap accepts, as other swaps move the price meanwhile.
+const MIN_OUT_PERCENT: u64 = 50;
+
+/// Pool of APT and a custom coin, defined by the package published under the pool account.
+#[derive(Clone, Debug)]
+pub struct LiquidityPool {
+    pub address: AccountAddress,
+    pub module_id: ModuleId,
+    pub coin_type: TypeTag,
+}
+
+/// Same as the `swap_amount_out` of the Simple package, keeping the product of reserves constant.
+fn swap_amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> u64 {
+    (amount_in as u128 * reserve_out as u128 / (reserve_in as u128 + amount_in as u128)) as u64
+}
+
+fn create_pool_payload(
+    pool: &LiquidityPool,
+    amount_apt: u64,
+    amount_coin: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        pool.module_id.clone(),
+        ident_str!("create_pool").to_owned(),
+        vec![APTOS_COIN_TYPE.clone(), pool.coin_type.clone()],
+        vec![
+            bcs::to_bytes(&amount_apt).unwrap(),
+            bcs::to_bytes(&amount_coin).unwrap(),
+        ],
+    ))
+}
+
+fn swap_payload(
+    pool: &LiquidityPool,
+    amount_in: u64,
+    buy_coin: bool,
+    min_out: u64,
+) -> TransactionPayload {
+    TransactionPayload::EntryFunction(EntryFunction::new(
+        pool.module_id.clone(),
+        ident_str!("swap").to_owned(),
+        vec![APTOS_COIN_TYPE.clone(), pool.coin_type.clone()],
+        vec![
+            bcs::to_bytes(&pool.address).unwrap(),
+            bcs::to_bytes(&amount_in).unwrap(),
+            bcs::to_bytes(&buy_coin).unwrap(),
+            bcs::to_bytes(&min_out).unwrap(),
+        ],
+    ))
+}
+
+/// Swaps between APT and the custom coins of the pools created during setup, buying the coin
+/// of a random pool, or selling it back if the account holds enough of it. Coin balances are
+/// tracked from the minimum outputs of the swaps, so that no swap is generated for more than
+/// the account holds. Swap outputs are estimated from the reserves as moved by this generator
+/// alone, hence the tolerance on the minimum output.
+pub struct DexSwapGenerator {
+    rng: ResumableRng,
+    txn_factory: SharedTransactionFactory,
+    pools: Arc<Vec<LiquidityPool>>,
+    // (APT, coin) reserves of each pool, as seen by this generator
+    reserves: Vec<(u64, u64)>,
+    // lower bound of the coin balance of each account, per pool
+    coin_balances: HashMap<(AccountAddress, usize), u64>,
+}
+
+impl DexSwapGenerator {
+    pub fn new(
+        rng: ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        pools: Arc<Vec<LiquidityPool>>,
+    ) -> Self {
+        assert!(!pools.is_empty(), "No pools to swap in");
+        let reserves = vec![(POOL_RESERVE, POOL_RESERVE); pools.len()];
+        Self {
+            rng,
+            txn_factory,
+            pools,
+            reserves,
+            coin_balances: HashMap::new(),
+        }
+    }
+}
+
+impl TransactionGenerator for DexSwapGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
+        for account in accounts {
+            for _ in 0..transactions_per_account {
+                let idx = self.rng.gen_range(0, self.pools.len());
+                let coin_balance = self
+                    .coin_balances
+                    .entry((account.address(), idx))
+                    .or_default();
+                let buy_coin = *coin_balance < SWAP_AMOUNT || self.rng.gen::<bool>();
+                let (reserve_apt, reserve_coin) = &mut self.reserves[idx];
+                let min_out = if buy_coin {
+                    let amount_out = swap_amount_out(SWAP_AMOUNT, *reserve_apt, *reserve_coin);
+                    *reserve_apt += SWAP_AMOUNT;
+                    *reserve_coin -= amount_out;
+                    let min_out = amount_out * MIN_OUT_PERCENT / 100;
+                    *coin_balance += min_out;
+                    min_out
+                } else {
+                    let amount_out = swap_amount_out(SWAP_AMOUNT, *reserve_coin, *reserve_apt);
+                    *reserve_coin += SWAP_AMOUNT;
+                    *reserve_apt -= amount_out;
+                    *coin_balance -= SWAP_AMOUNT;
+                    amount_out * MIN_OUT_PERCENT / 100
+                };
+                requests.push(
+                    account.sign_with_transaction_builder(self.txn_factory.payload(
+                        swap_payload(&self.pools[idx], SWAP_AMOUNT, buy_coin, min_out),
+                        &mut self.rng,
+                    )),
+                );
+            }
+        }
+        requests
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
+}
+
+pub struct DexSwapCreator {
+    txn_factory: SharedTransactionFactory,
+    pools: Arc<Vec<LiquidityPool>>,
+}
+
+impl DexSwapCreator {
+    /// Creates `pairs` pools, each under a new account funded from the source accounts. The pool
+    /// account publishes the package defining its coin and the pool, initializes the coin, mints
+    /// itself the coin reserve, and creates the pool with both reserves.
+    pub async fn new(
+        rng: &mut ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        init_txn_factory: SharedTransactionFactory,
+        source_accounts: &mut [LocalAccount],
+        txn_executor: &dyn TransactionExecutor,
+        pairs: usize,
+    ) -> Self {
+        assert!(pairs > 0, "Must have pairs to swap");
+        let mut pool_accounts = (0..pairs)
+            .map(|_| LocalAccount::generate(rng))
+            .collect::<Vec<_>>();
+
+        let fund_txns = sign_across_source_accounts(
+            source_accounts,
+            &pool_accounts,
+            |source_account, pool_account| {
+                create_and_fund_account_request(
+                    source_account,
+                    POOL_ACCOUNT_BALANCE,
+                    pool_account.public_key(),
+                    &init_txn_factory,
+                    rng,
+                )
+            },
+        );
+        info!("Funding {} liquidity pool accounts", pool_accounts.len());
+        execute_across_source_accounts(txn_executor, &fund_txns, source_accounts.len())
+            .await
+            .unwrap();
+
+        let mut package_handler = PackageHandler::new();
+        let mut pools = Vec::with_capacity(pairs);
+        let mut setup_txns = Vec::with_capacity(pairs * 5);
+        for pool_account in pool_accounts.iter_mut() {
+            // Coin type must be defined under the account initializing it
+            let package = package_handler.pick_package(rng, pool_account);
+            let pool = LiquidityPool {
+                address: pool_account.address(),
+                module_id: package.module_id(),
+                coin_type: package.coin_type(),
+            };
+            setup_txns.push(package.publish_transaction(rng, pool_account, &init_txn_factory));
+            for payload in [
+                aptos_stdlib::managed_coin_initialize(
+                    pool.coin_type.clone(),
+                    b"Pool Coin".to_vec(),
+                    b"PC".to_vec(),
+                    8,
+                    false,
+                ),
+                aptos_stdlib::managed_coin_register(pool.coin_type.clone()),
+                aptos_stdlib::managed_coin_mint(pool.coin_type.clone(), pool.address, POOL_RESERVE),
+                create_pool_payload(&pool, POOL_RESERVE, POOL_RESERVE),
+            ] {
+                setup_txns.push(
+                    pool_account
+                        .sign_with_transaction_builder(init_txn_factory.payload(payload, rng)),
+                );
+            }
+            pools.push(pool);
+        }
+        info!("Creating {} liquidity pools", pools.len());
+        // per account limit is 100
+        for chunk in setup_txns.chunks(100) {
+            txn_executor.execute_transactions(chunk).await.unwrap();
+        }
+        info!("Done creating {} liquidity pools", pools.len());
+
+        Self {
+            txn_factory,
+            pools: Arc::new(pools),
+        }
+    }
+
+    pub fn pools(&self) -> &[LiquidityPool] {
+        &self.pools
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for DexSwapCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(DexSwapGenerator::new(
+            ResumableRng::from_entropy(),
+            self.txn_factory.clone(),
+            self.pools.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::in_memory_executor::InMemoryExecutor;
+    use aptos_sdk::{transaction_builder::TransactionFactory, types::chain_id::ChainId};
+    use std::collections::HashSet;
+
+    fn function_name(txn: &SignedTransaction) -> String {
+        match txn.payload() {
+            TransactionPayload::EntryFunction(entry_function) => {
+                entry_function.function().to_string()
+            },
+            _ => "".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pools_created_before_swaps() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let mut source_accounts = (0..2)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let executor = InMemoryExecutor::default();
+        let mut creator = DexSwapCreator::new(
+            &mut rng,
+            txn_factory.clone(),
+            txn_factory,
+            &mut source_accounts,
+            &executor,
+            3,
+        )
+        .await;
+
+        // Every pool account creates its pool, once its coin is initialized and minted
+        let pools = creator.pools().to_vec();
+        assert_eq!(pools.len(), 3);
+        let executed = executor.executed.lock().clone();
+        for pool in &pools {
+            let functions = executed
+                .iter()
+                .filter(|txn| txn.sender() == pool.address)
+                .map(function_name)
+                .collect::<Vec<_>>();
+            assert_eq!(functions.last().unwrap(), "create_pool");
+            assert!(functions.contains(&"initialize".to_string()));
+            assert!(functions.contains(&"mint".to_string()));
+        }
+
+        let mut generator = creator.create_transaction_generator().await;
+        let mut traders = (0..4)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let pool_addresses = pools
+            .iter()
+            .map(|pool| pool.address)
+            .collect::<HashSet<_>>();
+        let mut coin_balances = HashMap::new();
+        for _ in 0..5 {
+            for txn in generator.generate_transactions(traders.iter_mut().collect(), 5) {
+                let entry_function = match txn.payload() {
+                    TransactionPayload::EntryFunction(entry_function) => entry_function.clone(),
+                    _ => panic!("Swaps are entry function calls"),
+                };
+                assert_eq!(entry_function.function().as_str(), "swap");
+                let pool: AccountAddress = bcs::from_bytes(&entry_function.args()[0]).unwrap();
+                assert!(pool_addresses.contains(&pool));
+
+                // Coins are only sold once bought
+                let buy_coin: bool = bcs::from_bytes(&entry_function.args()[2]).unwrap();
+                let min_out: u64 = bcs::from_bytes(&entry_function.args()[3]).unwrap();
+                let balance = coin_balances.entry((txn.sender(), pool)).or_insert(0u64);
+                if buy_coin {
+                    *balance += min_out;
+                } else {
+                    assert!(*balance >= SWAP_AMOUNT);
+                    *balance -= SWAP_AMOUNT;
+                }
+            }
+        }
+    }
+}
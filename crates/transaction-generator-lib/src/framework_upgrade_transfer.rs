@@ -0,0 +1,171 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    TransactionGenerator, TransactionGeneratorCreator,
+};
+use aptos_infallible::RwLock;
+use aptos_sdk::{
+    move_types::account_address::AccountAddress,
+    transaction_builder::aptos_stdlib,
+    types::{
+        transaction::{SignedTransaction, TransactionPayload},
+        LocalAccount,
+    },
+};
+use async_trait::async_trait;
+use rand::{prelude::SliceRandom, SeedableRng};
+use std::sync::Arc;
+
+/// Transfers using both the pre-upgrade (`0x1::coin::transfer<AptosCoin>`) and the post-upgrade
+/// (`0x1::aptos_account::transfer`) entry function, to check that both keep executing during
+/// a rolling framework upgrade. `legacy_percentage` of the transfers use the pre-upgrade form,
+/// spread evenly, so that any prefix of the stream has (rounded down) that share of them.
+pub struct FrameworkUpgradeTransferGenerator {
+    rng: ResumableRng,
+    txn_factory: SharedTransactionFactory,
+    all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
+    legacy_percentage: u64,
+    num_generated: u64,
+}
+
+impl FrameworkUpgradeTransferGenerator {
+    pub fn new(
+        rng: ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
+        legacy_percentage: usize,
+    ) -> Self {
+        assert!(
+            legacy_percentage <= 100,
+            "Invalid legacy percentage {}",
+            legacy_percentage
+        );
+        Self {
+            rng,
+            txn_factory,
+            all_addresses,
+            legacy_percentage: legacy_percentage as u64,
+            num_generated: 0,
+        }
+    }
+
+    fn next_payload(&mut self, to: AccountAddress) -> TransactionPayload {
+        let num_legacy = self.num_generated * self.legacy_percentage / 100;
+        self.num_generated += 1;
+        if self.num_generated * self.legacy_percentage / 100 > num_legacy {
+            aptos_stdlib::aptos_coin_transfer(to, 1)
+        } else {
+            aptos_stdlib::aptos_account_transfer(to, 1)
+        }
+    }
+}
+
+impl TransactionGenerator for FrameworkUpgradeTransferGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
+        for sender in accounts {
+            let receivers = self
+                .all_addresses
+                .read()
+                .choose_multiple(&mut self.rng, transactions_per_account)
+                .cloned()
+                .collect::<Vec<_>>();
+            for receiver in receivers {
+                let payload = self.next_payload(receiver);
+                requests.push(sender.sign_with_transaction_builder(
+                    self.txn_factory.payload(payload, &mut self.rng),
+                ));
+            }
+        }
+        requests
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
+}
+
+pub struct FrameworkUpgradeTransferCreator {
+    txn_factory: SharedTransactionFactory,
+    all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
+    legacy_percentage: usize,
+}
+
+impl FrameworkUpgradeTransferCreator {
+    pub fn new(
+        txn_factory: SharedTransactionFactory,
+        all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
+        legacy_percentage: usize,
+    ) -> Self {
+        Self {
+            txn_factory,
+            all_addresses,
+            legacy_percentage,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for FrameworkUpgradeTransferCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(FrameworkUpgradeTransferGenerator::new(
+            ResumableRng::from_entropy(),
+            self.txn_factory.clone(),
+            self.all_addresses.clone(),
+            self.legacy_percentage,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{transaction_builder::TransactionFactory, types::chain_id::ChainId};
+
+    #[test]
+    fn test_legacy_form_ratio() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let all_addresses = Arc::new(RwLock::new(
+            (0..20)
+                .map(|_| AccountAddress::random())
+                .collect::<Vec<_>>(),
+        ));
+        let mut accounts = (0..10)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+
+        for legacy_percentage in [0, 30, 100] {
+            let mut generator = FrameworkUpgradeTransferGenerator::new(
+                ResumableRng::seed_from_u64(1),
+                SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+                all_addresses.clone(),
+                legacy_percentage,
+            );
+            let txns = generator.generate_transactions(accounts.iter_mut().collect(), 10);
+            assert_eq!(txns.len(), 100);
+            let num_legacy = txns
+                .iter()
+                .filter(|txn| {
+                    let entry_function = txn.payload().clone().into_entry_function();
+                    match entry_function.module().name().as_str() {
+                        "coin" => true,
+                        "aptos_account" => false,
+                        module => panic!("Unexpected module {}", module),
+                    }
+                })
+                .count();
+            assert_eq!(num_legacy, legacy_percentage);
+        }
+    }
+}
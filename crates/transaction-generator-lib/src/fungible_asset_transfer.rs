@@ -0,0 +1,189 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    TransactionGenerator, TransactionGeneratorCreator,
+};
+use aptos_infallible::RwLock;
+use aptos_sdk::{
+    bcs,
+    move_types::{
+        account_address::AccountAddress,
+        ident_str,
+        language_storage::{ModuleId, StructTag, TypeTag},
+    },
+    types::{
+        transaction::{EntryFunction, SignedTransaction, TransactionPayload},
+        LocalAccount,
+    },
+};
+use async_trait::async_trait;
+use rand::{prelude::SliceRandom, SeedableRng};
+use std::sync::Arc;
+
+/// Transfers a fungible asset between primary stores, through
+/// `0x1::primary_fungible_store::transfer`. The primary store of the recipient is
+/// created by the transfer if it doesn't exist yet, so any address can receive.
+pub struct FungibleAssetTransferGenerator {
+    rng: ResumableRng,
+    txn_factory: SharedTransactionFactory,
+    metadata_address: AccountAddress,
+    amount: u64,
+    all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
+}
+
+impl FungibleAssetTransferGenerator {
+    pub fn new(
+        rng: ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        metadata_address: AccountAddress,
+        amount: u64,
+        all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
+    ) -> Self {
+        Self {
+            rng,
+            txn_factory,
+            metadata_address,
+            amount,
+            all_addresses,
+        }
+    }
+
+    fn transfer(&self, to: AccountAddress) -> TransactionPayload {
+        TransactionPayload::EntryFunction(EntryFunction::new(
+            ModuleId::new(
+                AccountAddress::ONE,
+                ident_str!("primary_fungible_store").to_owned(),
+            ),
+            ident_str!("transfer").to_owned(),
+            vec![TypeTag::Struct(Box::new(StructTag {
+                address: AccountAddress::ONE,
+                module: ident_str!("fungible_asset").to_owned(),
+                name: ident_str!("Metadata").to_owned(),
+                type_params: vec![],
+            }))],
+            vec![
+                // Object<Metadata> is serialized as its address
+                bcs::to_bytes(&self.metadata_address).unwrap(),
+                bcs::to_bytes(&to).unwrap(),
+                bcs::to_bytes(&self.amount).unwrap(),
+            ],
+        ))
+    }
+}
+
+impl TransactionGenerator for FungibleAssetTransferGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
+        for sender in accounts {
+            let receivers = self
+                .all_addresses
+                .read()
+                .choose_multiple(&mut self.rng, transactions_per_account)
+                .cloned()
+                .collect::<Vec<_>>();
+            for receiver in receivers {
+                requests.push(
+                    sender.sign_with_transaction_builder(
+                        self.txn_factory
+                            .payload(self.transfer(receiver), &mut self.rng),
+                    ),
+                );
+            }
+        }
+        requests
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
+}
+
+pub struct FungibleAssetTransferGeneratorCreator {
+    txn_factory: SharedTransactionFactory,
+    metadata_address: AccountAddress,
+    amount: u64,
+    all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
+}
+
+impl FungibleAssetTransferGeneratorCreator {
+    pub fn new(
+        txn_factory: SharedTransactionFactory,
+        metadata_address: AccountAddress,
+        amount: u64,
+        all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
+    ) -> Self {
+        Self {
+            txn_factory,
+            metadata_address,
+            amount,
+            all_addresses,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for FungibleAssetTransferGeneratorCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(FungibleAssetTransferGenerator::new(
+            ResumableRng::from_entropy(),
+            self.txn_factory.clone(),
+            self.metadata_address,
+            self.amount,
+            self.all_addresses.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{transaction_builder::TransactionFactory, types::chain_id::ChainId};
+
+    #[test]
+    fn test_transfers_through_primary_store() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let metadata_address = AccountAddress::random();
+        let all_addresses = (0..10)
+            .map(|_| AccountAddress::random())
+            .collect::<Vec<_>>();
+        let mut generator = FungibleAssetTransferGenerator::new(
+            ResumableRng::seed_from_u64(1),
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            metadata_address,
+            7,
+            Arc::new(RwLock::new(all_addresses.clone())),
+        );
+        let mut accounts = (0..3)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 2);
+        assert_eq!(txns.len(), 6);
+        for txn in txns {
+            let entry_function = txn.payload().clone().into_entry_function();
+            assert_eq!(
+                entry_function.module().name().as_str(),
+                "primary_fungible_store"
+            );
+            assert_eq!(entry_function.function().as_str(), "transfer");
+            let args = entry_function.args();
+            assert_eq!(
+                bcs::from_bytes::<AccountAddress>(&args[0]).unwrap(),
+                metadata_address
+            );
+            assert!(all_addresses.contains(&bcs::from_bytes(&args[1]).unwrap()));
+            assert_eq!(bcs::from_bytes::<u64>(&args[2]).unwrap(), 7);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::cmp::max;
+
+/// Distribution from which the gas unit price of each generated transaction is sampled,
+/// by the transaction factory shared by the generators, to benchmark prioritization in the
+/// fee market. Sampled prices are never below the minimum gas unit price passed in.
+#[derive(Debug, Copy, Clone, Deserialize, Eq, PartialEq, Serialize)]
+pub enum GasPriceDistribution {
+    /// All transactions use the same gas unit price
+    Fixed(u64),
+    /// Gas unit price is sampled uniformly from [low, high]
+    UniformRange { low: u64, high: u64 },
+    /// Gas unit price is the minimum, plus an exponentially distributed premium with given mean.
+    /// (most transactions pay close to the minimum, with a long tail of high bidders)
+    Exponential { mean: u64 },
+}
+
+impl GasPriceDistribution {
+    pub fn validate(&self) -> Result<()> {
+        if let GasPriceDistribution::UniformRange { low, high } = self {
+            if low > high {
+                bail!("Invalid gas unit price range {}..={}", low, high);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn sample<R: Rng>(&self, rng: &mut R, min_gas_unit_price: u64) -> u64 {
+        let gas_unit_price = match self {
+            GasPriceDistribution::Fixed(gas_unit_price) => *gas_unit_price,
+            GasPriceDistribution::UniformRange { low, high } => {
+                rng.gen_range(*low, high.saturating_add(1))
+            },
+            GasPriceDistribution::Exponential { mean } => {
+                // inverse transform sampling, 1 - u is in (0, 1], so ln is finite
+                let u: f64 = rng.gen();
+                let premium = -(*mean as f64) * (1.0 - u).ln();
+                min_gas_unit_price.saturating_add(premium as u64)
+            },
+        };
+        max(gas_unit_price, min_gas_unit_price)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resumable_rng::ResumableRng;
+    use rand::SeedableRng;
+
+    fn samples(distribution: GasPriceDistribution, min_gas_unit_price: u64) -> Vec<u64> {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        (0..10_000)
+            .map(|_| distribution.sample(&mut rng, min_gas_unit_price))
+            .collect()
+    }
+
+    fn mean(samples: &[u64]) -> f64 {
+        samples.iter().sum::<u64>() as f64 / samples.len() as f64
+    }
+
+    #[test]
+    fn test_empirical_distributions() {
+        assert!(samples(GasPriceDistribution::Fixed(150), 100)
+            .iter()
+            .all(|price| *price == 150));
+        // The minimum is enforced
+        assert!(samples(GasPriceDistribution::Fixed(50), 100)
+            .iter()
+            .all(|price| *price == 100));
+
+        let uniform = samples(
+            GasPriceDistribution::UniformRange {
+                low: 100,
+                high: 200,
+            },
+            100,
+        );
+        assert!(uniform.iter().all(|price| (100..=200).contains(price)));
+        assert!(uniform.contains(&100) && uniform.contains(&200));
+        assert!((mean(&uniform) - 150.0).abs() < 2.0);
+
+        let exponential = samples(GasPriceDistribution::Exponential { mean: 50 }, 100);
+        assert!(exponential.iter().all(|price| *price >= 100));
+        // Premiums are truncated, so their mean is half a unit lower
+        assert!((mean(&exponential) - 149.5).abs() < 2.0);
+        // 1 - 1/e of the premiums are below the mean
+        let below_mean = exponential.iter().filter(|price| **price < 150).count();
+        assert!((below_mean as f64 / exponential.len() as f64 - 0.632).abs() < 0.02);
+    }
+}
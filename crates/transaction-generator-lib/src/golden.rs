@@ -0,0 +1,58 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Golden hashes of generated transactions, to catch accidental nondeterminism in generators.
+//!
+//! Set env REGENERATE_GOLDENFILES to update the golden files when running tests.
+
+use aptos_crypto::HashValue;
+use aptos_sdk::{bcs, types::transaction::SignedTransaction};
+use std::path::Path;
+
+/// Hash of the parts of the transactions the generators determine. The expiration timestamp
+/// depends on the wall clock, and so does the signature covering it, so both are left out.
+fn generated_hash(txns: &[SignedTransaction]) -> HashValue {
+    let generated = txns
+        .iter()
+        .map(|txn| {
+            (
+                txn.sender(),
+                txn.sequence_number(),
+                txn.payload(),
+                txn.max_gas_amount(),
+                txn.gas_unit_price(),
+                txn.chain_id(),
+            )
+        })
+        .collect::<Vec<_>>();
+    HashValue::sha3_256_of(&bcs::to_bytes(&generated).unwrap())
+}
+
+/// Asserts that the transactions hash to the golden hash stored at `path`,
+/// or stores their hash there if REGENERATE_GOLDENFILES is set.
+pub fn assert_matches_golden(txns: &[SignedTransaction], path: &Path) {
+    let hash = generated_hash(txns).to_hex();
+    if std::env::var_os("REGENERATE_GOLDENFILES").is_some() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).unwrap();
+        }
+        std::fs::write(path, format!("{}\n", hash))
+            .unwrap_or_else(|e| panic!("Failed writing golden file {}: {}", path.display(), e));
+        return;
+    }
+
+    let golden = std::fs::read_to_string(path).unwrap_or_else(|e| {
+        panic!(
+            "Failed reading golden file {}: {}, set REGENERATE_GOLDENFILES to create it",
+            path.display(),
+            e
+        )
+    });
+    assert_eq!(
+        golden.trim(),
+        hash,
+        "Generated transactions don't match golden file {}, \
+        set REGENERATE_GOLDENFILES if the change is intended",
+        path.display()
+    );
+}
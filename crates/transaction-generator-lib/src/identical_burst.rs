@@ -0,0 +1,143 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    TransactionGenerator, TransactionGeneratorCreator, SEND_AMOUNT,
+};
+use aptos_infallible::RwLock;
+use aptos_sdk::{
+    move_types::account_address::AccountAddress,
+    transaction_builder::aptos_stdlib,
+    types::{transaction::SignedTransaction, LocalAccount},
+};
+use async_trait::async_trait;
+use rand::{prelude::SliceRandom, SeedableRng};
+use std::sync::Arc;
+
+/// Generates bursts of `burst_size` byte-identical copies of a single transfer per account,
+/// all with the same sequence number, to exercise the duplicate suppression of mempool.
+/// Only one copy of every burst can commit.
+pub struct IdenticalBurstGenerator {
+    rng: ResumableRng,
+    txn_factory: SharedTransactionFactory,
+    all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
+    burst_size: usize,
+}
+
+impl IdenticalBurstGenerator {
+    pub fn new(
+        rng: ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
+        burst_size: usize,
+    ) -> Self {
+        assert!(burst_size > 0, "Invalid burst size {}", burst_size);
+        Self {
+            rng,
+            txn_factory,
+            all_addresses,
+            burst_size,
+        }
+    }
+}
+
+impl TransactionGenerator for IdenticalBurstGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        _transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let mut requests = Vec::with_capacity(accounts.len() * self.burst_size);
+        for sender in accounts {
+            let receiver = *self
+                .all_addresses
+                .read()
+                .choose(&mut self.rng)
+                .expect("all_addresses can't be empty");
+            let txn = sender.sign_with_transaction_builder(self.txn_factory.payload(
+                aptos_stdlib::aptos_coin_transfer(receiver, SEND_AMOUNT),
+                &mut self.rng,
+            ));
+            requests.extend(std::iter::repeat(txn).take(self.burst_size));
+        }
+        requests
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
+}
+
+pub struct IdenticalBurstCreator {
+    txn_factory: SharedTransactionFactory,
+    all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
+    burst_size: usize,
+}
+
+impl IdenticalBurstCreator {
+    pub fn new(
+        txn_factory: SharedTransactionFactory,
+        all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
+        burst_size: usize,
+    ) -> Self {
+        Self {
+            txn_factory,
+            all_addresses,
+            burst_size,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for IdenticalBurstCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(IdenticalBurstGenerator::new(
+            ResumableRng::from_entropy(),
+            self.txn_factory.clone(),
+            self.all_addresses.clone(),
+            self.burst_size,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{bcs, transaction_builder::TransactionFactory, types::chain_id::ChainId};
+
+    #[test]
+    fn test_bursts_byte_identical() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let all_addresses = Arc::new(RwLock::new(
+            (0..20)
+                .map(|_| AccountAddress::random())
+                .collect::<Vec<_>>(),
+        ));
+        let mut accounts = (0..3)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let mut generator = IdenticalBurstGenerator::new(
+            ResumableRng::seed_from_u64(1),
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            all_addresses,
+            5,
+        );
+
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 10);
+        assert_eq!(txns.len(), 15);
+        for (burst, account) in txns.chunks(5).zip(&accounts) {
+            let bytes = bcs::to_bytes(&burst[0]).unwrap();
+            assert!(burst.iter().all(|txn| bcs::to_bytes(txn).unwrap() == bytes));
+            assert_eq!(burst[0].sender(), account.address());
+            assert_eq!(burst[0].sequence_number(), 0);
+            // A single sequence number is used by the whole burst
+            assert_eq!(account.sequence_number(), 1);
+        }
+    }
+}
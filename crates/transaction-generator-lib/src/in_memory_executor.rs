@@ -0,0 +1,245 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Executor keeping the state of a network in memory, shared by the tests of the crate.
+
+use crate::{CounterState, TransactionExecutor};
+use anyhow::{bail, Result};
+use aptos_infallible::Mutex;
+use aptos_rest_client::aptos_api_types::MoveModule;
+use aptos_sdk::{
+    bcs,
+    move_types::account_address::AccountAddress,
+    types::{
+        transaction::{SignedTransaction, TransactionPayload},
+        utility_coin::APTOS_COIN_TYPE,
+    },
+};
+use async_trait::async_trait;
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+type DropFilter = Box<dyn FnMut(&SignedTransaction) -> bool + Send>;
+
+/// Executor committing transactions into an in-memory state: sequence numbers of the senders,
+/// and APT balances of the receivers of transfers (senders are not charged, so they don't need
+/// to be funded). Transactions selected by the drop filter are not committed, and fail their
+/// batch. Everything committed is recorded in order, and queries are counted.
+pub struct InMemoryExecutor {
+    balances: Mutex<HashMap<AccountAddress, u64>>,
+    sequence_numbers: Mutex<HashMap<AccountAddress, u64>>,
+    modules: Mutex<HashMap<(AccountAddress, String), MoveModule>>,
+    drop_filter: Mutex<Option<DropFilter>>,
+    execution_delay: Duration,
+    gas_estimate: Option<u64>,
+    min_gas_unit_price: Option<u64>,
+    mempool_load: Mutex<Option<f64>>,
+    pub executed: Mutex<Vec<SignedTransaction>>,
+    pub balance_queries: AtomicUsize,
+    // number of accounts in each query of sequence numbers
+    pub sequence_number_queries: Mutex<Vec<usize>>,
+}
+
+impl Default for InMemoryExecutor {
+    fn default() -> Self {
+        Self {
+            balances: Mutex::new(HashMap::new()),
+            sequence_numbers: Mutex::new(HashMap::new()),
+            modules: Mutex::new(HashMap::new()),
+            drop_filter: Mutex::new(None),
+            execution_delay: Duration::ZERO,
+            gas_estimate: None,
+            min_gas_unit_price: None,
+            mempool_load: Mutex::new(None),
+            executed: Mutex::new(Vec::new()),
+            balance_queries: AtomicUsize::new(0),
+            sequence_number_queries: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl InMemoryExecutor {
+    /// Drops (instead of committing) the transactions the filter returns true for.
+    pub fn with_drop_filter(
+        self,
+        drop_filter: impl FnMut(&SignedTransaction) -> bool + Send + 'static,
+    ) -> Self {
+        *self.drop_filter.lock() = Some(Box::new(drop_filter));
+        self
+    }
+
+    /// Delays every execution, to keep the transactions in flight for a while.
+    pub fn with_execution_delay(mut self, execution_delay: Duration) -> Self {
+        self.execution_delay = execution_delay;
+        self
+    }
+
+    pub fn with_gas_estimate(mut self, gas_estimate: u64) -> Self {
+        self.gas_estimate = Some(gas_estimate);
+        self
+    }
+
+    pub fn with_min_gas_unit_price(mut self, min_gas_unit_price: u64) -> Self {
+        self.min_gas_unit_price = Some(min_gas_unit_price);
+        self
+    }
+
+    pub fn with_sequence_number(self, address: AccountAddress, sequence_number: u64) -> Self {
+        self.sequence_numbers
+            .lock()
+            .insert(address, sequence_number);
+        self
+    }
+
+    pub fn set_balance(&self, address: AccountAddress, balance: u64) {
+        self.balances.lock().insert(address, balance);
+    }
+
+    pub fn balance(&self, address: AccountAddress) -> Option<u64> {
+        self.balances.lock().get(&address).copied()
+    }
+
+    pub fn set_mempool_load(&self, mempool_load: f64) {
+        *self.mempool_load.lock() = Some(mempool_load);
+    }
+
+    /// Serves the module at its own address and name, replacing the one published before.
+    pub fn publish_module(&self, module: MoveModule) {
+        self.modules
+            .lock()
+            .insert((*module.address.inner(), module.name.to_string()), module);
+    }
+
+    /// Commits the transactions that are not dropped, and returns how many were.
+    async fn commit(&self, txns: &[SignedTransaction]) -> Result<usize> {
+        tokio::time::sleep(self.execution_delay).await;
+        let mut num_dropped = 0;
+        for txn in txns {
+            if let Some(drop_filter) = self.drop_filter.lock().as_mut() {
+                if drop_filter(txn) {
+                    num_dropped += 1;
+                    continue;
+                }
+            }
+            if let Some((receiver, amount)) = apt_transfer(txn.payload())? {
+                *self.balances.lock().entry(receiver).or_default() += amount;
+            }
+            let mut sequence_numbers = self.sequence_numbers.lock();
+            let next = sequence_numbers.entry(txn.sender()).or_default();
+            *next = (*next).max(txn.sequence_number() + 1);
+            self.executed.lock().push(txn.clone());
+        }
+        Ok(num_dropped)
+    }
+}
+
+/// Receiver and amount of the APT transfer, if the payload is one.
+fn apt_transfer(payload: &TransactionPayload) -> Result<Option<(AccountAddress, u64)>> {
+    let entry_function = match payload {
+        TransactionPayload::EntryFunction(entry_function) => entry_function,
+        _ => return Ok(None),
+    };
+    let module = entry_function.module();
+    let is_apt_transfer = *module.address() == AccountAddress::ONE
+        && entry_function.function().as_str() == "transfer"
+        && match module.name().as_str() {
+            "aptos_account" => entry_function.ty_args().is_empty(),
+            "coin" => entry_function.ty_args() == [APTOS_COIN_TYPE.clone()],
+            _ => false,
+        };
+    if !is_apt_transfer {
+        return Ok(None);
+    }
+    Ok(Some((
+        bcs::from_bytes(&entry_function.args()[0])?,
+        bcs::from_bytes(&entry_function.args()[1])?,
+    )))
+}
+
+#[async_trait]
+impl TransactionExecutor for InMemoryExecutor {
+    async fn get_account_balance(&self, account_address: AccountAddress) -> Result<u64> {
+        self.balance_queries.fetch_add(1, Ordering::Relaxed);
+        match self.balance(account_address) {
+            Some(balance) => Ok(balance),
+            None => bail!("Account {} not found", account_address),
+        }
+    }
+
+    async fn query_sequence_number(&self, account_address: AccountAddress) -> Result<u64> {
+        Ok(self.query_sequence_numbers(&[account_address]).await?[0])
+    }
+
+    async fn query_sequence_numbers(&self, addresses: &[AccountAddress]) -> Result<Vec<u64>> {
+        self.sequence_number_queries.lock().push(addresses.len());
+        let sequence_numbers = self.sequence_numbers.lock();
+        Ok(addresses
+            .iter()
+            .map(|address| *sequence_numbers.get(address).unwrap_or(&0))
+            .collect())
+    }
+
+    async fn execute_transactions(&self, txns: &[SignedTransaction]) -> Result<()> {
+        let num_dropped = self.commit(txns).await?;
+        if num_dropped > 0 {
+            bail!("{} of {} transactions dropped", num_dropped, txns.len());
+        }
+        Ok(())
+    }
+
+    async fn execute_transactions_with_counter(
+        &self,
+        txns: &[SignedTransaction],
+        state: &CounterState,
+    ) -> Result<()> {
+        let num_dropped = self.commit(txns).await?;
+        state
+            .successes
+            .fetch_add(txns.len() - num_dropped, Ordering::Relaxed);
+        if num_dropped > 0 {
+            state.submit_failures[0].fetch_add(num_dropped, Ordering::Relaxed);
+            bail!("{} of {} transactions dropped", num_dropped, txns.len());
+        }
+        Ok(())
+    }
+
+    fn create_counter_state(&self) -> CounterState {
+        CounterState {
+            submit_failures: vec![AtomicUsize::new(0)],
+            wait_failures: vec![AtomicUsize::new(0)],
+            successes: AtomicUsize::new(0),
+            by_client: HashMap::new(),
+        }
+    }
+
+    async fn get_module(&self, address: AccountAddress, module_name: &str) -> Result<MoveModule> {
+        match self.modules.lock().get(&(address, module_name.to_string())) {
+            Some(module) => Ok(module.clone()),
+            None => bail!("No module {}::{}", address, module_name),
+        }
+    }
+
+    async fn estimate_gas(&self, _txn: &SignedTransaction) -> Result<u64> {
+        match self.gas_estimate {
+            Some(gas_estimate) => Ok(gas_estimate),
+            None => bail!("Gas estimation is not supported by this executor"),
+        }
+    }
+
+    async fn mempool_load(&self) -> Result<f64> {
+        match *self.mempool_load.lock() {
+            Some(mempool_load) => Ok(mempool_load),
+            None => bail!("Mempool load is not supported by this executor"),
+        }
+    }
+
+    async fn min_gas_unit_price(&self) -> Result<u64> {
+        match self.min_gas_unit_price {
+            Some(min_gas_unit_price) => Ok(min_gas_unit_price),
+            None => bail!("Minimum gas unit price is not supported by this executor"),
+        }
+    }
+}
@@ -0,0 +1,278 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{CounterState, TransactionExecutor};
+use anyhow::{anyhow, bail, Result};
+use aptos_rest_client::aptos_api_types::MoveModule;
+use aptos_sdk::{
+    bcs,
+    move_types::{account_address::AccountAddress, language_storage::StructTag},
+    types::transaction::SignedTransaction,
+};
+use async_trait::async_trait;
+use futures::future::join_all;
+use rdkafka::{
+    producer::{FutureProducer, FutureRecord},
+    ClientConfig,
+};
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+/// Destination of the transactions published by `KafkaExecutor`.
+#[async_trait]
+pub trait TransactionProducer: Sync + Send {
+    async fn produce(&self, topic: &str, key: &[u8], payload: &[u8]) -> Result<()>;
+}
+
+/// Kafka producer that, when its local queue is full, waits up to `queue_timeout` for room
+/// instead of failing right away.
+pub struct KafkaProducer {
+    producer: FutureProducer,
+    queue_timeout: Duration,
+}
+
+#[async_trait]
+impl TransactionProducer for KafkaProducer {
+    async fn produce(&self, topic: &str, key: &[u8], payload: &[u8]) -> Result<()> {
+        self.producer
+            .send(
+                FutureRecord::to(topic).key(key).payload(payload),
+                self.queue_timeout,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|(err, _)| anyhow!(err))
+    }
+}
+
+/// Executor that, instead of submitting transactions to the chain, publishes them
+/// (bcs encoded, keyed by transaction hash) to a Kafka topic, for downstream pipelines.
+/// Queries are delegated to the inner executor, so generators keep seeing the real chain state.
+pub struct KafkaExecutor<E, P = KafkaProducer> {
+    inner: E,
+    producer: P,
+    topic: String,
+}
+
+impl<E: TransactionExecutor, P: TransactionProducer> KafkaExecutor<E, P> {
+    pub fn new(inner: E, producer: P, topic: String) -> Self {
+        Self {
+            inner,
+            producer,
+            topic,
+        }
+    }
+}
+
+impl<E: TransactionExecutor> KafkaExecutor<E> {
+    /// Creates an executor publishing to `topic` on the given (comma separated) brokers.
+    /// Publishing waits up to `queue_timeout` for room in the local queue of the producer.
+    pub fn connect(
+        inner: E,
+        brokers: &str,
+        topic: String,
+        queue_timeout: Duration,
+    ) -> Result<Self> {
+        let producer = KafkaProducer {
+            producer: ClientConfig::new()
+                .set("bootstrap.servers", brokers)
+                .create()?,
+            queue_timeout,
+        };
+        Ok(Self::new(inner, producer, topic))
+    }
+}
+
+#[async_trait]
+impl<E: TransactionExecutor, P: TransactionProducer> TransactionExecutor for KafkaExecutor<E, P> {
+    async fn get_account_balance(&self, account_address: AccountAddress) -> Result<u64> {
+        self.inner.get_account_balance(account_address).await
+    }
+
+    async fn get_coin_balance(
+        &self,
+        account_address: AccountAddress,
+        coin_type: StructTag,
+    ) -> Result<u64> {
+        self.inner
+            .get_coin_balance(account_address, coin_type)
+            .await
+    }
+
+    async fn query_sequence_number(&self, account_address: AccountAddress) -> Result<u64> {
+        self.inner.query_sequence_number(account_address).await
+    }
+
+    async fn query_sequence_numbers(&self, addresses: &[AccountAddress]) -> Result<Vec<u64>> {
+        self.inner.query_sequence_numbers(addresses).await
+    }
+
+    async fn execute_transactions(&self, txns: &[SignedTransaction]) -> Result<()> {
+        self.execute_transactions_with_counter(txns, &self.create_counter_state())
+            .await
+    }
+
+    async fn execute_transactions_with_counter(
+        &self,
+        txns: &[SignedTransaction],
+        state: &CounterState,
+    ) -> Result<()> {
+        let results = join_all(txns.iter().map(|txn| async move {
+            let key = txn.clone().committed_hash();
+            let payload = bcs::to_bytes(txn)?;
+            self.producer
+                .produce(&self.topic, key.as_ref(), &payload)
+                .await
+        }))
+        .await;
+
+        let mut num_failed = 0;
+        for result in results {
+            if result.is_ok() {
+                state.successes.fetch_add(1, Ordering::Relaxed);
+            } else {
+                state.submit_failures[0].fetch_add(1, Ordering::Relaxed);
+                num_failed += 1;
+            }
+        }
+        if num_failed > 0 {
+            bail!(
+                "Failed publishing {} out of {} txns to {}",
+                num_failed,
+                txns.len(),
+                self.topic
+            );
+        }
+        Ok(())
+    }
+
+    fn create_counter_state(&self) -> CounterState {
+        CounterState {
+            submit_failures: vec![AtomicUsize::new(0)],
+            wait_failures: vec![AtomicUsize::new(0)],
+            successes: AtomicUsize::new(0),
+            by_client: HashMap::new(),
+        }
+    }
+
+    async fn get_module(&self, address: AccountAddress, module_name: &str) -> Result<MoveModule> {
+        self.inner.get_module(address, module_name).await
+    }
+
+    async fn estimate_gas(&self, txn: &SignedTransaction) -> Result<u64> {
+        self.inner.estimate_gas(txn).await
+    }
+
+    async fn mempool_load(&self) -> Result<f64> {
+        self.inner.mempool_load().await
+    }
+
+    async fn min_gas_unit_price(&self) -> Result<u64> {
+        self.inner.min_gas_unit_price().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{in_memory_executor::InMemoryExecutor, resumable_rng::ResumableRng};
+    use aptos_infallible::Mutex;
+    use aptos_sdk::{
+        transaction_builder::{aptos_stdlib, TransactionFactory},
+        types::{chain_id::ChainId, LocalAccount},
+    };
+    use rand::SeedableRng;
+
+    /// Producer that records published messages, failing for the given keys.
+    #[derive(Default)]
+    struct MockProducer {
+        published: Mutex<Vec<(String, Vec<u8>, Vec<u8>)>>,
+        failing_keys: Vec<Vec<u8>>,
+    }
+
+    #[async_trait]
+    impl TransactionProducer for MockProducer {
+        async fn produce(&self, topic: &str, key: &[u8], payload: &[u8]) -> Result<()> {
+            if self.failing_keys.iter().any(|failing| failing == key) {
+                bail!("Broker unavailable");
+            }
+            self.published
+                .lock()
+                .push((topic.to_string(), key.to_vec(), payload.to_vec()));
+            Ok(())
+        }
+    }
+
+    fn transfers(num_txns: usize) -> Vec<SignedTransaction> {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut account = LocalAccount::generate(&mut rng);
+        let txn_factory = TransactionFactory::new(ChainId::test());
+        (0..num_txns)
+            .map(|_| {
+                account.sign_with_transaction_builder(
+                    txn_factory.payload(aptos_stdlib::aptos_coin_transfer(AccountAddress::ONE, 1)),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_all_transactions_published() {
+        let txns = transfers(10);
+        let executor = KafkaExecutor::new(
+            InMemoryExecutor::default().with_sequence_number(AccountAddress::ONE, 7),
+            MockProducer::default(),
+            "generated".to_string(),
+        );
+        assert_eq!(
+            executor
+                .query_sequence_number(AccountAddress::ONE)
+                .await
+                .unwrap(),
+            7
+        );
+
+        let state = executor.create_counter_state();
+        executor
+            .execute_transactions_with_counter(&txns, &state)
+            .await
+            .unwrap();
+        assert_eq!(state.successes.load(Ordering::Relaxed), txns.len());
+        // Transactions are published, not executed
+        assert!(executor.inner.executed.lock().is_empty());
+
+        let published = executor.producer.published.lock();
+        assert_eq!(published.len(), txns.len());
+        for (txn, (topic, key, payload)) in txns.iter().zip(published.iter()) {
+            assert_eq!(topic, "generated");
+            assert_eq!(key.as_slice(), txn.clone().committed_hash().as_ref());
+            assert_eq!(&bcs::from_bytes::<SignedTransaction>(payload).unwrap(), txn);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_produce_failures_counted() {
+        let txns = transfers(5);
+        let producer = MockProducer {
+            published: Mutex::new(Vec::new()),
+            failing_keys: vec![txns[1].clone().committed_hash().to_vec()],
+        };
+        let executor = KafkaExecutor::new(
+            InMemoryExecutor::default(),
+            producer,
+            "generated".to_string(),
+        );
+
+        let state = executor.create_counter_state();
+        assert!(executor
+            .execute_transactions_with_counter(&txns, &state)
+            .await
+            .is_err());
+        assert_eq!(state.submit_failures[0].load(Ordering::Relaxed), 1);
+        assert_eq!(state.successes.load(Ordering::Relaxed), txns.len() - 1);
+        assert_eq!(executor.producer.published.lock().len(), txns.len() - 1);
+    }
+}
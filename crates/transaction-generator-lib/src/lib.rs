@@ -3,17 +3,29 @@
 
 #![forbid(unsafe_code)]
 
-use anyhow::Result;
+use anyhow::{bail, Result};
+use aptos_crypto::HashValue;
 use aptos_infallible::RwLock;
 use aptos_logger::{sample, sample::SampleRate, warn};
+use aptos_rest_client::aptos_api_types::MoveModule;
 use aptos_sdk::{
-    move_types::account_address::AccountAddress,
-    transaction_builder::TransactionFactory,
-    types::{transaction::SignedTransaction, LocalAccount},
+    move_types::{
+        account_address::AccountAddress,
+        language_storage::{StructTag, TypeTag},
+    },
+    transaction_builder::{aptos_stdlib, TransactionBuilder, TransactionFactory},
+    types::{
+        transaction::{RawTransaction, SignedTransaction},
+        utility_coin::APTOS_COIN_TYPE,
+        LocalAccount,
+    },
 };
 use async_trait::async_trait;
+use rand::{thread_rng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
+    path::PathBuf,
     sync::{
         atomic::{AtomicUsize, Ordering},
         Arc,
@@ -23,51 +35,260 @@ use std::{
 
 pub mod account_generator;
 pub mod accounts_pool_wrapper;
+pub mod adaptive_rate;
+pub mod ans;
+pub mod block_packing;
+pub mod cached_generator;
 pub mod call_custom_modules;
+pub mod chaos_executor;
+pub mod circular_transfer;
+pub mod create_empty_account;
+pub mod custom_coin;
+pub mod dedup_executor;
+pub mod dex_swap;
+pub mod framework_upgrade_transfer;
+pub mod fungible_asset_transfer;
+pub mod gas_price_distribution;
+#[cfg(test)]
+mod golden;
+pub mod identical_burst;
+#[cfg(test)]
+mod in_memory_executor;
+#[cfg(feature = "kafka")]
+pub mod kafka_executor;
+pub mod many_signers;
+pub mod marked_events;
+pub mod marketplace;
+pub mod multi_agent_generator;
 pub mod nft_mint_and_transfer;
+pub mod observed_generator;
+pub mod oracle_update;
 pub mod p2p_transaction_generator;
 pub mod publish_modules;
 mod publishing;
+pub mod resumable_rng;
+pub mod round_robin_generator;
+pub mod run_manifest;
+pub mod shared_txn_factory;
+pub mod skewed_account_size;
+pub mod source_accounts;
+pub mod trace_replay;
 pub mod transaction_mix_generator;
+pub mod type_counters;
+pub mod vector_growth;
+pub mod wide_read_set;
 use self::{
-    account_generator::AccountGeneratorCreator, call_custom_modules::CallCustomModulesCreator,
-    nft_mint_and_transfer::NFTMintAndTransferGeneratorCreator,
+    account_generator::AccountGeneratorCreator,
+    ans::{AnsRegisterGeneratorCreator, ANS_ADDRESS},
+    call_custom_modules::{
+        gas_exhaustion_min_gas_units, CallCustomModulesCreator, GAS_EXHAUSTION_LOOP_COUNT,
+    },
+    circular_transfer::CircularTransferCreator,
+    create_empty_account::CreateEmptyAccountCreator,
+    custom_coin::CustomCoinGeneratorCreator,
+    dex_swap::DexSwapCreator,
+    framework_upgrade_transfer::FrameworkUpgradeTransferCreator,
+    fungible_asset_transfer::FungibleAssetTransferGeneratorCreator,
+    gas_price_distribution::GasPriceDistribution,
+    identical_burst::IdenticalBurstCreator,
+    many_signers::ManySignersCreator,
+    marked_events::MarkedEventsCreator,
+    marketplace::MarketplaceGeneratorCreator,
+    multi_agent_generator::MultiAgentCreator,
+    nft_mint_and_transfer::{NFTMintAndTransferGeneratorCreator, DEFAULT_NFT_COLLECTION_SIZE},
+    observed_generator::ObservedGeneratorCreator,
+    oracle_update::OracleUpdateCreator,
     p2p_transaction_generator::P2PTransactionGeneratorCreator,
     publish_modules::PublishPackageCreator,
+    resumable_rng::{ResumableRng, RngSnapshot},
+    run_manifest::{FactorySettings, FactorySummary},
+    shared_txn_factory::SharedTransactionFactory,
+    skewed_account_size::SkewedAccountSizeCreator,
+    trace_replay::TraceReplayCreator,
     transaction_mix_generator::PhasedTxnMixGeneratorCreator,
+    type_counters::{CountingGeneratorCreator, TransactionTypeCounters},
+    vector_growth::VectorGrowthCreator,
+    wide_read_set::WideReadSetCreator,
 };
-use crate::accounts_pool_wrapper::AccountsPoolWrapperCreator;
+use crate::accounts_pool_wrapper::{AccountPools, AccountsPoolWrapperCreator};
+pub use observed_generator::TransactionObserver;
+pub use p2p_transaction_generator::InvalidTransactionType;
 pub use publishing::module_simple::EntryPoints;
+pub use run_manifest::RunManifest;
+pub use shared_txn_factory::GasOverrides;
+pub use skewed_account_size::AccountSizeDistribution;
+pub use transaction_mix_generator::MixMode;
 
 pub const SEND_AMOUNT: u64 = 1;
+/// Rounds of transfers `TransactionExecutor::fund_accounts` sends before giving up.
+pub const FUND_ACCOUNTS_MAX_ATTEMPTS: usize = 3;
+/// Mixed into the master seed to seed the setup of the generators, so that the setup doesn't
+/// draw the same values (e.g. accounts) as the generators, which are seeded from the master seed.
+const SETUP_SEED_SALT: u64 = 0x9E37_79B9_7F4A_7C15;
 
-#[derive(Debug, Copy, Clone)]
+// Serializable as part of a `RunManifest`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum TransactionType {
     CoinTransfer {
+        // Inclusive range the amount of each transfer is picked from
+        amount_range: (u64, u64),
         invalid_transaction_ratio: usize,
+        // Weighted kinds of the invalid transactions, all the original ones if empty.
+        invalid_kinds: Vec<(InvalidTransactionType, usize)>,
+        sender_use_account_pool: bool,
+        account_pool: Option<String>,
+        gas_overrides: GasOverrides,
+    },
+    // Transfers of the fungible asset with the given metadata object, between primary stores.
+    FungibleAssetTransfer {
+        metadata_address: AccountAddress,
+        amount: u64,
         sender_use_account_pool: bool,
+        account_pool: Option<String>,
     },
     AccountGeneration {
         add_created_accounts_to_pool: bool,
         max_account_working_set: usize,
         creation_balance: u64,
+        // Name of the pool accounts are added to (or taken from, for the pool consumers),
+        // None for the pool shared by all types that don't name one.
+        account_pool: Option<String>,
+        // If set, the pool is capped at this many accounts, and the oldest ones are evicted
+        // (along with their addresses) once exceeded, instead of exchanging random accounts
+        // once max_account_working_set is reached.
+        max_pool_size: Option<usize>,
     },
-    NftMintAndTransfer,
+    // Mints of tokens of a collection of `collection_size`, and transfers back, with mints
+    // making up `mint_ratio` of the transactions.
+    NftMintAndTransfer {
+        collection_size: u64,
+        mint_ratio: f32,
+    },
+    NftDrop {
+        mint_spike: usize,
+        trade_phase: usize,
+    },
+    Marketplace {
+        listings_per_round: usize,
+    },
+    // Publishes packages of `num_modules` modules, each padded with `module_bytes_padding`
+    // bytes. The whole package has to fit in a transaction.
     PublishPackage {
         use_account_pool: bool,
+        account_pool: Option<String>,
+        num_modules: usize,
+        module_bytes_padding: usize,
     },
     CallCustomModules {
         entry_point: EntryPoints,
         num_modules: usize,
+        // Total number of distinct modules to publish and call in rotation,
+        // if larger than num_modules. Used to force module cache misses.
+        module_address_space: usize,
         use_account_pool: bool,
+        account_pool: Option<String>,
+        gas_overrides: GasOverrides,
+    },
+    VectorGrowth {
+        pushes_per_txn: usize,
+    },
+    CustomCoinLifecycle,
+    MarkedEvents,
+    FlashLoan {
+        steps: usize,
+    },
+    UniqueNoOp {
+        num_modules: usize,
+    },
+    SharedCounter {
+        increments_per_txn: usize,
+    },
+    // Registers unique ANS domains. ANS must already be deployed at `ans::ANS_ADDRESS`
+    // (as on mainnet and testnet), creating the generator fails otherwise.
+    AnsRegister {
+        domains_per_round: usize,
+    },
+    OracleUpdate {
+        feeds: usize,
+    },
+    // Reads and writes `members_touched` members of a resource group of the sender.
+    ResourceGroupOps {
+        members_touched: usize,
+    },
+    GasExhaustion,
+    // Multi-agent no-op scripts, to benchmark verifying the signatures.
+    // Clamped to the maximum number of signatures per transaction.
+    ManySigners {
+        num_signers: usize,
+    },
+    // Calls to the entry point, signed by the sender and the given number of secondary
+    // signers, all taken from the accounts of the worker. The entry point must take as many
+    // signers, e.g. `EntryPoints::NopTwoSigners` with one secondary signer.
+    MultiAgent {
+        entry_point: EntryPoints,
+        num_secondary_signers: usize,
+    },
+    // Transfers through both the pre-upgrade (coin::transfer) and the post-upgrade
+    // (aptos_account::transfer) entry function, with the given percentage of the former.
+    FrameworkUpgradeTransfer {
+        legacy_percentage: usize,
+    },
+    // Transfers from every account to itself, so that no recipient state is touched.
+    SelfTransfer {
+        amount: u64,
+    },
+    // Read-only calls, each reading `resources_read` distinct resources (created during setup).
+    // Clamped to what fits in the max gas amount of a transaction.
+    WideReadSet {
+        resources_read: usize,
+    },
+    // Replays the transactions of a captured trace, at the recorded cadence.
+    TraceReplay {
+        trace_path: PathBuf,
+    },
+    // Transfers expiring the given number of seconds after being generated, to test mempool
+    // expiration and garbage collection. Negative values produce already expired transactions.
+    ExpiringCoinTransfer {
+        seconds_until_expiry: i64,
+    },
+    // Bursts of `burst_size` byte-identical transfers per account (with the same sequence
+    // number), to test the duplicate suppression of mempool. Only one of each burst commits.
+    IdenticalBurst {
+        burst_size: usize,
+    },
+    // Transfers along rings of `chain_length` accounts, each sending to the next one within
+    // the same block, to maximize conflicts in the parallel executor.
+    CircularTransfer {
+        chain_length: usize,
+    },
+    // Explicit account creations (without funding) for fresh addresses. Created accounts have
+    // no coin balance, so they can only be targeted, if their addresses are added to the pool.
+    CreateEmptyAccount {
+        add_created_addresses_to_pool: bool,
+        max_working_set: usize,
+    },
+    // Appends to a vector under each account, up to a size picked from a long-tailed
+    // distribution, so that the state holds a few large accounts and many small ones.
+    SkewedAccountSize {
+        distribution: AccountSizeDistribution,
+        pushes_per_txn: usize,
+    },
+    // Swaps between APT and custom coins, in liquidity pools created during setup for each of
+    // the pairs, never selling more coins than the account received from previous swaps.
+    DexSwap {
+        pairs: usize,
     },
 }
 
 impl TransactionType {
     pub fn default_coin_transfer() -> Self {
         Self::CoinTransfer {
+            amount_range: (SEND_AMOUNT, SEND_AMOUNT),
             invalid_transaction_ratio: 0,
+            invalid_kinds: Vec::new(),
             sender_use_account_pool: false,
+            account_pool: None,
+            gas_overrides: GasOverrides::default(),
         }
     }
 
@@ -76,6 +297,15 @@ impl TransactionType {
             add_created_accounts_to_pool: true,
             max_account_working_set: 1_000_000,
             creation_balance: 0,
+            account_pool: None,
+            max_pool_size: None,
+        }
+    }
+
+    pub fn default_nft() -> Self {
+        Self::NftMintAndTransfer {
+            collection_size: DEFAULT_NFT_COLLECTION_SIZE,
+            mint_ratio: 0.5,
         }
     }
 
@@ -83,7 +313,10 @@ impl TransactionType {
         Self::CallCustomModules {
             entry_point: EntryPoints::Nop,
             num_modules: 1,
+            module_address_space: 0,
             use_account_pool: false,
+            account_pool: None,
+            gas_overrides: GasOverrides::default(),
         }
     }
 
@@ -91,7 +324,46 @@ impl TransactionType {
         Self::CallCustomModules {
             entry_point: EntryPoints::Nop,
             num_modules: 100,
+            module_address_space: 0,
             use_account_pool: false,
+            account_pool: None,
+            gas_overrides: GasOverrides::default(),
+        }
+    }
+
+    /// Name of the type, under which the generated transactions are counted.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CoinTransfer { .. } => "CoinTransfer",
+            Self::FungibleAssetTransfer { .. } => "FungibleAssetTransfer",
+            Self::AccountGeneration { .. } => "AccountGeneration",
+            Self::NftMintAndTransfer { .. } => "NftMintAndTransfer",
+            Self::NftDrop { .. } => "NftDrop",
+            Self::Marketplace { .. } => "Marketplace",
+            Self::PublishPackage { .. } => "PublishPackage",
+            Self::CallCustomModules { .. } => "CallCustomModules",
+            Self::VectorGrowth { .. } => "VectorGrowth",
+            Self::CustomCoinLifecycle => "CustomCoinLifecycle",
+            Self::MarkedEvents => "MarkedEvents",
+            Self::FlashLoan { .. } => "FlashLoan",
+            Self::UniqueNoOp { .. } => "UniqueNoOp",
+            Self::SharedCounter { .. } => "SharedCounter",
+            Self::AnsRegister { .. } => "AnsRegister",
+            Self::OracleUpdate { .. } => "OracleUpdate",
+            Self::ResourceGroupOps { .. } => "ResourceGroupOps",
+            Self::GasExhaustion => "GasExhaustion",
+            Self::ManySigners { .. } => "ManySigners",
+            Self::MultiAgent { .. } => "MultiAgent",
+            Self::FrameworkUpgradeTransfer { .. } => "FrameworkUpgradeTransfer",
+            Self::SelfTransfer { .. } => "SelfTransfer",
+            Self::WideReadSet { .. } => "WideReadSet",
+            Self::TraceReplay { .. } => "TraceReplay",
+            Self::ExpiringCoinTransfer { .. } => "ExpiringCoinTransfer",
+            Self::IdenticalBurst { .. } => "IdenticalBurst",
+            Self::CircularTransfer { .. } => "CircularTransfer",
+            Self::CreateEmptyAccount { .. } => "CreateEmptyAccount",
+            Self::SkewedAccountSize { .. } => "SkewedAccountSize",
+            Self::DexSwap { .. } => "DexSwap",
         }
     }
 }
@@ -108,6 +380,37 @@ pub trait TransactionGenerator: Sync + Send {
         accounts: Vec<&mut LocalAccount>,
         transactions_per_account: usize,
     ) -> Vec<SignedTransaction>;
+
+    /// Same transactions as `generate_transactions`, without their signatures, for building
+    /// corpuses fed to an offline simulator. Sequence numbers of the accounts advance the same.
+    /// By default the transactions are still signed, generators override it to skip signing.
+    fn generate_raw_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<RawTransaction> {
+        self.generate_transactions(accounts, transactions_per_account)
+            .into_iter()
+            .map(SignedTransaction::into_raw_transaction)
+            .collect()
+    }
+
+    /// Snapshot of the state of the generator's RNG, to resume the transaction stream
+    /// mid-run with `restore_rng`. None if the generator holds no RNG, or (for wrappers)
+    /// holds one in addition to the inner generator.
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        None
+    }
+
+    /// Restores the RNG from a `rng_snapshot` of a generator created the same way, so that
+    /// subsequent transactions are identical to the ones of the run it was taken from.
+    fn restore_rng(&mut self, _snapshot: &RngSnapshot) {}
+}
+
+/// Hook through which generators report where the time goes while generating transactions.
+pub trait TransactionGeneratorMetrics: Sync + Send {
+    /// Time spent building raw transactions, and signing them, for a single batch.
+    fn record_build_and_sign(&self, build_ns: u64, sign_ns: u64);
 }
 
 pub struct CounterState {
@@ -123,12 +426,54 @@ pub trait TransactionGeneratorCreator: Sync + Send {
     async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator>;
 }
 
+/// Outcome of a single transaction, as observed after submitting and waiting for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TxnStatus {
+    pub hash: HashValue,
+    pub committed: bool,
+    pub vm_status: Option<String>,
+}
+
+/// Type of AptosCoin, for querying balances through `TransactionExecutor::get_coin_balance`.
+pub fn aptos_coin_struct_tag() -> StructTag {
+    match &*APTOS_COIN_TYPE {
+        TypeTag::Struct(struct_tag) => *struct_tag.clone(),
+        _ => unreachable!("AptosCoin must be a struct"),
+    }
+}
+
 #[async_trait]
 pub trait TransactionExecutor: Sync + Send {
     async fn get_account_balance(&self, account_address: AccountAddress) -> Result<u64>;
 
+    /// Balance of the account in the coin of the given type.
+    /// By default, only AptosCoin balances are supported, through `get_account_balance`.
+    async fn get_coin_balance(
+        &self,
+        account_address: AccountAddress,
+        coin_type: StructTag,
+    ) -> Result<u64> {
+        if coin_type != aptos_coin_struct_tag() {
+            bail!(
+                "Balances in {} are not supported by this executor",
+                coin_type
+            );
+        }
+        self.get_account_balance(account_address).await
+    }
+
     async fn query_sequence_number(&self, account_address: AccountAddress) -> Result<u64>;
 
+    /// Sequence numbers of the given accounts, in the same order.
+    /// By default, queries them one account at a time.
+    async fn query_sequence_numbers(&self, addresses: &[AccountAddress]) -> Result<Vec<u64>> {
+        let mut sequence_numbers = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            sequence_numbers.push(self.query_sequence_number(*address).await?);
+        }
+        Ok(sequence_numbers)
+    }
+
     async fn execute_transactions(&self, txns: &[SignedTransaction]) -> Result<()>;
 
     async fn execute_transactions_with_counter(
@@ -138,6 +483,165 @@ pub trait TransactionExecutor: Sync + Send {
     ) -> Result<()>;
 
     fn create_counter_state(&self) -> CounterState;
+
+    /// Executes transactions, retrying the whole batch on failure, up to `max_retries` times,
+    /// with exponential backoff (starting at `base_backoff`) and jitter in between.
+    /// Failures are counted across all attempts, and reported if all of them fail.
+    async fn execute_transactions_with_retries(
+        &self,
+        txns: &[SignedTransaction],
+        max_retries: usize,
+        base_backoff: Duration,
+    ) -> Result<()> {
+        let state = self.create_counter_state();
+        let mut attempt = 0;
+        loop {
+            let err = match self.execute_transactions_with_counter(txns, &state).await {
+                Ok(()) => return Ok(()),
+                Err(err) => err,
+            };
+            if attempt >= max_retries {
+                return Err(err.context(format!(
+                    "Giving up executing {} txns after {} attempts, {}",
+                    txns.len(),
+                    attempt + 1,
+                    state.show_simple()
+                )));
+            }
+            // Jitter spreads out the retries of concurrent callers
+            let backoff = base_backoff.saturating_mul(2u32.saturating_pow(attempt as u32));
+            let backoff = backoff.mul_f64(thread_rng().gen_range(0.5, 1.5));
+            sample!(
+                SampleRate::Duration(Duration::from_secs(60)),
+                warn!(
+                    "Failed executing {} txns, retrying in {:?}: {}",
+                    txns.len(),
+                    backoff,
+                    err
+                )
+            );
+            tokio::time::sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+
+    /// Fetches the ABI of the module currently published under the given address and name.
+    async fn get_module(&self, address: AccountAddress, module_name: &str) -> Result<MoveModule>;
+
+    /// Estimates the gas used by the transaction, by simulating it against the latest state,
+    /// without committing it. Not supported by default.
+    async fn estimate_gas(&self, _txn: &SignedTransaction) -> Result<u64> {
+        bail!("Gas estimation is not supported by this executor")
+    }
+
+    /// Fullness of the mempool of the node transactions are submitted to,
+    /// between 0 (empty) and 1 (full). Not supported by default.
+    async fn mempool_load(&self) -> Result<f64> {
+        bail!("Mempool load is not supported by this executor")
+    }
+
+    /// Minimum gas unit price accepted by the network, from the on-chain gas schedule.
+    /// Fetched once, and cached for the rest of the run. Not supported by default.
+    async fn min_gas_unit_price(&self) -> Result<u64> {
+        bail!("Minimum gas unit price is not supported by this executor")
+    }
+
+    /// Executes transactions, and returns the outcome of each of them, in the same order.
+    /// By default, whether a transaction got committed is derived from the sender's
+    /// sequence number, and the VM status is not known.
+    async fn submit_and_await(&self, txns: &[SignedTransaction]) -> Result<Vec<TxnStatus>> {
+        if let Err(err) = self.execute_transactions(txns).await {
+            sample!(
+                SampleRate::Duration(Duration::from_secs(60)),
+                warn!(
+                    "Failed executing {} txns, checking which got committed: {}",
+                    txns.len(),
+                    err
+                )
+            );
+        }
+
+        // Every sender is only queried once, all of them in a single batch
+        let mut senders = Vec::new();
+        for txn in txns {
+            if !senders.contains(&txn.sender()) {
+                senders.push(txn.sender());
+            }
+        }
+        let sequence_numbers = senders
+            .iter()
+            .copied()
+            .zip(self.query_sequence_numbers(&senders).await?)
+            .collect::<HashMap<_, _>>();
+
+        Ok(txns
+            .iter()
+            .map(|txn| TxnStatus {
+                hash: txn.clone().committed_hash(),
+                committed: txn.sequence_number() < sequence_numbers[&txn.sender()],
+                vm_status: None,
+            })
+            .collect())
+    }
+
+    /// Funds every target from `funder`, so that it holds (at least) the given balance, and
+    /// verifies it through `get_account_balance`. Targets still short of their balance after
+    /// a round of transfers (e.g., because some transactions got dropped) are topped up in the
+    /// next one, up to `FUND_ACCOUNTS_MAX_ATTEMPTS` rounds. Accounts whose balance can't be
+    /// queried (e.g., because they don't exist yet) count as empty.
+    async fn fund_accounts(
+        &self,
+        funder: &mut LocalAccount,
+        targets: &[(AccountAddress, u64)],
+        factory: &TransactionFactory,
+    ) -> Result<()> {
+        let mut shortfalls = Vec::with_capacity(targets.len());
+        for attempt in 0..=FUND_ACCOUNTS_MAX_ATTEMPTS {
+            shortfalls.clear();
+            for (address, balance) in targets {
+                let current = self.get_account_balance(*address).await.unwrap_or(0);
+                if current < *balance {
+                    shortfalls.push((*address, *balance - current));
+                }
+            }
+            if shortfalls.is_empty() {
+                return Ok(());
+            }
+            if attempt == FUND_ACCOUNTS_MAX_ATTEMPTS {
+                break;
+            }
+
+            let txns = shortfalls
+                .iter()
+                .map(|(address, amount)| {
+                    funder.sign_with_transaction_builder(
+                        factory.payload(aptos_stdlib::aptos_account_transfer(*address, *amount)),
+                    )
+                })
+                .collect::<Vec<_>>();
+            if let Err(err) = self.execute_transactions(&txns).await {
+                sample!(
+                    SampleRate::Duration(Duration::from_secs(60)),
+                    warn!(
+                        "Failed funding {} accounts, verifying their balances: {}",
+                        txns.len(),
+                        err
+                    )
+                );
+            }
+            // Sequence numbers of the transactions that didn't commit are reused
+            *funder.sequence_number_mut() = self.query_sequence_number(funder.address()).await?;
+        }
+        bail!(
+            "{} of {} accounts are still short of their balance after funding them {} times, \
+            e.g. {} by {}",
+            shortfalls.len(),
+            targets.len(),
+            FUND_ACCOUNTS_MAX_ATTEMPTS,
+            shortfalls[0].0,
+            shortfalls[0].1
+        )
+    }
 }
 
 fn failed_requests_to_trimmed_vec(failed_requests: &[AtomicUsize]) -> Vec<usize> {
@@ -183,6 +687,13 @@ impl CounterState {
     }
 }
 
+/// Creates the generators of the transaction mix, along with the counts of the transactions
+/// they generate, per transaction type, and the manifest to recreate an equivalent run from.
+/// RNGs of the generators, and of their setup, are seeded from `master_seed`. If set, `observer`
+/// is invoked with every transaction generated, otherwise the generators are not wrapped at all.
+/// If set, gas unit prices of the generated transactions are sampled from `gas_price_distribution`.
+/// Fails if a generator can't be set up, e.g. if the trace to replay can't be loaded, or if the
+/// distribution (or the gas overrides of a transaction type) is invalid.
 pub async fn create_txn_generator_creator(
     transaction_mix_per_phase: &[Vec<(TransactionType, usize)>],
     num_workers: usize,
@@ -190,12 +701,57 @@ pub async fn create_txn_generator_creator(
     txn_executor: &dyn TransactionExecutor,
     txn_factory: &TransactionFactory,
     init_txn_factory: &TransactionFactory,
+    gas_price_distribution: Option<GasPriceDistribution>,
     cur_phase: Arc<AtomicUsize>,
-) -> Box<dyn TransactionGeneratorCreator> {
+    mix_mode: MixMode,
+    master_seed: u64,
+    observer: Option<TransactionObserver>,
+) -> Result<(
+    Box<dyn TransactionGeneratorCreator>,
+    TransactionTypeCounters,
+    RunManifest,
+)> {
+    let manifest = RunManifest {
+        mix: transaction_mix_per_phase.to_vec(),
+        mix_mode,
+        master_seed,
+        num_workers,
+        factory_summary: FactorySummary {
+            txn_factory: FactorySettings::from_factory(txn_factory),
+            init_txn_factory: FactorySettings::from_factory(init_txn_factory),
+            gas_price_distribution,
+        },
+    };
     let all_addresses = Arc::new(RwLock::new(
         all_accounts.iter().map(|d| d.address()).collect::<Vec<_>>(),
     ));
-    let accounts_pool = Arc::new(RwLock::new(Vec::new()));
+    let mut account_pools = AccountPools::default();
+    let type_counters = TransactionTypeCounters::default();
+    // All generators share the factories, instead of each of them owning a copy
+    let mut txn_factory = SharedTransactionFactory::from(txn_factory.clone());
+    let mut init_txn_factory = SharedTransactionFactory::from(init_txn_factory.clone());
+    if let Some(gas_price_distribution) = gas_price_distribution {
+        gas_price_distribution.validate()?;
+        txn_factory = txn_factory.with_gas_price_distribution(gas_price_distribution);
+    }
+    for (transaction_type, _) in transaction_mix_per_phase.iter().flatten() {
+        if let TransactionType::CoinTransfer { gas_overrides, .. }
+        | TransactionType::CallCustomModules { gas_overrides, .. } = transaction_type
+        {
+            gas_overrides.validate()?;
+        }
+    }
+    // Transactions priced below the network floor would all be rejected
+    match txn_executor.min_gas_unit_price().await {
+        Ok(min_gas_unit_price) => {
+            txn_factory = txn_factory.with_min_gas_unit_price(min_gas_unit_price);
+            init_txn_factory = init_txn_factory.with_min_gas_unit_price(min_gas_unit_price);
+        },
+        Err(err) => warn!("Not enforcing the minimum gas unit price: {}", err),
+    }
+    // Accounts and packages created during setup are seeded from `master_seed` too, so that
+    // recreating the run sets up the same ones
+    let mut setup_rng = ResumableRng::seed_from_u64(master_seed ^ SETUP_SEED_SALT);
 
     let mut txn_generator_creator_mix_per_phase: Vec<
         Vec<(Box<dyn TransactionGeneratorCreator>, usize)>,
@@ -220,74 +776,438 @@ pub async fn create_txn_generator_creator(
             let txn_generator_creator: Box<dyn TransactionGeneratorCreator> = match transaction_type
             {
                 TransactionType::CoinTransfer {
+                    amount_range,
                     invalid_transaction_ratio,
+                    invalid_kinds,
                     sender_use_account_pool,
+                    account_pool,
+                    gas_overrides,
                 } => wrap_accounts_pool(
-                    Box::new(P2PTransactionGeneratorCreator::new(
+                    Box::new(
+                        P2PTransactionGeneratorCreator::new(
+                            gas_overrides.apply(txn_factory.clone()),
+                            *amount_range,
+                            all_addresses.clone(),
+                            *invalid_transaction_ratio,
+                            None,
+                        )
+                        .with_invalid_kinds(invalid_kinds.clone()),
+                    ),
+                    *sender_use_account_pool,
+                    account_pools.get(account_pool.as_deref()),
+                ),
+                TransactionType::FungibleAssetTransfer {
+                    metadata_address,
+                    amount,
+                    sender_use_account_pool,
+                    account_pool,
+                } => wrap_accounts_pool(
+                    Box::new(FungibleAssetTransferGeneratorCreator::new(
                         txn_factory.clone(),
-                        SEND_AMOUNT,
+                        *metadata_address,
+                        *amount,
                         all_addresses.clone(),
-                        *invalid_transaction_ratio,
                     )),
                     *sender_use_account_pool,
-                    accounts_pool.clone(),
+                    account_pools.get(account_pool.as_deref()),
                 ),
                 TransactionType::AccountGeneration {
                     add_created_accounts_to_pool,
                     max_account_working_set,
                     creation_balance,
-                } => Box::new(AccountGeneratorCreator::new(
-                    txn_factory.clone(),
-                    all_addresses.clone(),
-                    accounts_pool.clone(),
-                    *add_created_accounts_to_pool,
-                    *max_account_working_set,
-                    *creation_balance,
-                )),
-                TransactionType::NftMintAndTransfer => Box::new(
+                    account_pool,
+                    max_pool_size,
+                } => {
+                    let creator = AccountGeneratorCreator::new(
+                        txn_factory.clone(),
+                        all_addresses.clone(),
+                        account_pools.get(account_pool.as_deref()),
+                        *add_created_accounts_to_pool,
+                        *max_account_working_set,
+                        *creation_balance,
+                    );
+                    Box::new(match max_pool_size {
+                        Some(max_pool_size) => creator.with_max_pool_size(*max_pool_size),
+                        None => creator,
+                    })
+                },
+                TransactionType::NftMintAndTransfer {
+                    collection_size,
+                    mint_ratio,
+                } => Box::new(
                     NFTMintAndTransferGeneratorCreator::new(
+                        &mut setup_rng,
                         txn_factory.clone(),
                         init_txn_factory.clone(),
                         all_accounts.get_mut(0).unwrap(),
                         txn_executor,
                         num_workers,
+                        *collection_size,
+                    )
+                    .await
+                    .with_mint_ratio(*mint_ratio),
+                ),
+                TransactionType::NftDrop {
+                    mint_spike,
+                    trade_phase,
+                } => Box::new(
+                    NFTMintAndTransferGeneratorCreator::new(
+                        &mut setup_rng,
+                        txn_factory.clone(),
+                        init_txn_factory.clone(),
+                        all_accounts.get_mut(0).unwrap(),
+                        txn_executor,
+                        num_workers,
+                        DEFAULT_NFT_COLLECTION_SIZE,
+                    )
+                    .await
+                    .with_drop(*mint_spike, *trade_phase),
+                ),
+                TransactionType::Marketplace { listings_per_round } => Box::new(
+                    MarketplaceGeneratorCreator::new(
+                        &mut setup_rng,
+                        txn_factory.clone(),
+                        init_txn_factory.clone(),
+                        all_accounts,
+                        txn_executor,
+                        num_workers,
+                        *listings_per_round,
                     )
                     .await,
                 ),
-                TransactionType::PublishPackage { use_account_pool } => wrap_accounts_pool(
-                    Box::new(PublishPackageCreator::new(txn_factory.clone())),
+                TransactionType::PublishPackage {
+                    use_account_pool,
+                    account_pool,
+                    num_modules,
+                    module_bytes_padding,
+                } => wrap_accounts_pool(
+                    Box::new(PublishPackageCreator::new(
+                        txn_factory.clone(),
+                        *num_modules,
+                        *module_bytes_padding,
+                    )),
                     *use_account_pool,
-                    accounts_pool.clone(),
+                    account_pools.get(account_pool.as_deref()),
                 ),
                 TransactionType::CallCustomModules {
                     entry_point,
                     num_modules,
+                    module_address_space,
                     use_account_pool,
+                    account_pool,
+                    gas_overrides,
                 } => wrap_accounts_pool(
                     Box::new(
                         CallCustomModulesCreator::new(
-                            txn_factory.clone(),
+                            &mut setup_rng,
+                            gas_overrides.apply(txn_factory.clone()),
                             init_txn_factory.clone(),
                             all_accounts,
                             txn_executor,
                             *entry_point,
                             *num_modules,
                         )
+                        .await
+                        .with_module_address_space(
+                            &mut setup_rng,
+                            &init_txn_factory,
+                            all_accounts,
+                            txn_executor,
+                            *module_address_space,
+                        )
                         .await,
                     ),
                     *use_account_pool,
-                    accounts_pool.clone(),
+                    account_pools.get(account_pool.as_deref()),
+                ),
+                TransactionType::VectorGrowth { pushes_per_txn } => Box::new(
+                    VectorGrowthCreator::new(
+                        &mut setup_rng,
+                        txn_factory.clone(),
+                        init_txn_factory.clone(),
+                        all_accounts.get_mut(0).unwrap(),
+                        txn_executor,
+                        *pushes_per_txn,
+                    )
+                    .await,
+                ),
+                TransactionType::CustomCoinLifecycle => Box::new(
+                    CustomCoinGeneratorCreator::new(
+                        &mut setup_rng,
+                        txn_factory.clone(),
+                        init_txn_factory.clone(),
+                        all_accounts,
+                        txn_executor,
+                    )
+                    .await,
+                ),
+                TransactionType::MarkedEvents => Box::new(
+                    MarkedEventsCreator::new(
+                        &mut setup_rng,
+                        txn_factory.clone(),
+                        init_txn_factory.clone(),
+                        all_accounts.get_mut(0).unwrap(),
+                        txn_executor,
+                    )
+                    .await,
+                ),
+                TransactionType::FlashLoan { steps } => Box::new(
+                    CallCustomModulesCreator::new(
+                        &mut setup_rng,
+                        txn_factory.clone(),
+                        init_txn_factory.clone(),
+                        all_accounts,
+                        txn_executor,
+                        EntryPoints::FlashLoan {
+                            steps: *steps as u64,
+                        },
+                        1,
+                    )
+                    .await,
+                ),
+                // Committed as out of gas, so these count as executed (but failed),
+                // not as submission failures like transactions rejected at validation.
+                TransactionType::GasExhaustion => Box::new(
+                    CallCustomModulesCreator::new(
+                        &mut setup_rng,
+                        txn_factory
+                            .clone()
+                            .with_max_gas_amount(gas_exhaustion_min_gas_units() - 1),
+                        init_txn_factory.clone(),
+                        all_accounts,
+                        txn_executor,
+                        EntryPoints::Loopy {
+                            loop_count: Some(GAS_EXHAUSTION_LOOP_COUNT),
+                        },
+                        1,
+                    )
+                    .await,
+                ),
+                TransactionType::UniqueNoOp { num_modules } => Box::new(
+                    CallCustomModulesCreator::new(
+                        &mut setup_rng,
+                        txn_factory.clone(),
+                        init_txn_factory.clone(),
+                        all_accounts,
+                        txn_executor,
+                        EntryPoints::Nop,
+                        *num_modules,
+                    )
+                    .await
+                    .with_unique_payloads(),
+                ),
+                TransactionType::SharedCounter { increments_per_txn } => Box::new(
+                    CallCustomModulesCreator::new(
+                        &mut setup_rng,
+                        txn_factory.clone(),
+                        init_txn_factory.clone(),
+                        all_accounts,
+                        txn_executor,
+                        EntryPoints::IncrementSharedCounter {
+                            increments: *increments_per_txn as u64,
+                        },
+                        1,
+                    )
+                    .await,
+                ),
+                TransactionType::ResourceGroupOps { members_touched } => Box::new(
+                    CallCustomModulesCreator::new(
+                        &mut setup_rng,
+                        txn_factory.clone(),
+                        init_txn_factory.clone(),
+                        all_accounts,
+                        txn_executor,
+                        EntryPoints::ResourceGroupOps {
+                            members_touched: *members_touched as u64,
+                        },
+                        1,
+                    )
+                    .await,
+                ),
+                TransactionType::AnsRegister { domains_per_round } => Box::new(
+                    AnsRegisterGeneratorCreator::new(
+                        txn_factory.clone(),
+                        txn_executor,
+                        AccountAddress::from_hex_literal(ANS_ADDRESS).unwrap(),
+                        *domains_per_round,
+                        false,
+                    )
+                    .await?,
+                ),
+                TransactionType::OracleUpdate { feeds } => Box::new(
+                    OracleUpdateCreator::new(
+                        &mut setup_rng,
+                        txn_factory.clone(),
+                        init_txn_factory.clone(),
+                        all_accounts.get_mut(0).unwrap(),
+                        txn_executor,
+                        num_workers,
+                        *feeds,
+                    )
+                    .await,
+                ),
+                TransactionType::ManySigners { num_signers } => Box::new(
+                    ManySignersCreator::new(
+                        &mut setup_rng,
+                        txn_factory.clone(),
+                        init_txn_factory.clone(),
+                        all_accounts.get_mut(0).unwrap(),
+                        txn_executor,
+                        *num_signers,
+                    )
+                    .await,
+                ),
+                TransactionType::MultiAgent {
+                    entry_point,
+                    num_secondary_signers,
+                } => {
+                    if entry_point.num_secondary_signers() != *num_secondary_signers {
+                        bail!(
+                            "{} takes {} secondary signers, not {}",
+                            entry_point.function_name(),
+                            entry_point.num_secondary_signers(),
+                            num_secondary_signers
+                        );
+                    }
+                    Box::new(
+                        MultiAgentCreator::new(
+                            &mut setup_rng,
+                            txn_factory.clone(),
+                            init_txn_factory.clone(),
+                            all_accounts.get_mut(0).unwrap(),
+                            txn_executor,
+                            *entry_point,
+                            *num_secondary_signers,
+                        )
+                        .await,
+                    )
+                },
+                TransactionType::FrameworkUpgradeTransfer { legacy_percentage } => {
+                    Box::new(FrameworkUpgradeTransferCreator::new(
+                        txn_factory.clone(),
+                        all_addresses.clone(),
+                        *legacy_percentage,
+                    ))
+                },
+                TransactionType::SelfTransfer { amount } => Box::new(
+                    P2PTransactionGeneratorCreator::new(
+                        txn_factory.clone(),
+                        (*amount, *amount),
+                        all_addresses.clone(),
+                        0,
+                        None,
+                    )
+                    .with_self_transfer(),
+                ),
+                TransactionType::WideReadSet { resources_read } => Box::new(
+                    WideReadSetCreator::new(
+                        &mut setup_rng,
+                        txn_factory.clone(),
+                        init_txn_factory.clone(),
+                        all_accounts.get_mut(0).unwrap(),
+                        txn_executor,
+                        *resources_read,
+                    )
+                    .await,
+                ),
+                TransactionType::TraceReplay { trace_path } => Box::new(
+                    TraceReplayCreator::from_file(txn_factory.clone(), trace_path)?,
+                ),
+                TransactionType::ExpiringCoinTransfer {
+                    seconds_until_expiry,
+                } => Box::new(P2PTransactionGeneratorCreator::new(
+                    txn_factory
+                        .clone()
+                        .with_expiration_offset_secs(*seconds_until_expiry),
+                    (SEND_AMOUNT, SEND_AMOUNT),
+                    all_addresses.clone(),
+                    0,
+                    None,
+                )),
+                TransactionType::IdenticalBurst { burst_size } => {
+                    Box::new(IdenticalBurstCreator::new(
+                        txn_factory.clone(),
+                        all_addresses.clone(),
+                        *burst_size,
+                    ))
+                },
+                TransactionType::CircularTransfer { chain_length } => Box::new(
+                    CircularTransferCreator::new(txn_factory.clone(), *chain_length),
+                ),
+                TransactionType::CreateEmptyAccount {
+                    add_created_addresses_to_pool,
+                    max_working_set,
+                } => Box::new(CreateEmptyAccountCreator::new(
+                    txn_factory.clone(),
+                    all_addresses.clone(),
+                    *add_created_addresses_to_pool,
+                    *max_working_set,
+                )),
+                TransactionType::SkewedAccountSize {
+                    distribution,
+                    pushes_per_txn,
+                } => Box::new(
+                    SkewedAccountSizeCreator::new(
+                        &mut setup_rng,
+                        txn_factory.clone(),
+                        init_txn_factory.clone(),
+                        all_accounts.get_mut(0).unwrap(),
+                        txn_executor,
+                        *distribution,
+                        *pushes_per_txn,
+                    )
+                    .await,
+                ),
+                TransactionType::DexSwap { pairs } => Box::new(
+                    DexSwapCreator::new(
+                        &mut setup_rng,
+                        txn_factory.clone(),
+                        init_txn_factory.clone(),
+                        all_accounts,
+                        txn_executor,
+                        *pairs,
+                    )
+                    .await,
                 ),
             };
-            txn_generator_creator_mix.push((txn_generator_creator, *weight));
+            txn_generator_creator_mix.push((
+                Box::new(CountingGeneratorCreator::new(
+                    txn_generator_creator,
+                    transaction_type.name(),
+                    type_counters.clone(),
+                )),
+                *weight,
+            ));
         }
         txn_generator_creator_mix_per_phase.push(txn_generator_creator_mix)
     }
 
-    Box::new(PhasedTxnMixGeneratorCreator::new(
-        txn_generator_creator_mix_per_phase,
-        cur_phase,
-    ))
+    let txn_generator_creator: Box<dyn TransactionGeneratorCreator> = Box::new(
+        PhasedTxnMixGeneratorCreator::new(txn_generator_creator_mix_per_phase, cur_phase)
+            .with_mix_mode(mix_mode)
+            .with_master_seed(master_seed),
+    );
+    let txn_generator_creator: Box<dyn TransactionGeneratorCreator> = match observer {
+        Some(observer) => Box::new(ObservedGeneratorCreator::new(
+            txn_generator_creator,
+            observer,
+        )),
+        None => txn_generator_creator,
+    };
+    Ok((txn_generator_creator, type_counters, manifest))
+}
+
+/// Same as `LocalAccount::sign_with_transaction_builder`, without the signing.
+pub(crate) fn build_raw_transaction(
+    account: &mut LocalAccount,
+    builder: TransactionBuilder,
+) -> RawTransaction {
+    let raw_txn = builder
+        .sender(account.address())
+        .sequence_number(account.sequence_number())
+        .build();
+    *account.sequence_number_mut() += 1;
+    raw_txn
 }
 
 fn get_account_to_burn_from_pool(
@@ -307,3 +1227,239 @@ fn get_account_to_burn_from_pool(
         .drain((num_in_pool - needed)..)
         .collect::<Vec<_>>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::in_memory_executor::InMemoryExecutor;
+    use aptos_sdk::{transaction_builder::aptos_stdlib, types::chain_id::ChainId};
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_submit_and_await_mixed_statuses() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let txn_factory = TransactionFactory::new(ChainId::test());
+        let mut committed_sender = LocalAccount::generate(&mut rng);
+        let mut dropped_sender = LocalAccount::generate(&mut rng);
+        let dropped_address = dropped_sender.address();
+        let executor = InMemoryExecutor::default()
+            .with_drop_filter(move |txn| txn.sender() == dropped_address);
+        let payload =
+            || txn_factory.payload(aptos_stdlib::aptos_coin_transfer(AccountAddress::ONE, 1));
+        let txns = vec![
+            committed_sender.sign_with_transaction_builder(payload()),
+            dropped_sender.sign_with_transaction_builder(payload()),
+            committed_sender.sign_with_transaction_builder(payload()),
+        ];
+
+        // Failures of the batch are not propagated, the statuses tell which got committed
+        let statuses = executor.submit_and_await(&txns).await.unwrap();
+        assert_eq!(
+            statuses,
+            txns.iter()
+                .zip([true, false, true])
+                .map(|(txn, committed)| TxnStatus {
+                    hash: txn.clone().committed_hash(),
+                    committed,
+                    vm_status: None,
+                })
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sequence_numbers_queried_in_one_batch() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let txn_factory = TransactionFactory::new(ChainId::test());
+        let mut accounts = (0..2)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        // Two transactions from the first account, one from the second
+        let txns = (0..3)
+            .map(|i| {
+                accounts[i / 2].sign_with_transaction_builder(
+                    txn_factory.payload(aptos_stdlib::aptos_coin_transfer(AccountAddress::ONE, 1)),
+                )
+            })
+            .collect::<Vec<_>>();
+        let dropped = txns[1].clone().committed_hash();
+        let executor = InMemoryExecutor::default()
+            .with_drop_filter(move |txn| txn.clone().committed_hash() == dropped);
+
+        let statuses = executor.submit_and_await(&txns).await.unwrap();
+        // Sequence number 0 of both accounts got committed, 1 of the first one didn't
+        assert_eq!(
+            statuses
+                .iter()
+                .map(|status| status.committed)
+                .collect::<Vec<_>>(),
+            vec![true, false, true]
+        );
+        assert_eq!(*executor.sequence_number_queries.lock(), vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_gas_without_executing() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut account = LocalAccount::generate(&mut rng);
+        let txn = account.sign_with_transaction_builder(
+            TransactionFactory::new(ChainId::test())
+                .payload(aptos_stdlib::aptos_coin_transfer(AccountAddress::ONE, 1)),
+        );
+        // Unsupported unless the executor can simulate transactions
+        assert!(InMemoryExecutor::default()
+            .estimate_gas(&txn)
+            .await
+            .is_err());
+
+        let executor = InMemoryExecutor::default().with_gas_estimate(1_234);
+        assert_eq!(executor.estimate_gas(&txn).await.unwrap(), 1_234);
+        assert_eq!(executor.estimate_gas(&txn).await.unwrap(), 1_234);
+        assert!(executor.executed.lock().is_empty());
+        assert_eq!(
+            executor
+                .query_sequence_number(account.address())
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_coin_balance_defaults_to_aptos_coin() {
+        let executor = InMemoryExecutor::default();
+        executor.set_balance(AccountAddress::ONE, 7);
+        assert_eq!(
+            executor
+                .get_coin_balance(AccountAddress::ONE, aptos_coin_struct_tag())
+                .await
+                .unwrap(),
+            7
+        );
+
+        // Only AptosCoin balances are supported by default
+        let custom_coin = StructTag::from_str("0xABCD::custom_coin::CustomCoin").unwrap();
+        assert!(executor
+            .get_coin_balance(AccountAddress::ONE, custom_coin)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fund_accounts_tops_up_shortfalls() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut funder = LocalAccount::generate(&mut rng);
+        let partially_funded = AccountAddress::random();
+        let targets = vec![
+            (AccountAddress::random(), 100),
+            (partially_funded, 50),
+            (AccountAddress::random(), 200),
+        ];
+        // The last transfer of the first round is dropped
+        let mut dropped = false;
+        let executor = InMemoryExecutor::default().with_drop_filter(move |txn| {
+            let drop = !dropped && txn.sequence_number() == 2;
+            dropped |= drop;
+            drop
+        });
+        executor.set_balance(partially_funded, 20);
+
+        executor
+            .fund_accounts(
+                &mut funder,
+                &targets,
+                &TransactionFactory::new(ChainId::test()),
+            )
+            .await
+            .unwrap();
+
+        // The dropped transfer is sent again, and only the missing amounts are transferred
+        for (address, balance) in &targets {
+            assert_eq!(executor.balance(*address), Some(*balance));
+        }
+        // The sequence number of the dropped transfer is reused
+        assert_eq!(funder.sequence_number(), 3);
+        // Balances are checked before each of the two rounds of transfers, and after the last
+        assert_eq!(executor.balance_queries.load(Ordering::Relaxed), 9);
+    }
+
+    #[tokio::test]
+    async fn test_multi_agent_signers_must_match_entry_point() {
+        let mut accounts = vec![LocalAccount::generate(&mut StdRng::seed_from_u64(0))];
+        let txn_factory = TransactionFactory::new(ChainId::test());
+        let executor = InMemoryExecutor::default();
+        for (num_secondary_signers, is_ok) in [(1, true), (2, false)] {
+            let result = create_txn_generator_creator(
+                &[vec![(
+                    TransactionType::MultiAgent {
+                        entry_point: EntryPoints::NopTwoSigners,
+                        num_secondary_signers,
+                    },
+                    1,
+                )]],
+                1,
+                &mut accounts,
+                &executor,
+                &txn_factory,
+                &txn_factory,
+                None,
+                Arc::new(AtomicUsize::new(0)),
+                MixMode::Probabilistic,
+                0,
+                None,
+            )
+            .await;
+            assert_eq!(result.is_ok(), is_ok);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_gas_price_distribution_rejected() {
+        let mut accounts = vec![LocalAccount::generate(&mut StdRng::seed_from_u64(0))];
+        let txn_factory = TransactionFactory::new(ChainId::test());
+        let result = create_txn_generator_creator(
+            &[vec![(TransactionType::default_coin_transfer(), 1)]],
+            1,
+            &mut accounts,
+            &InMemoryExecutor::default(),
+            &txn_factory,
+            &txn_factory,
+            Some(GasPriceDistribution::UniformRange {
+                low: 200,
+                high: 100,
+            }),
+            Arc::new(AtomicUsize::new(0)),
+            MixMode::Probabilistic,
+            0,
+            None,
+        )
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_gas_overrides_rejected() {
+        let mut accounts = vec![LocalAccount::generate(&mut StdRng::seed_from_u64(0))];
+        let txn_factory = TransactionFactory::new(ChainId::test());
+        let mut coin_transfer = TransactionType::default_coin_transfer();
+        if let TransactionType::CoinTransfer { gas_overrides, .. } = &mut coin_transfer {
+            gas_overrides.max_gas_amount = Some((2_000, 1_000));
+        }
+        let result = create_txn_generator_creator(
+            &[vec![(coin_transfer, 1)]],
+            1,
+            &mut accounts,
+            &InMemoryExecutor::default(),
+            &txn_factory,
+            &txn_factory,
+            None,
+            Arc::new(AtomicUsize::new(0)),
+            MixMode::Probabilistic,
+            0,
+            None,
+        )
+        .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    nft_mint_and_transfer::create_and_fund_account_request, resumable_rng::ResumableRng,
+    shared_txn_factory::SharedTransactionFactory, TransactionExecutor, TransactionGenerator,
+    TransactionGeneratorCreator,
+};
+use aptos_logger::{info, warn};
+use aptos_sdk::types::{
+    transaction::{authenticator::MAX_NUM_OF_SIGS, Script, SignedTransaction, TransactionPayload},
+    LocalAccount,
+};
+use async_trait::async_trait;
+use move_binary_format::file_format::empty_script;
+use rand::thread_rng;
+use std::sync::Arc;
+
+/// Script that does nothing, and takes no signers. A script that doesn't take signers
+/// can be called with any number of them, so it works for any number of secondary signers.
+pub fn noop_script() -> Script {
+    let mut code = vec![];
+    empty_script()
+        .serialize(&mut code)
+        .expect("Empty script must serialize");
+    Script::new(code, vec![], vec![])
+}
+
+/// Generates multi-agent transactions calling a no-op script, with all transactions
+/// sharing the same secondary signers, to isolate the cost of verifying the signatures.
+pub struct ManySignersGenerator {
+    txn_factory: SharedTransactionFactory,
+    secondary_signers: Arc<Vec<LocalAccount>>,
+    script: Script,
+}
+
+impl ManySignersGenerator {
+    pub fn new(
+        txn_factory: SharedTransactionFactory,
+        secondary_signers: Arc<Vec<LocalAccount>>,
+    ) -> Self {
+        Self {
+            txn_factory,
+            secondary_signers,
+            script: noop_script(),
+        }
+    }
+}
+
+impl TransactionGenerator for ManySignersGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
+        for account in accounts {
+            for _ in 0..transactions_per_account {
+                requests.push(account.sign_multi_agent_with_transaction_builder(
+                    self.secondary_signers.iter().collect(),
+                    self.txn_factory.payload(
+                        TransactionPayload::Script(self.script.clone()),
+                        &mut thread_rng(),
+                    ),
+                ));
+            }
+        }
+        requests
+    }
+}
+
+/// Largest number of secondary signers, the sender's signature counts towards the limit too.
+pub fn clamp_num_signers(num_signers: usize) -> usize {
+    let max_num_signers = MAX_NUM_OF_SIGS - 1;
+    if num_signers > max_num_signers {
+        warn!(
+            "{} secondary signers requested, using the maximum of {}",
+            num_signers, max_num_signers
+        );
+    }
+    num_signers.min(max_num_signers)
+}
+
+pub struct ManySignersCreator {
+    txn_factory: SharedTransactionFactory,
+    secondary_signers: Arc<Vec<LocalAccount>>,
+}
+
+impl ManySignersCreator {
+    pub async fn new(
+        rng: &mut ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        init_txn_factory: SharedTransactionFactory,
+        root_account: &mut LocalAccount,
+        txn_executor: &dyn TransactionExecutor,
+        num_signers: usize,
+    ) -> Self {
+        // Secondary signers only sign, but they need to exist on chain
+        let secondary_signers = (0..clamp_num_signers(num_signers))
+            .map(|_| LocalAccount::generate(rng))
+            .collect::<Vec<_>>();
+        let txns = secondary_signers
+            .iter()
+            .map(|signer| {
+                create_and_fund_account_request(
+                    root_account,
+                    0,
+                    signer.public_key(),
+                    &init_txn_factory,
+                    rng,
+                )
+            })
+            .collect::<Vec<_>>();
+        info!("Creating {} secondary signer accounts", txns.len());
+        txn_executor.execute_transactions(&txns).await.unwrap();
+        info!("Done creating {} secondary signer accounts", txns.len());
+
+        Self {
+            txn_factory,
+            secondary_signers: Arc::new(secondary_signers),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for ManySignersCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(ManySignersGenerator::new(
+            self.txn_factory.clone(),
+            self.secondary_signers.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{transaction_builder::TransactionFactory, types::chain_id::ChainId};
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_requested_number_of_signers() {
+        assert_eq!(clamp_num_signers(1000), MAX_NUM_OF_SIGS - 1);
+
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let secondary_signers = (0..clamp_num_signers(8))
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let secondary_addresses = secondary_signers
+            .iter()
+            .map(|signer| signer.address())
+            .collect::<Vec<_>>();
+        let mut generator = ManySignersGenerator::new(
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            Arc::new(secondary_signers),
+        );
+        let mut accounts = (0..2)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 3);
+        assert_eq!(txns.len(), 6);
+        for txn in txns {
+            assert_eq!(
+                txn.authenticator().secondary_signer_addreses(),
+                secondary_addresses
+            );
+            assert!(txn.clone().check_signature().is_ok());
+            assert_eq!(txn.payload(), &TransactionPayload::Script(noop_script()));
+        }
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{
+    publishing::{module_simple::EntryPoints, publish_util::Package},
+    TransactionExecutor,
+};
+use crate::{
+    publishing::publish_util::PackageHandler, resumable_rng::ResumableRng,
+    shared_txn_factory::SharedTransactionFactory, TransactionGenerator,
+    TransactionGeneratorCreator,
+};
+use aptos_logger::info;
+use aptos_sdk::types::{transaction::SignedTransaction, LocalAccount};
+use async_trait::async_trait;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+/// Emits a single event per transaction, carrying a marker taken from a counter shared
+/// by all generators of the creator. Markers are unique and increase with the order
+/// in which transactions are generated, so the indexer (or any other consumer of
+/// the event stream) can detect missing events.
+pub struct MarkedEventsGenerator {
+    txn_factory: SharedTransactionFactory,
+    package: Arc<Package>,
+    next_marker: Arc<AtomicU64>,
+}
+
+impl MarkedEventsGenerator {
+    pub fn new(
+        txn_factory: SharedTransactionFactory,
+        package: Arc<Package>,
+        next_marker: Arc<AtomicU64>,
+    ) -> Self {
+        Self {
+            txn_factory,
+            package,
+            next_marker,
+        }
+    }
+}
+
+impl TransactionGenerator for MarkedEventsGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
+        for account in accounts {
+            for _ in 0..transactions_per_account {
+                let marker = self.next_marker.fetch_add(1, Ordering::Relaxed);
+                requests.push(self.package.use_specific_transaction(
+                    EntryPoints::EmitMarker { marker },
+                    account,
+                    &self.txn_factory,
+                    None,
+                    None,
+                ));
+            }
+        }
+        requests
+    }
+}
+
+pub struct MarkedEventsCreator {
+    txn_factory: SharedTransactionFactory,
+    package: Arc<Package>,
+    next_marker: Arc<AtomicU64>,
+}
+
+impl MarkedEventsCreator {
+    pub async fn new(
+        rng: &mut ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        init_txn_factory: SharedTransactionFactory,
+        publisher: &mut LocalAccount,
+        txn_executor: &dyn TransactionExecutor,
+    ) -> Self {
+        let mut package_handler = PackageHandler::new();
+        let package = package_handler.pick_package(rng, publisher);
+        let txn = package.publish_transaction(rng, publisher, &init_txn_factory);
+        info!("Publishing marked events package");
+        txn_executor.execute_transactions(&[txn]).await.unwrap();
+        info!("Done publishing marked events package");
+
+        Self {
+            txn_factory,
+            package: Arc::new(package),
+            next_marker: Arc::new(AtomicU64::new(0)),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for MarkedEventsCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(MarkedEventsGenerator::new(
+            self.txn_factory.clone(),
+            self.package.clone(),
+            self.next_marker.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{bcs, transaction_builder::TransactionFactory, types::chain_id::ChainId};
+    use rand::SeedableRng;
+
+    fn markers(txns: &[SignedTransaction]) -> Vec<u64> {
+        txns.iter()
+            .map(|txn| {
+                let entry_function = txn.payload().clone().into_entry_function();
+                assert_eq!(entry_function.function().as_str(), "emit_marker");
+                bcs::from_bytes(&entry_function.args()[0]).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_markers_strictly_increasing() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let package = Arc::new(Package::simple());
+        let next_marker = Arc::new(AtomicU64::new(0));
+        let mut accounts = (0..5)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+
+        // Generators share the counter, so markers keep increasing across them
+        let mut generators = (0..2)
+            .map(|_| {
+                MarkedEventsGenerator::new(
+                    txn_factory.clone(),
+                    package.clone(),
+                    next_marker.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut all_markers = Vec::new();
+        for _ in 0..3 {
+            for generator in generators.iter_mut() {
+                let txns = generator.generate_transactions(accounts.iter_mut().collect(), 4);
+                all_markers.extend(markers(&txns));
+            }
+        }
+
+        assert_eq!(all_markers.len(), 3 * 2 * 5 * 4);
+        assert!(all_markers.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
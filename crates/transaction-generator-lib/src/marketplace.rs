@@ -0,0 +1,320 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    nft_mint_and_transfer::{
+        create_and_fund_account_request, create_nft_transfer_request, initialize_nft_collection,
+        DEFAULT_NFT_COLLECTION_SIZE,
+    },
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    source_accounts::{execute_across_source_accounts, sign_across_source_accounts},
+    TransactionExecutor, TransactionGenerator, TransactionGeneratorCreator,
+};
+use aptos_logger::info;
+use aptos_sdk::{
+    transaction_builder::aptos_stdlib::aptos_token_stdlib,
+    types::{account_address::AccountAddress, transaction::SignedTransaction, LocalAccount},
+};
+use async_trait::async_trait;
+use rand::{Rng, SeedableRng};
+
+const SELLER_INITIAL_BALANCE: u64 = 100_000_000;
+const SELLER_TOKEN_BALANCE: u64 = 1_000_000_000;
+/// Probability that an open listing is cancelled by the seller, instead of being bought.
+const CANCEL_PROBABILITY: f64 = 0.2;
+
+/// Simulates a token marketplace, using the token_transfers escrow as listings:
+/// seller lists a token for a buyer (offer), and then buyer either buys it (claim),
+/// or seller cancels the listing (cancel offer).
+///
+/// Listings created in a round can only be bought or cancelled in the later rounds,
+/// as transactions within the same round can be executed in any order.
+pub struct MarketplaceGenerator {
+    rng: ResumableRng,
+    txn_factory: SharedTransactionFactory,
+    seller: LocalAccount,
+    creator_address: AccountAddress,
+    collection_name: Vec<u8>,
+    token_name: Vec<u8>,
+    listings_per_round: usize,
+    // buyers for which seller has an open listing
+    open_listings: Vec<AccountAddress>,
+}
+
+impl MarketplaceGenerator {
+    pub fn new(
+        rng: ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        seller: LocalAccount,
+        creator_address: AccountAddress,
+        collection_name: Vec<u8>,
+        token_name: Vec<u8>,
+        listings_per_round: usize,
+    ) -> Self {
+        Self {
+            rng,
+            txn_factory,
+            seller,
+            creator_address,
+            collection_name,
+            token_name,
+            listings_per_round,
+            open_listings: Vec::new(),
+        }
+    }
+
+    fn list(&mut self, buyer: AccountAddress) -> SignedTransaction {
+        self.seller
+            .sign_with_transaction_builder(self.txn_factory.payload(
+                aptos_token_stdlib::token_transfers_offer_script(
+                    buyer,
+                    self.creator_address,
+                    self.collection_name.clone(),
+                    self.token_name.clone(),
+                    0,
+                    1,
+                ),
+                &mut self.rng,
+            ))
+    }
+
+    fn buy(&mut self, buyer: &mut LocalAccount) -> SignedTransaction {
+        buyer.sign_with_transaction_builder(self.txn_factory.payload(
+            aptos_token_stdlib::token_transfers_claim_script(
+                self.seller.address(),
+                self.creator_address,
+                self.collection_name.clone(),
+                self.token_name.clone(),
+                0,
+            ),
+            &mut self.rng,
+        ))
+    }
+
+    fn cancel(&mut self, buyer: AccountAddress) -> SignedTransaction {
+        self.seller
+            .sign_with_transaction_builder(self.txn_factory.payload(
+                aptos_token_stdlib::token_transfers_cancel_offer_script(
+                    buyer,
+                    self.creator_address,
+                    self.collection_name.clone(),
+                    self.token_name.clone(),
+                    0,
+                ),
+                &mut self.rng,
+            ))
+    }
+}
+
+impl TransactionGenerator for MarketplaceGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
+        let mut new_listings = Vec::new();
+        for account in accounts {
+            for _ in 0..transactions_per_account {
+                let buyer = account.address();
+                if let Some(idx) = self.open_listings.iter().position(|b| *b == buyer) {
+                    self.open_listings.swap_remove(idx);
+                    requests.push(
+                        if self.rng.gen_bool(CANCEL_PROBABILITY) {
+                            self.cancel(buyer)
+                        } else {
+                            self.buy(account)
+                        },
+                    );
+                } else if new_listings.len() < self.listings_per_round
+                    && !new_listings.contains(&buyer)
+                {
+                    requests.push(self.list(buyer));
+                    new_listings.push(buyer);
+                }
+            }
+        }
+        self.open_listings.append(&mut new_listings);
+        requests
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
+}
+
+pub struct MarketplaceGeneratorCreator {
+    txn_factory: SharedTransactionFactory,
+    creator_address: AccountAddress,
+    sellers: Vec<LocalAccount>,
+    collection_name: Vec<u8>,
+    token_name: Vec<u8>,
+    listings_per_round: usize,
+}
+
+impl MarketplaceGeneratorCreator {
+    pub async fn new(
+        rng: &mut ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        init_txn_factory: SharedTransactionFactory,
+        source_accounts: &mut [LocalAccount],
+        txn_executor: &dyn TransactionExecutor,
+        num_workers: usize,
+        listings_per_round: usize,
+    ) -> Self {
+        let mut creator_account = LocalAccount::generate(rng);
+        let creator_address = creator_account.address();
+        let collection_name = "marketplace collection".to_owned().into_bytes();
+        let token_name = "marketplace token".to_owned().into_bytes();
+        initialize_nft_collection(
+            txn_executor,
+            source_accounts
+                .get_mut(0)
+                .expect("Must have source accounts"),
+            &mut creator_account,
+            &init_txn_factory,
+            rng,
+            &collection_name,
+            &token_name,
+            DEFAULT_NFT_COLLECTION_SIZE,
+        )
+        .await;
+
+        let sellers = (0..num_workers)
+            .map(|_| LocalAccount::generate(rng))
+            .collect::<Vec<_>>();
+        // Funding is spread across source accounts, token transfers all come from the creator
+        let create_txns =
+            sign_across_source_accounts(source_accounts, &sellers, |source_account, seller| {
+                create_and_fund_account_request(
+                    source_account,
+                    SELLER_INITIAL_BALANCE,
+                    seller.public_key(),
+                    &init_txn_factory,
+                    rng,
+                )
+            });
+        let token_txns = sellers
+            .iter()
+            .map(|seller| {
+                create_nft_transfer_request(
+                    &mut creator_account,
+                    seller,
+                    creator_address,
+                    &collection_name,
+                    &token_name,
+                    &init_txn_factory,
+                    rng,
+                    SELLER_TOKEN_BALANCE,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        info!("Creating {} marketplace sellers", sellers.len());
+        execute_across_source_accounts(txn_executor, &create_txns, source_accounts.len())
+            .await
+            .unwrap();
+        // per account limit is 100
+        for chunk in token_txns.chunks(100) {
+            txn_executor.execute_transactions(chunk).await.unwrap();
+        }
+        info!("Done creating {} marketplace sellers", sellers.len());
+
+        Self {
+            txn_factory,
+            creator_address,
+            sellers,
+            collection_name,
+            token_name,
+            listings_per_round,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for MarketplaceGeneratorCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(MarketplaceGenerator::new(
+            ResumableRng::from_entropy(),
+            self.txn_factory.clone(),
+            self.sellers.pop().unwrap(),
+            self.creator_address,
+            self.collection_name.clone(),
+            self.token_name.clone(),
+            self.listings_per_round,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{
+        bcs,
+        transaction_builder::TransactionFactory,
+        types::{chain_id::ChainId, transaction::TransactionPayload},
+    };
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_no_buy_before_list() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let seller = LocalAccount::generate(&mut rng);
+        let seller_address = seller.address();
+        let mut buyers = (0..10)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let mut generator = MarketplaceGenerator::new(
+            rng,
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            seller,
+            AccountAddress::ONE,
+            b"collection".to_vec(),
+            b"token".to_vec(),
+            3,
+        );
+
+        // buyers listed for in the previous rounds
+        let mut listed = HashSet::new();
+        let (mut num_bought, mut num_cancelled) = (0, 0);
+        for _ in 0..20 {
+            let mut new_listings = HashSet::new();
+            for txn in generator.generate_transactions(buyers.iter_mut().collect(), 1) {
+                let entry_function = match txn.payload() {
+                    TransactionPayload::EntryFunction(entry_function) => entry_function.clone(),
+                    _ => panic!("Marketplace must only call entry functions"),
+                };
+                match entry_function.function().as_str() {
+                    "offer_script" => {
+                        assert_eq!(txn.sender(), seller_address);
+                        let buyer: AccountAddress =
+                            bcs::from_bytes(&entry_function.args()[0]).unwrap();
+                        assert!(!listed.contains(&buyer));
+                        assert!(new_listings.insert(buyer));
+                    },
+                    "claim_script" => {
+                        assert!(listed.remove(&txn.sender()));
+                        num_bought += 1;
+                    },
+                    "cancel_offer_script" => {
+                        assert_eq!(txn.sender(), seller_address);
+                        let buyer: AccountAddress =
+                            bcs::from_bytes(&entry_function.args()[0]).unwrap();
+                        assert!(listed.remove(&buyer));
+                        num_cancelled += 1;
+                    },
+                    function => panic!("Unexpected function {}", function),
+                }
+            }
+            assert!(new_listings.len() <= 3);
+            listed.extend(new_listings);
+        }
+        assert!(num_bought > 0);
+        assert!(num_cancelled > 0);
+    }
+}
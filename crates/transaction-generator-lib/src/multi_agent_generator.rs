@@ -0,0 +1,197 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    many_signers::clamp_num_signers,
+    publishing::{
+        module_simple::EntryPoints,
+        publish_util::{Package, PackageHandler},
+    },
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    TransactionExecutor, TransactionGenerator, TransactionGeneratorCreator,
+};
+use aptos_logger::{info, sample, sample::SampleRate, warn};
+use aptos_sdk::types::{transaction::SignedTransaction, LocalAccount};
+use async_trait::async_trait;
+use rand::SeedableRng;
+use std::{sync::Arc, time::Duration};
+
+/// Generates multi-agent transactions calling the entry point of a published package.
+/// Passed accounts are split into groups of a primary account (the sender) and
+/// `num_secondary_signers` secondary signers, and every transaction is signed by the whole
+/// group. The entry point is expected to take one signer per member of the group
+/// (see `EntryPoints::num_secondary_signers`).
+pub struct MultiAgentGenerator {
+    rng: ResumableRng,
+    txn_factory: SharedTransactionFactory,
+    package: Arc<Package>,
+    entry_point: EntryPoints,
+    num_secondary_signers: usize,
+}
+
+impl MultiAgentGenerator {
+    pub fn new(
+        rng: ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        package: Arc<Package>,
+        entry_point: EntryPoints,
+        num_secondary_signers: usize,
+    ) -> Self {
+        Self {
+            rng,
+            txn_factory,
+            package,
+            entry_point,
+            num_secondary_signers,
+        }
+    }
+}
+
+impl TransactionGenerator for MultiAgentGenerator {
+    fn generate_transactions(
+        &mut self,
+        mut accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        // Accounts left over after the last full group don't send anything
+        let group_size = 1 + self.num_secondary_signers;
+        if accounts.len() % group_size != 0 {
+            sample!(
+                SampleRate::Duration(Duration::from_secs(60)),
+                warn!(
+                    "{} accounts can't be grouped into a sender and {} secondary signers, \
+                    {} are unused",
+                    accounts.len(),
+                    self.num_secondary_signers,
+                    accounts.len() % group_size
+                )
+            );
+        }
+
+        let mut requests =
+            Vec::with_capacity(accounts.len() / group_size * transactions_per_account);
+        for group in accounts.chunks_exact_mut(group_size) {
+            let (primary, secondary_signers) = group.split_first_mut().unwrap();
+            let secondary_signers = secondary_signers
+                .iter()
+                .map(|signer| &**signer)
+                .collect::<Vec<_>>();
+            for _ in 0..transactions_per_account {
+                let payload = self.entry_point.create_payload(
+                    self.package.module_id(),
+                    Some(&mut self.rng),
+                    None,
+                );
+                requests.push(primary.sign_multi_agent_with_transaction_builder(
+                    secondary_signers.clone(),
+                    self.txn_factory.payload(payload, &mut self.rng),
+                ));
+            }
+        }
+        requests
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
+}
+
+pub struct MultiAgentCreator {
+    txn_factory: SharedTransactionFactory,
+    package: Arc<Package>,
+    entry_point: EntryPoints,
+    num_secondary_signers: usize,
+}
+
+impl MultiAgentCreator {
+    pub async fn new(
+        rng: &mut ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        init_txn_factory: SharedTransactionFactory,
+        publisher: &mut LocalAccount,
+        txn_executor: &dyn TransactionExecutor,
+        entry_point: EntryPoints,
+        num_secondary_signers: usize,
+    ) -> Self {
+        let package = PackageHandler::new().pick_package(rng, publisher);
+        let txn = package.publish_transaction(rng, publisher, &init_txn_factory);
+        info!("Publishing package for multi-agent transactions");
+        txn_executor.execute_transactions(&[txn]).await.unwrap();
+        info!("Done publishing package for multi-agent transactions");
+
+        Self {
+            txn_factory,
+            package: Arc::new(package),
+            entry_point,
+            num_secondary_signers: clamp_num_signers(num_secondary_signers),
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for MultiAgentCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(MultiAgentGenerator::new(
+            ResumableRng::from_entropy(),
+            self.txn_factory.clone(),
+            self.package.clone(),
+            self.entry_point,
+            self.num_secondary_signers,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{transaction_builder::TransactionFactory, types::chain_id::ChainId};
+
+    fn generator(num_secondary_signers: usize) -> MultiAgentGenerator {
+        MultiAgentGenerator::new(
+            ResumableRng::seed_from_u64(1),
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            Arc::new(Package::simple()),
+            EntryPoints::NopTwoSigners,
+            num_secondary_signers,
+        )
+    }
+
+    #[test]
+    fn test_signed_by_sender_and_secondary_signers() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut accounts = (0..7)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let addresses = accounts
+            .iter()
+            .map(|account| account.address())
+            .collect::<Vec<_>>();
+
+        // Three pairs, the last account is left over
+        let txns = generator(1).generate_transactions(accounts.iter_mut().collect(), 2);
+        assert_eq!(txns.len(), 6);
+        for (i, txn) in txns.iter().enumerate() {
+            let group = &addresses[i / 2 * 2..i / 2 * 2 + 2];
+            assert_eq!(txn.sender(), group[0]);
+            assert_eq!(txn.authenticator().secondary_signer_addreses(), &group[1..]);
+            assert!(txn.clone().check_signature().is_ok());
+            assert_eq!(
+                txn.payload()
+                    .clone()
+                    .into_entry_function()
+                    .function()
+                    .as_str(),
+                "nop_2_signers"
+            );
+        }
+
+        // Not enough accounts for a single group
+        let txns = generator(8).generate_transactions(accounts.iter_mut().collect(), 2);
+        assert!(txns.is_empty());
+    }
+}
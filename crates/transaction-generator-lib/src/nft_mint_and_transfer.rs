@@ -2,11 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::TransactionExecutor;
-use crate::{TransactionGenerator, TransactionGeneratorCreator};
+use crate::{
+    resumable_rng::ResumableRng, shared_txn_factory::SharedTransactionFactory,
+    TransactionGenerator, TransactionGeneratorCreator,
+};
 use aptos_crypto::ed25519::Ed25519PublicKey;
 use aptos_logger::info;
 use aptos_sdk::{
-    transaction_builder::{aptos_stdlib, aptos_stdlib::aptos_token_stdlib, TransactionFactory},
+    transaction_builder::{aptos_stdlib, aptos_stdlib::aptos_token_stdlib},
     types::{
         account_address::AccountAddress,
         transaction::{
@@ -17,35 +20,59 @@ use aptos_sdk::{
     },
 };
 use async_trait::async_trait;
-use rand::{rngs::StdRng, thread_rng, SeedableRng};
+use rand::{thread_rng, Rng};
 use std::collections::HashMap;
 
-const INITIAL_NFT_BALANCE: u64 = 50_000;
+/// Supply of the token of the collection, unless configured otherwise.
+pub const DEFAULT_NFT_COLLECTION_SIZE: u64 = 100_000_000_000;
 
+/// Mints (transfers out of the distribution account) tokens to the accounts, and transfers
+/// them back one at a time, with mints making up `mint_ratio` of the transactions. A mint
+/// gives an account enough tokens for the transfers that follow it, and accounts that don't
+/// hold a token get a mint regardless. The distribution account holds its share of the
+/// collection, and only mints as many tokens as are back in it.
 pub struct NFTMintAndTransfer {
-    txn_factory: TransactionFactory,
+    txn_factory: SharedTransactionFactory,
     creator_address: AccountAddress,
     distribution_account: LocalAccount,
     collection_name: Vec<u8>,
     token_name: Vec<u8>,
-    account_funded: HashMap<AccountAddress, bool>,
+    mint_ratio: f32,
+    // tokens given by a single mint
+    mint_amount: u64,
+    // tokens left in the distribution account, to mint from
+    distribution_balance: u64,
+    // accumulated share of mints, a mint is due whenever it reaches 1
+    mint_credit: f32,
+    tokens_held: HashMap<AccountAddress, u64>,
 }
 
 impl NFTMintAndTransfer {
-    pub async fn new(
-        txn_factory: TransactionFactory,
+    pub fn new(
+        txn_factory: SharedTransactionFactory,
         creator_address: AccountAddress,
         distribution_account: LocalAccount,
         collection_name: Vec<u8>,
         token_name: Vec<u8>,
+        distribution_balance: u64,
+        mint_ratio: f32,
     ) -> Self {
+        assert!(
+            mint_ratio > 0.0 && mint_ratio <= 1.0,
+            "Invalid mint ratio {}",
+            mint_ratio
+        );
         Self {
             txn_factory,
             distribution_account,
             creator_address,
             collection_name,
             token_name,
-            account_funded: Default::default(),
+            mint_ratio,
+            mint_amount: ((1.0 - mint_ratio) / mint_ratio).ceil().max(1.0) as u64,
+            distribution_balance,
+            mint_credit: 0.0,
+            tokens_held: HashMap::new(),
         }
     }
 }
@@ -58,58 +85,172 @@ impl TransactionGenerator for NFTMintAndTransfer {
     ) -> Vec<SignedTransaction> {
         let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
         for account in accounts {
-            let account_funded = self
-                .account_funded
-                .get(&account.address())
-                .cloned()
-                .unwrap_or(false);
-            for i in 0..transactions_per_account {
+            for _ in 0..transactions_per_account {
+                self.mint_credit += self.mint_ratio;
+                let held = self.tokens_held.entry(account.address()).or_default();
+                let mint = match (self.distribution_balance > 0, *held > 0) {
+                    (false, false) => break,
+                    (true, false) => true,
+                    (false, true) => false,
+                    (true, true) => self.mint_credit >= 1.0,
+                };
                 requests.push(
-                    if account_funded {
+                    if mint {
+                        let amount = self.mint_amount.min(self.distribution_balance);
+                        self.mint_credit -= 1.0;
+                        self.distribution_balance -= amount;
+                        *held += amount;
                         create_nft_transfer_request(
+                            &mut self.distribution_account,
                             account,
-                            &self.distribution_account,
                             self.creator_address,
                             &self.collection_name,
                             &self.token_name,
                             &self.txn_factory,
-                            if i != transactions_per_account - 1 {
-                                1
-                            } else {
-                                INITIAL_NFT_BALANCE + 1 - transactions_per_account as u64
-                            },
+                            &mut thread_rng(),
+                            amount,
                         )
                     } else {
+                        self.distribution_balance += 1;
+                        *held -= 1;
                         create_nft_transfer_request(
-                            &mut self.distribution_account,
                             account,
+                            &self.distribution_account,
                             self.creator_address,
                             &self.collection_name,
                             &self.token_name,
                             &self.txn_factory,
-                            if i != transactions_per_account - 1 {
-                                1
-                            } else {
-                                INITIAL_NFT_BALANCE + 1 - transactions_per_account as u64
-                            },
+                            &mut thread_rng(),
+                            1,
                         )
                     },
                 );
             }
-            self.account_funded
-                .insert(account.address(), !account_funded);
         }
         requests
     }
 }
 
-pub async fn initialize_nft_collection(
+/// Models an NFT launch: a spike of `mint_spike` mints (transfers out of the distribution
+/// account) to the accounts, followed by `trade_phase` transfers between the accounts
+/// that got a token, after which the next drop starts.
+pub struct NFTDrop {
+    txn_factory: SharedTransactionFactory,
+    creator_address: AccountAddress,
+    distribution_account: LocalAccount,
+    collection_name: Vec<u8>,
+    token_name: Vec<u8>,
+    mint_spike: usize,
+    trade_phase: usize,
+    // number of transactions generated in the current drop
+    generated: usize,
+    tokens_held: HashMap<AccountAddress, u64>,
+}
+
+impl NFTDrop {
+    pub fn new(
+        txn_factory: SharedTransactionFactory,
+        creator_address: AccountAddress,
+        distribution_account: LocalAccount,
+        collection_name: Vec<u8>,
+        token_name: Vec<u8>,
+        mint_spike: usize,
+        trade_phase: usize,
+    ) -> Self {
+        Self {
+            txn_factory,
+            creator_address,
+            distribution_account,
+            collection_name,
+            token_name,
+            mint_spike,
+            trade_phase,
+            generated: 0,
+            tokens_held: HashMap::new(),
+        }
+    }
+
+    fn transfer(
+        &mut self,
+        sender: &mut LocalAccount,
+        receiver: &LocalAccount,
+    ) -> SignedTransaction {
+        *self.tokens_held.entry(sender.address()).or_default() -= 1;
+        *self.tokens_held.entry(receiver.address()).or_default() += 1;
+        create_nft_transfer_request(
+            sender,
+            receiver,
+            self.creator_address,
+            &self.collection_name,
+            &self.token_name,
+            &self.txn_factory,
+            &mut thread_rng(),
+            1,
+        )
+    }
+}
+
+impl TransactionGenerator for NFTDrop {
+    fn generate_transactions(
+        &mut self,
+        mut accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
+        let num_accounts = accounts.len();
+        for i in 0..num_accounts {
+            for _ in 0..transactions_per_account {
+                if self.generated == self.mint_spike + self.trade_phase {
+                    self.generated = 0;
+                }
+
+                if self.generated < self.mint_spike {
+                    *self.tokens_held.entry(accounts[i].address()).or_default() += 1;
+                    requests.push(create_nft_transfer_request(
+                        &mut self.distribution_account,
+                        &*accounts[i],
+                        self.creator_address,
+                        &self.collection_name,
+                        &self.token_name,
+                        &self.txn_factory,
+                        &mut thread_rng(),
+                        1,
+                    ));
+                } else {
+                    // Trade with the next account in the batch, if this one holds a token
+                    let holds_token = self
+                        .tokens_held
+                        .get(&accounts[i].address())
+                        .map_or(false, |held| *held > 0);
+                    if num_accounts < 2 || !holds_token {
+                        break;
+                    }
+                    let j = (i + 1) % num_accounts;
+                    let (sender, receiver) = if i < j {
+                        let (left, right) = accounts.split_at_mut(j);
+                        (&mut *left[i], &*right[0])
+                    } else {
+                        let (left, right) = accounts.split_at_mut(i);
+                        (&mut *right[0], &*left[j])
+                    };
+                    requests.push(self.transfer(sender, receiver));
+                }
+                self.generated += 1;
+            }
+        }
+        requests
+    }
+}
+
+pub async fn initialize_nft_collection<R: Rng>(
     txn_executor: &dyn TransactionExecutor,
     root_account: &mut LocalAccount,
     creator_account: &mut LocalAccount,
-    txn_factory: &TransactionFactory,
+    txn_factory: &SharedTransactionFactory,
+    rng: &mut R,
     collection_name: &[u8],
     token_name: &[u8],
+    supply: u64,
 ) {
     // Create and mint the owner account first
     let create_account_txn = create_and_fund_account_request(
@@ -117,6 +258,7 @@ pub async fn initialize_nft_collection(
         10_000_000,
         creator_account.public_key(),
         txn_factory,
+        rng,
     );
 
     txn_executor
@@ -125,15 +267,21 @@ pub async fn initialize_nft_collection(
         .unwrap();
 
     let collection_txn =
-        create_nft_collection_request(creator_account, collection_name, txn_factory);
+        create_nft_collection_request(creator_account, collection_name, txn_factory, rng);
 
     txn_executor
         .execute_transactions(&[collection_txn])
         .await
         .unwrap();
 
-    let token_txn =
-        create_nft_token_request(creator_account, collection_name, token_name, txn_factory);
+    let token_txn = create_nft_token_request(
+        creator_account,
+        collection_name,
+        token_name,
+        supply,
+        txn_factory,
+        rng,
+    );
 
     txn_executor
         .execute_transactions(&[token_txn])
@@ -143,10 +291,11 @@ pub async fn initialize_nft_collection(
     info!("initialize_nft_collection complete");
 }
 
-pub fn create_nft_collection_request(
+pub fn create_nft_collection_request<R: Rng>(
     creation_account: &mut LocalAccount,
     collection_name: &[u8],
-    txn_factory: &TransactionFactory,
+    txn_factory: &SharedTransactionFactory,
+    rng: &mut R,
 ) -> SignedTransaction {
     creation_account.sign_with_transaction_builder(txn_factory.payload(
         aptos_token_stdlib::token_create_collection_script(
@@ -156,21 +305,24 @@ pub fn create_nft_collection_request(
             u64::MAX,
             vec![false, false, false],
         ),
+        rng,
     ))
 }
 
-pub fn create_nft_token_request(
+pub fn create_nft_token_request<R: Rng>(
     creation_account: &mut LocalAccount,
     collection_name: &[u8],
     token_name: &[u8],
-    txn_factory: &TransactionFactory,
+    supply: u64,
+    txn_factory: &SharedTransactionFactory,
+    rng: &mut R,
 ) -> SignedTransaction {
     creation_account.sign_with_transaction_builder(txn_factory.payload(
         aptos_token_stdlib::token_create_token_script(
             collection_name.to_vec(),
             token_name.to_vec(),
             "collection description".to_owned().into_bytes(),
-            100_000_000_000,
+            supply,
             u64::MAX,
             "uri".to_owned().into_bytes(),
             creation_account.address(),
@@ -181,48 +333,66 @@ pub fn create_nft_token_request(
             vec![Vec::new()],
             vec![Vec::new()],
         ),
+        rng,
     ))
 }
 
-pub fn create_nft_transfer_request(
+pub fn create_nft_transfer_request<R: Rng>(
     sender: &mut LocalAccount,
     receiver: &LocalAccount,
     creation_address: AccountAddress,
     collection_name: &[u8],
     token_name: &[u8],
-    txn_factory: &TransactionFactory,
+    txn_factory: &SharedTransactionFactory,
+    rng: &mut R,
     amount: u64,
 ) -> SignedTransaction {
     sender.sign_multi_agent_with_transaction_builder(
         vec![receiver],
-        txn_factory.payload(aptos_token_stdlib::token_direct_transfer_script(
-            creation_address,
-            collection_name.to_vec(),
-            token_name.to_vec(),
-            0,
-            amount,
-        )),
+        txn_factory.payload(
+            aptos_token_stdlib::token_direct_transfer_script(
+                creation_address,
+                collection_name.to_vec(),
+                token_name.to_vec(),
+                0,
+                amount,
+            ),
+            rng,
+        ),
     )
 }
 
 pub struct NFTMintAndTransferGeneratorCreator {
-    txn_factory: TransactionFactory,
+    txn_factory: SharedTransactionFactory,
     creator_address: AccountAddress,
     distribution_accounts: Vec<LocalAccount>,
     collection_name: Vec<u8>,
     token_name: Vec<u8>,
+    // share of the collection given to each distribution account
+    distribution_balance: u64,
+    mint_ratio: f32,
+    // (mint_spike, trade_phase), if generating NFT drops
+    drop: Option<(usize, usize)>,
 }
 
 impl NFTMintAndTransferGeneratorCreator {
     pub async fn new(
-        txn_factory: TransactionFactory,
-        init_txn_factory: TransactionFactory,
+        rng: &mut ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        init_txn_factory: SharedTransactionFactory,
         root_account: &mut LocalAccount,
         txn_executor: &dyn TransactionExecutor,
         num_workers: usize,
+        collection_size: u64,
     ) -> Self {
-        let mut rng = StdRng::from_entropy();
-        let mut creator_account = LocalAccount::generate(&mut rng);
+        let distribution_balance = collection_size / num_workers as u64;
+        assert!(
+            distribution_balance > 0,
+            "Collection of {} NFTs can't be split across {} workers",
+            collection_size,
+            num_workers
+        );
+        let mut creator_account = LocalAccount::generate(rng);
         let creator_address = creator_account.address();
         let collection_name = "collection name".to_owned().into_bytes();
         let token_name = "token name".to_owned().into_bytes();
@@ -231,8 +401,10 @@ impl NFTMintAndTransferGeneratorCreator {
             root_account,
             &mut creator_account,
             &init_txn_factory,
+            rng,
             &collection_name,
             &token_name,
+            collection_size,
         )
         .await;
 
@@ -240,7 +412,7 @@ impl NFTMintAndTransferGeneratorCreator {
         let mut txns = Vec::new();
 
         for _ in 0..num_workers {
-            let distribution_account = LocalAccount::generate(&mut thread_rng());
+            let distribution_account = LocalAccount::generate(rng);
             txns.push(create_nft_transfer_request(
                 &mut creator_account,
                 &distribution_account,
@@ -248,7 +420,8 @@ impl NFTMintAndTransferGeneratorCreator {
                 &collection_name,
                 &token_name,
                 &init_txn_factory,
-                1_000_000_000,
+                rng,
+                distribution_balance,
             ));
             distribution_accounts.push(distribution_account);
         }
@@ -266,35 +439,149 @@ impl NFTMintAndTransferGeneratorCreator {
             distribution_accounts,
             collection_name,
             token_name,
+            distribution_balance,
+            mint_ratio: 0.5,
+            drop: None,
         }
     }
+
+    /// Share of mints among the generated transactions, the rest being transfers back.
+    pub fn with_mint_ratio(mut self, mint_ratio: f32) -> Self {
+        self.mint_ratio = mint_ratio;
+        self
+    }
+
+    /// Generates NFT drops (see `NFTDrop`) on top of the same collection setup,
+    /// instead of mints and transfers back and forth with the distribution account.
+    pub fn with_drop(mut self, mint_spike: usize, trade_phase: usize) -> Self {
+        self.drop = Some((mint_spike, trade_phase));
+        self
+    }
 }
 
 #[async_trait]
 impl TransactionGeneratorCreator for NFTMintAndTransferGeneratorCreator {
     async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
-        Box::new(
-            NFTMintAndTransfer::new(
+        if let Some((mint_spike, trade_phase)) = self.drop {
+            return Box::new(NFTDrop::new(
                 self.txn_factory.clone(),
                 self.creator_address,
                 self.distribution_accounts.pop().unwrap(),
                 self.collection_name.clone(),
                 self.token_name.clone(),
-            )
-            .await,
-        )
+                mint_spike,
+                trade_phase,
+            ));
+        }
+        Box::new(NFTMintAndTransfer::new(
+            self.txn_factory.clone(),
+            self.creator_address,
+            self.distribution_accounts.pop().unwrap(),
+            self.collection_name.clone(),
+            self.token_name.clone(),
+            self.distribution_balance,
+            self.mint_ratio,
+        ))
     }
 }
 
-fn create_and_fund_account_request(
+pub fn create_and_fund_account_request<R: Rng>(
     creation_account: &mut LocalAccount,
     amount: u64,
     pubkey: &Ed25519PublicKey,
-    txn_factory: &TransactionFactory,
+    txn_factory: &SharedTransactionFactory,
+    rng: &mut R,
 ) -> SignedTransaction {
     let preimage = AuthenticationKeyPreimage::ed25519(pubkey);
     let auth_key = AuthenticationKey::from_preimage(&preimage);
     creation_account.sign_with_transaction_builder(txn_factory.payload(
         aptos_stdlib::aptos_account_transfer(auth_key.derived_address(), amount),
+        rng,
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{bcs, transaction_builder::TransactionFactory, types::chain_id::ChainId};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_mint_ratio_and_collection_size() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let distribution_account = LocalAccount::generate(&mut rng);
+        let distribution_address = distribution_account.address();
+        let new_generator = |distribution_account, distribution_balance| {
+            NFTMintAndTransfer::new(
+                SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+                AccountAddress::ONE,
+                distribution_account,
+                b"collection".to_vec(),
+                b"token".to_vec(),
+                distribution_balance,
+                0.25,
+            )
+        };
+        let mut accounts = (0..4)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let is_mint = |txn: &SignedTransaction| txn.sender() == distribution_address;
+
+        // Every account gets a first mint, after which mints make up a quarter of the rest
+        let mut generator = new_generator(distribution_account, 1000);
+        let txns = (0..10)
+            .flat_map(|_| generator.generate_transactions(accounts.iter_mut().collect(), 5))
+            .collect::<Vec<_>>();
+        assert_eq!(txns.len(), 200);
+        let num_mints = txns.iter().filter(|txn| is_mint(txn)).count();
+        assert!((48..=54).contains(&num_mints), "{} mints", num_mints);
+
+        // Accounts never hold more tokens than the distribution account's share
+        let distribution_account = LocalAccount::generate(&mut rng);
+        let distribution_address = distribution_account.address();
+        let mut generator = new_generator(distribution_account, 5);
+        let mut outstanding = 0;
+        for txn in generator.generate_transactions(accounts.iter_mut().collect(), 5) {
+            let entry_function = txn.payload().clone().into_entry_function();
+            let amount: u64 = bcs::from_bytes(&entry_function.args()[4]).unwrap();
+            if txn.sender() == distribution_address {
+                outstanding += amount;
+            } else {
+                outstanding -= amount;
+            }
+            assert!(outstanding <= 5);
+        }
+    }
+
+    #[test]
+    fn test_drop_front_loads_mints() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let distribution_account = LocalAccount::generate(&mut rng);
+        let distribution_address = distribution_account.address();
+        let mut generator = NFTDrop::new(
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            AccountAddress::ONE,
+            distribution_account,
+            b"collection".to_vec(),
+            b"token".to_vec(),
+            10,
+            10,
+        );
+        let mut accounts = (0..5)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+
+        let is_mint = |txn: &SignedTransaction| txn.sender() == distribution_address;
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 4);
+        assert_eq!(txns.iter().filter(|txn| is_mint(txn)).count(), 10);
+        let first_trade = txns.iter().position(|txn| !is_mint(txn)).unwrap();
+        assert_eq!(first_trade, 10);
+        assert!(txns[first_trade..].iter().all(|txn| !is_mint(txn)));
+
+        // Once the trade phase is over, the next drop starts with a mint spike
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 4);
+        let first_mint = txns.iter().position(is_mint).unwrap();
+        assert!(txns[..first_mint].iter().all(|txn| !is_mint(txn)));
+        assert!(txns[first_mint..first_mint + 10].iter().all(is_mint));
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{resumable_rng::RngSnapshot, TransactionGenerator, TransactionGeneratorCreator};
+use aptos_sdk::types::{
+    transaction::{RawTransaction, SignedTransaction},
+    LocalAccount,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Callback invoked with every transaction generated, before it is submitted, e.g. to compute
+/// histograms of payload types, gas or sizes over a run.
+pub type TransactionObserver = Arc<dyn Fn(&SignedTransaction) + Send + Sync>;
+
+/// Wrapper passing every transaction generated by the inner generator to the observer.
+/// Raw transactions aren't observed, as they are never submitted.
+pub struct ObservedGenerator {
+    generator: Box<dyn TransactionGenerator>,
+    observer: TransactionObserver,
+}
+
+impl TransactionGenerator for ObservedGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let txns = self
+            .generator
+            .generate_transactions(accounts, transactions_per_account);
+        for txn in &txns {
+            (self.observer)(txn);
+        }
+        txns
+    }
+
+    fn generate_raw_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<RawTransaction> {
+        self.generator
+            .generate_raw_transactions(accounts, transactions_per_account)
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        self.generator.rng_snapshot()
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.generator.restore_rng(snapshot)
+    }
+}
+
+pub struct ObservedGeneratorCreator {
+    creator: Box<dyn TransactionGeneratorCreator>,
+    observer: TransactionObserver,
+}
+
+impl ObservedGeneratorCreator {
+    pub fn new(
+        creator: Box<dyn TransactionGeneratorCreator>,
+        observer: TransactionObserver,
+    ) -> Self {
+        Self { creator, observer }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for ObservedGeneratorCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(ObservedGenerator {
+            generator: self.creator.create_transaction_generator().await,
+            observer: self.observer.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        p2p_transaction_generator::P2PTransactionGeneratorCreator, resumable_rng::ResumableRng,
+        shared_txn_factory::SharedTransactionFactory,
+    };
+    use aptos_infallible::{Mutex, RwLock};
+    use aptos_sdk::{transaction_builder::TransactionFactory, types::chain_id::ChainId};
+    use rand::SeedableRng;
+
+    #[tokio::test]
+    async fn test_observer_sees_all_transactions() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut accounts = (0..4)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let addresses = accounts.iter().map(|account| account.address()).collect();
+        let observed = Arc::new(Mutex::new(Vec::new()));
+        let observed_clone = observed.clone();
+        let mut creator = ObservedGeneratorCreator::new(
+            Box::new(P2PTransactionGeneratorCreator::new(
+                SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+                (1, 1),
+                Arc::new(RwLock::new(addresses)),
+                0,
+                None,
+            )),
+            Arc::new(move |txn: &SignedTransaction| {
+                observed_clone
+                    .lock()
+                    .push((txn.sender(), txn.sequence_number()))
+            }),
+        );
+
+        let mut generator = creator.create_transaction_generator().await;
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 3);
+        assert_eq!(txns.len(), 12);
+        assert_eq!(
+            *observed.lock(),
+            txns.iter()
+                .map(|txn| (txn.sender(), txn.sequence_number()))
+                .collect::<Vec<_>>()
+        );
+    }
+}
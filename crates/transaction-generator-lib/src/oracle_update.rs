@@ -0,0 +1,195 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{
+    publishing::{module_simple::EntryPoints, publish_util::Package},
+    TransactionExecutor,
+};
+use crate::{
+    nft_mint_and_transfer::create_and_fund_account_request,
+    publishing::publish_util::PackageHandler,
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    TransactionGenerator, TransactionGeneratorCreator,
+};
+use aptos_logger::info;
+use aptos_sdk::types::{transaction::SignedTransaction, LocalAccount};
+use async_trait::async_trait;
+use rand::{Rng, SeedableRng};
+use std::sync::Arc;
+
+// per account limit is 100, and all updates of a generator share the sender
+const MAX_UPDATES_PER_ROUND: usize = 100;
+
+/// Updates the prices of `feeds` price feeds, round-robin, all from a single oracle account.
+/// The accounts handed to the generator only determine how many updates are generated.
+pub struct OracleUpdateGenerator {
+    rng: ResumableRng,
+    txn_factory: SharedTransactionFactory,
+    package: Arc<Package>,
+    oracle: LocalAccount,
+    feeds: usize,
+    next_feed: usize,
+}
+
+impl OracleUpdateGenerator {
+    pub fn new(
+        rng: ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        package: Arc<Package>,
+        oracle: LocalAccount,
+        feeds: usize,
+    ) -> Self {
+        assert!(feeds > 0, "Oracle needs at least one price feed");
+        Self {
+            rng,
+            txn_factory,
+            package,
+            oracle,
+            feeds,
+            next_feed: 0,
+        }
+    }
+}
+
+impl TransactionGenerator for OracleUpdateGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let num_updates = (accounts.len() * transactions_per_account).min(MAX_UPDATES_PER_ROUND);
+        let mut requests = Vec::with_capacity(num_updates);
+        for _ in 0..num_updates {
+            let feed = self.next_feed as u64;
+            self.next_feed = (self.next_feed + 1) % self.feeds;
+            let price = self.rng.gen_range(1u64, 1_000_000u64);
+            // Signing bumps the sequence number of the oracle, so updates stay in order
+            requests.push(self.package.use_specific_transaction(
+                EntryPoints::UpdatePrice { feed, price },
+                &mut self.oracle,
+                &self.txn_factory,
+                Some(&mut self.rng),
+                None,
+            ));
+        }
+        requests
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
+}
+
+pub struct OracleUpdateCreator {
+    txn_factory: SharedTransactionFactory,
+    package: Arc<Package>,
+    oracles: Vec<LocalAccount>,
+    feeds: usize,
+}
+
+impl OracleUpdateCreator {
+    pub async fn new(
+        rng: &mut ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        init_txn_factory: SharedTransactionFactory,
+        root_account: &mut LocalAccount,
+        txn_executor: &dyn TransactionExecutor,
+        num_workers: usize,
+        feeds: usize,
+    ) -> Self {
+        let mut package_handler = PackageHandler::new();
+        let package = package_handler.pick_package(rng, root_account);
+        let txn = package.publish_transaction(rng, root_account, &init_txn_factory);
+        info!("Publishing oracle package");
+        txn_executor.execute_transactions(&[txn]).await.unwrap();
+        info!("Done publishing oracle package");
+
+        // Every worker gets its own oracle, as the sequence numbers of an oracle
+        // can only be tracked by a single generator
+        let balance = txn_executor
+            .get_account_balance(root_account.address())
+            .await
+            .unwrap();
+        let oracle_balance = balance / (2 * num_workers as u64);
+        let oracles = (0..num_workers)
+            .map(|_| LocalAccount::generate(rng))
+            .collect::<Vec<_>>();
+        let txns = oracles
+            .iter()
+            .map(|oracle| {
+                create_and_fund_account_request(
+                    root_account,
+                    oracle_balance,
+                    oracle.public_key(),
+                    &init_txn_factory,
+                    rng,
+                )
+            })
+            .collect::<Vec<_>>();
+        info!("Creating {} oracle accounts", txns.len());
+        for chunk in txns.chunks(100) {
+            txn_executor.execute_transactions(chunk).await.unwrap();
+        }
+        info!("Done creating {} oracle accounts", txns.len());
+
+        Self {
+            txn_factory,
+            package: Arc::new(package),
+            oracles,
+            feeds,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for OracleUpdateCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(OracleUpdateGenerator::new(
+            ResumableRng::from_entropy(),
+            self.txn_factory.clone(),
+            self.package.clone(),
+            self.oracles.pop().unwrap(),
+            self.feeds,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{bcs, transaction_builder::TransactionFactory, types::chain_id::ChainId};
+
+    #[test]
+    fn test_updates_spread_across_feeds() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let oracle = LocalAccount::generate(&mut rng);
+        let oracle_address = oracle.address();
+        let mut generator = OracleUpdateGenerator::new(
+            ResumableRng::seed_from_u64(1),
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            Arc::new(Package::simple()),
+            oracle,
+            3,
+        );
+        let mut accounts = (0..5)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+
+        let mut txns = generator.generate_transactions(accounts.iter_mut().collect(), 2);
+        txns.extend(generator.generate_transactions(accounts.iter_mut().collect(), 2));
+        assert_eq!(txns.len(), 20);
+        for (i, txn) in txns.iter().enumerate() {
+            assert_eq!(txn.sender(), oracle_address);
+            assert_eq!(txn.sequence_number(), i as u64);
+            let entry_function = txn.payload().clone().into_entry_function();
+            assert_eq!(entry_function.function().as_str(), "update_price");
+            let feed: u64 = bcs::from_bytes(&entry_function.args()[0]).unwrap();
+            assert_eq!(feed, i as u64 % 3);
+        }
+    }
+}
@@ -1,43 +1,123 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
-use crate::{TransactionGenerator, TransactionGeneratorCreator};
+use crate::{
+    build_raw_transaction,
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    TransactionGenerator, TransactionGeneratorCreator, TransactionGeneratorMetrics,
+};
 use aptos_infallible::RwLock;
+use aptos_logger::{sample, sample::SampleRate, warn};
 use aptos_sdk::{
     move_types::account_address::AccountAddress,
-    transaction_builder::{aptos_stdlib, TransactionFactory},
-    types::{chain_id::ChainId, transaction::SignedTransaction, LocalAccount},
+    transaction_builder::aptos_stdlib,
+    types::{
+        chain_id::ChainId,
+        transaction::{RawTransaction, SignedTransaction},
+        LocalAccount,
+    },
 };
 use async_trait::async_trait;
 use rand::{
-    distributions::{Distribution, Standard},
+    distributions::{Distribution, Standard, Uniform},
     prelude::SliceRandom,
-    rngs::StdRng,
     Rng, RngCore, SeedableRng,
 };
-use std::{cmp::max, sync::Arc};
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::max,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 pub struct P2PTransactionGenerator {
-    rng: StdRng,
-    send_amount: u64,
-    txn_factory: TransactionFactory,
+    rng: ResumableRng,
+    // inclusive, an amount is picked for every transaction
+    amount_range: (u64, u64),
+    txn_factory: SharedTransactionFactory,
     all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
     invalid_transaction_ratio: usize,
+    metrics: Option<Arc<dyn TransactionGeneratorMetrics>>,
+    // every account sends to itself, instead of to accounts picked from all_addresses
+    self_transfer: bool,
+    // weighted kinds of the invalid transactions, all of the original ones if empty
+    invalid_kinds: Vec<(InvalidTransactionType, usize)>,
 }
 
 impl P2PTransactionGenerator {
     pub fn new(
-        rng: StdRng,
-        send_amount: u64,
-        txn_factory: TransactionFactory,
+        rng: ResumableRng,
+        amount_range: (u64, u64),
+        txn_factory: SharedTransactionFactory,
         all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
         invalid_transaction_ratio: usize,
     ) -> Self {
+        assert!(
+            amount_range.0 <= amount_range.1,
+            "Invalid amount range {:?}",
+            amount_range
+        );
         Self {
             rng,
-            send_amount,
+            amount_range,
             txn_factory,
             all_addresses,
             invalid_transaction_ratio,
+            metrics: None,
+            self_transfer: false,
+            invalid_kinds: Vec::new(),
+        }
+    }
+
+    pub fn with_metrics(mut self, metrics: Arc<dyn TransactionGeneratorMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Makes every account send the coins to itself, so that no other account is touched.
+    pub fn with_self_transfer(mut self) -> Self {
+        self.self_transfer = true;
+        self
+    }
+
+    /// Picks the kind of every invalid transaction from the given kinds, by weight,
+    /// instead of uniformly from the chain id, sender, receiver and duplication kinds.
+    pub fn with_invalid_kinds(
+        mut self,
+        invalid_kinds: Vec<(InvalidTransactionType, usize)>,
+    ) -> Self {
+        let total_weight: usize = invalid_kinds.iter().map(|(_, weight)| weight).sum();
+        assert!(
+            invalid_kinds.is_empty() || total_weight > 0,
+            "Invalid kinds {:?} have no weight",
+            invalid_kinds
+        );
+        self.invalid_kinds = invalid_kinds;
+        self
+    }
+
+    fn pick_invalid_kind(&self, rng: &mut ResumableRng) -> InvalidTransactionType {
+        if self.invalid_kinds.is_empty() {
+            return Standard.sample(rng);
+        }
+        let total_weight: usize = self.invalid_kinds.iter().map(|(_, weight)| weight).sum();
+        let mut picked = rng.gen_range(0, total_weight);
+        for (kind, weight) in &self.invalid_kinds {
+            if picked < *weight {
+                return *kind;
+            }
+            picked -= *weight;
+        }
+        unreachable!("Picked {} out of {}", picked, total_weight)
+    }
+
+    fn pick_amount(amount_range: (u64, u64), rng: &mut ResumableRng) -> u64 {
+        let (min, max) = amount_range;
+        // Fixed amounts don't draw from the RNG, to keep the generated stream as it was
+        if min == max {
+            min
+        } else {
+            Uniform::new_inclusive(min, max).sample(rng)
         }
     }
 
@@ -46,56 +126,148 @@ impl P2PTransactionGenerator {
         from: &mut LocalAccount,
         to: &AccountAddress,
         num_coins: u64,
-        txn_factory: &TransactionFactory,
+        txn_factory: &SharedTransactionFactory,
+        rng: &mut ResumableRng,
     ) -> SignedTransaction {
-        from.sign_with_transaction_builder(
-            txn_factory.payload(aptos_stdlib::aptos_coin_transfer(*to, num_coins)),
+        let raw_txn = Self::build_single_txn(from, to, num_coins, txn_factory, rng);
+        from.sign_transaction(raw_txn)
+    }
+
+    /// Builds the transaction without signing it, so that building and signing
+    /// can be measured separately.
+    fn build_single_txn(
+        from: &mut LocalAccount,
+        to: &AccountAddress,
+        num_coins: u64,
+        txn_factory: &SharedTransactionFactory,
+        rng: &mut ResumableRng,
+    ) -> RawTransaction {
+        build_raw_transaction(
+            from,
+            txn_factory.payload(aptos_stdlib::aptos_coin_transfer(*to, num_coins), rng),
         )
     }
 
+    /// Whether there are receivers to send to, warning if there aren't, as the addresses
+    /// pool is empty (e.g. as no source accounts were set up).
+    fn has_receivers(&self) -> bool {
+        if self.self_transfer || !self.all_addresses.read().is_empty() {
+            return true;
+        }
+        sample!(
+            SampleRate::Duration(Duration::from_secs(60)),
+            warn!("No addresses to send coins to, the addresses pool is empty")
+        );
+        false
+    }
+
+    fn pick_receivers(
+        &mut self,
+        sender: &LocalAccount,
+        transactions_per_account: usize,
+    ) -> Vec<AccountAddress> {
+        let receivers = if self.self_transfer {
+            vec![sender.address(); transactions_per_account]
+        } else {
+            self.all_addresses
+                .read()
+                .choose_multiple(&mut self.rng, transactions_per_account)
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        assert!(
+            receivers.len() >= transactions_per_account,
+            "failed: {} >= {}",
+            receivers.len(),
+            transactions_per_account
+        );
+        receivers
+    }
+
     fn generate_invalid_transaction(
         &self,
-        rng: &mut StdRng,
+        rng: &mut ResumableRng,
         sender: &mut LocalAccount,
         receiver: &AccountAddress,
         reqs: &[SignedTransaction],
     ) -> SignedTransaction {
         let mut invalid_account = LocalAccount::generate(rng);
         let invalid_address = invalid_account.address();
-        match Standard.sample(rng) {
+        let amount = Self::pick_amount(self.amount_range, rng);
+        // Transactions rejected before execution don't take the next sequence number of the
+        // sender, so that it isn't left with a gap for the transactions that follow.
+        let sender_address = sender.address();
+        let build_at =
+            |sequence_number, txn_factory: &SharedTransactionFactory, rng: &mut ResumableRng| {
+                txn_factory
+                    .payload(aptos_stdlib::aptos_coin_transfer(*receiver, amount), rng)
+                    .sender(sender_address)
+                    .sequence_number(sequence_number)
+                    .build()
+            };
+        match self.pick_invalid_kind(rng) {
             InvalidTransactionType::ChainId => {
                 let txn_factory = &self.txn_factory.clone().with_chain_id(ChainId::new(255));
-                self.gen_single_txn(sender, receiver, self.send_amount, txn_factory)
+                self.gen_single_txn(sender, receiver, amount, txn_factory, rng)
             },
             InvalidTransactionType::Sender => self.gen_single_txn(
                 &mut invalid_account,
                 receiver,
-                self.send_amount,
-                &self.txn_factory,
-            ),
-            InvalidTransactionType::Receiver => self.gen_single_txn(
-                sender,
-                &invalid_address,
-                self.send_amount,
+                amount,
                 &self.txn_factory,
+                rng,
             ),
+            InvalidTransactionType::Receiver => {
+                self.gen_single_txn(sender, &invalid_address, amount, &self.txn_factory, rng)
+            },
             InvalidTransactionType::Duplication => {
                 // if this is the first tx, default to generate invalid tx with wrong chain id
                 // otherwise, make a duplication of an exist valid tx
                 if reqs.is_empty() {
                     let txn_factory = &self.txn_factory.clone().with_chain_id(ChainId::new(255));
-                    self.gen_single_txn(sender, receiver, self.send_amount, txn_factory)
+                    self.gen_single_txn(sender, receiver, amount, txn_factory, rng)
                 } else {
                     let random_index = rng.gen_range(0, reqs.len());
                     reqs[random_index].clone()
                 }
             },
+            InvalidTransactionType::BadSignature => {
+                // signed by another key, under the public key of the sender
+                build_at(sender.sequence_number(), &self.txn_factory, rng)
+                    .sign(invalid_account.private_key(), sender.public_key().clone())
+                    .expect("Signing a txn can't fail")
+                    .into_inner()
+            },
+            InvalidTransactionType::InsufficientBalance => {
+                self.gen_single_txn(sender, receiver, u64::MAX, &self.txn_factory, rng)
+            },
+            InvalidTransactionType::OldSequenceNumber => {
+                // if nothing was sent yet, there is no old sequence number to reuse,
+                // so default to generate invalid tx with wrong chain id
+                match sender.sequence_number().checked_sub(1) {
+                    Some(old_sequence_number) => {
+                        let raw_txn = build_at(old_sequence_number, &self.txn_factory, rng);
+                        sender.sign_transaction(raw_txn)
+                    },
+                    None => {
+                        let txn_factory =
+                            &self.txn_factory.clone().with_chain_id(ChainId::new(255));
+                        self.gen_single_txn(sender, receiver, amount, txn_factory, rng)
+                    },
+                }
+            },
+            InvalidTransactionType::Expired => {
+                let txn_factory = &self.txn_factory.clone().with_expiration_offset_secs(-60);
+                let raw_txn = build_at(sender.sequence_number(), txn_factory, rng);
+                sender.sign_transaction(raw_txn)
+            },
         }
     }
 }
 
-#[derive(Debug)]
-enum InvalidTransactionType {
+/// Kinds of invalid transactions, each targeting a different rejection path.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum InvalidTransactionType {
     /// invalid tx with wrong chain id
     ChainId,
     /// invalid tx with sender not on chain
@@ -104,6 +276,14 @@ enum InvalidTransactionType {
     Receiver,
     /// duplicate an exist tx
     Duplication,
+    /// tx signed with a key other than the one of the sender
+    BadSignature,
+    /// transfer of more coins than the sender has, aborting during execution
+    InsufficientBalance,
+    /// tx reusing the last sequence number sent by the sender
+    OldSequenceNumber,
+    /// tx whose expiration time has already passed
+    Expired,
 }
 
 impl Distribution<InvalidTransactionType> for Standard {
@@ -123,6 +303,10 @@ impl TransactionGenerator for P2PTransactionGenerator {
         accounts: Vec<&mut LocalAccount>,
         transactions_per_account: usize,
     ) -> Vec<SignedTransaction> {
+        if !self.has_receivers() {
+            return Vec::new();
+        }
+
         let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
         let invalid_size = if self.invalid_transaction_ratio != 0 {
             // if enable mix invalid tx, at least 1 invalid tx per batch
@@ -131,24 +315,28 @@ impl TransactionGenerator for P2PTransactionGenerator {
             0
         };
         let mut num_valid_tx = transactions_per_account * (accounts.len() - invalid_size);
+        let mut build_time = Duration::ZERO;
+        let mut sign_time = Duration::ZERO;
         for sender in accounts {
-            let receivers = self
-                .all_addresses
-                .read()
-                .choose_multiple(&mut self.rng, transactions_per_account)
-                .cloned()
-                .collect::<Vec<_>>();
-            assert!(
-                receivers.len() >= transactions_per_account,
-                "failed: {} >= {}",
-                receivers.len(),
-                transactions_per_account
-            );
+            let receivers = self.pick_receivers(sender, transactions_per_account);
             for i in 0..transactions_per_account {
                 let receiver = receivers.get(i).expect("all_addresses can't be empty");
                 let request = if num_valid_tx > 0 {
                     num_valid_tx -= 1;
-                    self.gen_single_txn(sender, receiver, self.send_amount, &self.txn_factory)
+                    let amount = Self::pick_amount(self.amount_range, &mut self.rng);
+                    let start = Instant::now();
+                    let raw_txn = Self::build_single_txn(
+                        sender,
+                        receiver,
+                        amount,
+                        &self.txn_factory,
+                        &mut self.rng,
+                    );
+                    build_time += start.elapsed();
+                    let start = Instant::now();
+                    let txn = sender.sign_transaction(raw_txn);
+                    sign_time += start.elapsed();
+                    txn
                 } else {
                     self.generate_invalid_transaction(
                         &mut self.rng.clone(),
@@ -160,42 +348,527 @@ impl TransactionGenerator for P2PTransactionGenerator {
                 requests.push(request);
             }
         }
+        if let Some(metrics) = &self.metrics {
+            metrics
+                .record_build_and_sign(build_time.as_nanos() as u64, sign_time.as_nanos() as u64);
+        }
         requests
     }
+
+    fn generate_raw_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<RawTransaction> {
+        // Invalid transactions are defined by their signatures (or duplicate signed ones),
+        // so they only make sense signed
+        if self.invalid_transaction_ratio != 0 {
+            return self
+                .generate_transactions(accounts, transactions_per_account)
+                .into_iter()
+                .map(SignedTransaction::into_raw_transaction)
+                .collect();
+        }
+        if !self.has_receivers() {
+            return Vec::new();
+        }
+
+        let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
+        for sender in accounts {
+            let receivers = self.pick_receivers(sender, transactions_per_account);
+            for receiver in &receivers[..transactions_per_account] {
+                let amount = Self::pick_amount(self.amount_range, &mut self.rng);
+                requests.push(Self::build_single_txn(
+                    sender,
+                    receiver,
+                    amount,
+                    &self.txn_factory,
+                    &mut self.rng,
+                ));
+            }
+        }
+        requests
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
 }
 
 pub struct P2PTransactionGeneratorCreator {
-    txn_factory: TransactionFactory,
-    amount: u64,
+    txn_factory: SharedTransactionFactory,
+    amount_range: (u64, u64),
     all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
     invalid_transaction_ratio: usize,
+    metrics: Option<Arc<dyn TransactionGeneratorMetrics>>,
+    rng_seed: Option<u64>,
+    num_generators: u64,
+    self_transfer: bool,
+    invalid_kinds: Vec<(InvalidTransactionType, usize)>,
 }
 
 impl P2PTransactionGeneratorCreator {
+    /// With `rng_seed` set, the recipients, amounts and invalid transactions picked are the
+    /// same on every run. The n-th generator created is seeded with `rng_seed + n`, so that
+    /// generators don't all produce the same stream.
     pub fn new(
-        txn_factory: TransactionFactory,
-        amount: u64,
+        txn_factory: SharedTransactionFactory,
+        amount_range: (u64, u64),
         all_addresses: Arc<RwLock<Vec<AccountAddress>>>,
         invalid_transaction_ratio: usize,
+        rng_seed: Option<u64>,
     ) -> Self {
         Self {
             txn_factory,
-            amount,
+            amount_range,
             all_addresses,
             invalid_transaction_ratio,
+            metrics: None,
+            rng_seed,
+            num_generators: 0,
+            self_transfer: false,
+            invalid_kinds: Vec::new(),
         }
     }
+
+    pub fn with_metrics(mut self, metrics: Arc<dyn TransactionGeneratorMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Makes generators send the coins of every account to itself, to isolate the cost of
+    /// signature verification and sequence number updates from the state of recipients.
+    pub fn with_self_transfer(mut self) -> Self {
+        self.self_transfer = true;
+        self
+    }
+
+    /// Makes generators pick the kind of every invalid transaction from the given kinds,
+    /// by weight, to target specific rejection paths.
+    pub fn with_invalid_kinds(
+        mut self,
+        invalid_kinds: Vec<(InvalidTransactionType, usize)>,
+    ) -> Self {
+        self.invalid_kinds = invalid_kinds;
+        self
+    }
 }
 
 #[async_trait]
 impl TransactionGeneratorCreator for P2PTransactionGeneratorCreator {
     async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
-        Box::new(P2PTransactionGenerator::new(
-            StdRng::from_entropy(),
-            self.amount,
+        let rng = match self.rng_seed {
+            Some(rng_seed) => {
+                ResumableRng::seed_from_u64(rng_seed.wrapping_add(self.num_generators))
+            },
+            None => ResumableRng::from_entropy(),
+        };
+        self.num_generators += 1;
+        let generator = P2PTransactionGenerator::new(
+            rng,
+            self.amount_range,
             self.txn_factory.clone(),
             self.all_addresses.clone(),
             self.invalid_transaction_ratio,
-        ))
+        )
+        .with_invalid_kinds(self.invalid_kinds.clone());
+        let generator = if self.self_transfer {
+            generator.with_self_transfer()
+        } else {
+            generator
+        };
+        Box::new(match &self.metrics {
+            Some(metrics) => generator.with_metrics(metrics.clone()),
+            None => generator,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        create_txn_generator_creator, golden::assert_matches_golden,
+        in_memory_executor::InMemoryExecutor, GasOverrides, MixMode, TransactionType,
+    };
+    use aptos_infallible::Mutex;
+    use aptos_sdk::{
+        bcs, transaction_builder::TransactionFactory, types::transaction::TransactionPayload,
+    };
+    use std::{
+        path::Path,
+        sync::atomic::AtomicUsize,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    fn accounts(seed: u64) -> Vec<LocalAccount> {
+        let mut rng = ResumableRng::seed_from_u64(seed);
+        (0..4).map(|_| LocalAccount::generate(&mut rng)).collect()
+    }
+
+    // Expiration timestamps depend on when the transactions are generated
+    fn without_expiration(txn: SignedTransaction) -> (AccountAddress, u64, TransactionPayload) {
+        (txn.sender(), txn.sequence_number(), txn.payload().clone())
+    }
+
+    #[test]
+    fn test_resume_from_rng_snapshot() {
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let all_addresses = Arc::new(RwLock::new(
+            (0..20)
+                .map(|_| AccountAddress::random())
+                .collect::<Vec<_>>(),
+        ));
+        let new_generator = |seed| {
+            P2PTransactionGenerator::new(
+                ResumableRng::seed_from_u64(seed),
+                (1, 1),
+                txn_factory.clone(),
+                all_addresses.clone(),
+                0,
+            )
+        };
+
+        // Uninterrupted run, snapshotted after the first rounds
+        let mut generator = new_generator(0);
+        let mut senders = accounts(1);
+        for _ in 0..3 {
+            generator.generate_transactions(senders.iter_mut().collect(), 5);
+        }
+        let snapshot: RngSnapshot =
+            bcs::from_bytes(&bcs::to_bytes(&generator.rng_snapshot().unwrap()).unwrap()).unwrap();
+        let sequence_numbers = senders
+            .iter()
+            .map(|sender| sender.sequence_number())
+            .collect::<Vec<_>>();
+        let expected = (0..2)
+            .flat_map(|_| generator.generate_transactions(senders.iter_mut().collect(), 5))
+            .map(without_expiration)
+            .collect::<Vec<_>>();
+
+        // Resumed run, from a generator seeded differently
+        let mut resumed = new_generator(2);
+        resumed.restore_rng(&snapshot);
+        let mut resumed_senders = accounts(1);
+        for (sender, sequence_number) in resumed_senders.iter_mut().zip(sequence_numbers) {
+            *sender.sequence_number_mut() = sequence_number;
+        }
+        let actual = (0..2)
+            .flat_map(|_| resumed.generate_transactions(resumed_senders.iter_mut().collect(), 5))
+            .map(without_expiration)
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_seeded_creator_reproducible() {
+        let all_addresses = Arc::new(RwLock::new(
+            (0..20)
+                .map(|_| AccountAddress::random())
+                .collect::<Vec<_>>(),
+        ));
+        let generate = |rng_seed| {
+            let all_addresses = all_addresses.clone();
+            async move {
+                let mut creator = P2PTransactionGeneratorCreator::new(
+                    SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+                    (1, 100),
+                    all_addresses,
+                    10,
+                    rng_seed,
+                );
+                let mut generator = creator.create_transaction_generator().await;
+                let mut senders = accounts(1);
+                generator
+                    .generate_transactions(senders.iter_mut().collect(), 10)
+                    .into_iter()
+                    // not the hashes, the expiration time depends on the time of the call
+                    .map(|txn| (txn.sender(), txn.payload().clone()))
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(generate(Some(7)).await, generate(Some(7)).await);
+        assert_ne!(generate(Some(7)).await, generate(Some(8)).await);
+        assert_ne!(generate(None).await, generate(None).await);
+    }
+
+    #[tokio::test]
+    async fn test_self_transfer() {
+        // No recipients to pick from are needed
+        let mut creator = P2PTransactionGeneratorCreator::new(
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            (7, 7),
+            Arc::new(RwLock::new(Vec::new())),
+            0,
+            None,
+        )
+        .with_self_transfer();
+        let mut generator = creator.create_transaction_generator().await;
+        let mut senders = accounts(1);
+
+        let txns = generator.generate_transactions(senders.iter_mut().collect(), 3);
+        assert_eq!(txns.len(), 12);
+        for txn in txns {
+            assert_eq!(
+                txn.payload(),
+                &aptos_stdlib::aptos_coin_transfer(txn.sender(), 7)
+            );
+        }
+    }
+
+    struct RecordingMetrics {
+        batches: Mutex<Vec<(u64, u64)>>,
+    }
+
+    impl TransactionGeneratorMetrics for RecordingMetrics {
+        fn record_build_and_sign(&self, build_ns: u64, sign_ns: u64) {
+            self.batches.lock().push((build_ns, sign_ns));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_build_and_sign_time_reported() {
+        let metrics = Arc::new(RecordingMetrics {
+            batches: Mutex::new(Vec::new()),
+        });
+        let mut creator = P2PTransactionGeneratorCreator::new(
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            (1, 1),
+            Arc::new(RwLock::new(
+                (0..20)
+                    .map(|_| AccountAddress::random())
+                    .collect::<Vec<_>>(),
+            )),
+            0,
+            None,
+        )
+        .with_metrics(metrics.clone());
+        let mut generator = creator.create_transaction_generator().await;
+        let mut senders = accounts(1);
+
+        for _ in 0..3 {
+            generator.generate_transactions(senders.iter_mut().collect(), 5);
+        }
+        // One report per batch, and both steps take some time
+        let batches = metrics.batches.lock();
+        assert_eq!(batches.len(), 3);
+        assert!(batches
+            .iter()
+            .all(|(build_ns, sign_ns)| *build_ns > 0 && *sign_ns > 0));
+    }
+
+    #[test]
+    fn test_raw_transactions_match_signed() {
+        let all_addresses = Arc::new(RwLock::new(
+            (0..20)
+                .map(|_| AccountAddress::random())
+                .collect::<Vec<_>>(),
+        ));
+        let new_generator = || {
+            P2PTransactionGenerator::new(
+                ResumableRng::seed_from_u64(0),
+                (10, 20),
+                SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+                all_addresses.clone(),
+                0,
+            )
+        };
+        // not the expiration time, it depends on the time of the call
+        let fields = |txn: &RawTransaction| (txn.sender(), txn.clone().into_payload());
+
+        let mut senders = accounts(1);
+        let signed = new_generator()
+            .generate_transactions(senders.iter_mut().collect(), 5)
+            .into_iter()
+            .map(|txn| fields(&txn.into_raw_transaction()))
+            .collect::<Vec<_>>();
+        let mut raw_senders = accounts(1);
+        let raw = new_generator()
+            .generate_raw_transactions(raw_senders.iter_mut().collect(), 5)
+            .iter()
+            .map(fields)
+            .collect::<Vec<_>>();
+
+        assert_eq!(raw.len(), 20);
+        assert_eq!(raw, signed);
+        assert!(raw_senders
+            .iter()
+            .zip(&senders)
+            .all(|(raw_sender, sender)| raw_sender.sequence_number() == sender.sequence_number()));
+    }
+
+    #[tokio::test]
+    async fn test_empty_addresses_pool() {
+        let mut creator = P2PTransactionGeneratorCreator::new(
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            (1, 1),
+            Arc::new(RwLock::new(Vec::new())),
+            0,
+            None,
+        );
+        let mut generator = creator.create_transaction_generator().await;
+        let mut senders = accounts(1);
+
+        assert!(generator
+            .generate_transactions(senders.iter_mut().collect(), 3)
+            .is_empty());
+        assert!(generator
+            .generate_raw_transactions(senders.iter_mut().collect(), 3)
+            .is_empty());
+        assert!(senders.iter().all(|sender| sender.sequence_number() == 0));
+    }
+
+    #[test]
+    fn test_amounts_sampled_from_range() {
+        let mut generator = P2PTransactionGenerator::new(
+            ResumableRng::seed_from_u64(0),
+            (10, 20),
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            Arc::new(RwLock::new(
+                (0..20)
+                    .map(|_| AccountAddress::random())
+                    .collect::<Vec<_>>(),
+            )),
+            0,
+        );
+        let mut senders = accounts(1);
+        let amounts = generator
+            .generate_transactions(senders.iter_mut().collect(), 10)
+            .into_iter()
+            .map(|txn| {
+                let entry_function = txn.payload().clone().into_entry_function();
+                bcs::from_bytes::<u64>(&entry_function.args()[1]).unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(amounts.len(), 40);
+        assert!(amounts.iter().all(|amount| (10..=20).contains(amount)));
+        assert!(amounts.iter().any(|amount| *amount != amounts[0]));
+    }
+
+    #[test]
+    fn test_invalid_kinds() {
+        let new_generator = |invalid_kinds| {
+            P2PTransactionGenerator::new(
+                ResumableRng::seed_from_u64(0),
+                (1, 1),
+                SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+                Arc::new(RwLock::new(
+                    accounts(2)
+                        .iter()
+                        .map(|account| account.address())
+                        .collect::<Vec<_>>(),
+                )),
+                100,
+            )
+            .with_invalid_kinds(invalid_kinds)
+        };
+        let mut senders = accounts(1);
+        for sender in &mut senders {
+            *sender.sequence_number_mut() = 5;
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let txns = new_generator(vec![(InvalidTransactionType::BadSignature, 1)])
+            .generate_transactions(senders.iter_mut().collect(), 2);
+        assert_eq!(txns.len(), 8);
+        assert!(txns
+            .iter()
+            .all(|txn| txn.clone().check_signature().is_err()));
+
+        let txns = new_generator(vec![(InvalidTransactionType::OldSequenceNumber, 1)])
+            .generate_transactions(senders.iter_mut().collect(), 2);
+        assert!(txns.iter().all(|txn| txn.sequence_number() == 4));
+        assert!(txns.iter().all(|txn| txn.clone().check_signature().is_ok()));
+
+        let txns = new_generator(vec![
+            (InvalidTransactionType::Expired, 1),
+            (InvalidTransactionType::BadSignature, 0),
+        ])
+        .generate_transactions(senders.iter_mut().collect(), 2);
+        assert!(txns.iter().all(|txn| txn.expiration_timestamp_secs() < now));
+
+        // None of the kinds rejected before execution take a sequence number
+        assert!(senders.iter().all(|sender| sender.sequence_number() == 5));
+    }
+
+    #[test]
+    fn test_matches_golden() {
+        let mut generator = P2PTransactionGenerator::new(
+            ResumableRng::seed_from_u64(0),
+            (1, 100),
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            Arc::new(RwLock::new(
+                accounts(2)
+                    .iter()
+                    .map(|account| account.address())
+                    .collect::<Vec<_>>(),
+            )),
+            0,
+        );
+        let mut senders = accounts(1);
+        let txns = (0..3)
+            .flat_map(|_| generator.generate_transactions(senders.iter_mut().collect(), 4))
+            .collect::<Vec<_>>();
+        assert_matches_golden(
+            &txns,
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("goldens/p2p_transaction_generator.golden"),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_gas_unit_price_floor_respected() {
+        let mut accounts = accounts(1);
+        let executor = InMemoryExecutor::default().with_min_gas_unit_price(100);
+        let (mut creator, type_counters, _) = create_txn_generator_creator(
+            &[vec![
+                (TransactionType::default_coin_transfer(), 1),
+                (
+                    TransactionType::CoinTransfer {
+                        amount_range: (1, 1),
+                        invalid_transaction_ratio: 0,
+                        invalid_kinds: Vec::new(),
+                        sender_use_account_pool: false,
+                        account_pool: None,
+                        gas_overrides: GasOverrides {
+                            gas_unit_price: Some((1, 200)),
+                            max_gas_amount: None,
+                        },
+                    },
+                    1,
+                ),
+            ]],
+            1,
+            &mut accounts,
+            &executor,
+            &TransactionFactory::new(ChainId::test()).with_gas_unit_price(1),
+            &TransactionFactory::new(ChainId::test()).with_gas_unit_price(1),
+            None,
+            Arc::new(AtomicUsize::new(0)),
+            MixMode::Probabilistic,
+            0,
+            None,
+        )
+        .await
+        .unwrap();
+        let mut generator = creator.create_transaction_generator().await;
+
+        // As many transfers per account as there are receivers
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 4);
+        assert!(!txns.is_empty());
+        assert!(txns.iter().all(|txn| txn.gas_unit_price() >= 100));
+        // Both entries of the mix are counted under their common type
+        assert_eq!(
+            type_counters.snapshot().into_iter().collect::<Vec<_>>(),
+            vec![("CoinTransfer", txns.len() as u64)]
+        );
     }
 }
@@ -1,34 +1,42 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    publishing::publish_util::PackageHandler, TransactionGenerator, TransactionGeneratorCreator,
+    publishing::publish_util::PackageHandler,
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    TransactionGenerator, TransactionGeneratorCreator,
 };
 use aptos_infallible::RwLock;
-use aptos_sdk::{
-    transaction_builder::TransactionFactory,
-    types::{transaction::SignedTransaction, LocalAccount},
-};
+use aptos_sdk::types::{transaction::SignedTransaction, LocalAccount};
 use async_trait::async_trait;
-use rand::{rngs::StdRng, SeedableRng};
+use rand::SeedableRng;
 use std::sync::Arc;
 
+/// Publishes packages of `num_modules` modules, each padded with `module_bytes_padding`
+/// bytes (on top of the random size of the modules), and then calls into them.
 #[allow(dead_code)]
 pub struct PublishPackageGenerator {
-    rng: StdRng,
+    rng: ResumableRng,
     package_handler: Arc<RwLock<PackageHandler>>,
-    txn_factory: TransactionFactory,
+    txn_factory: SharedTransactionFactory,
+    num_modules: usize,
+    module_bytes_padding: usize,
 }
 
 impl PublishPackageGenerator {
     pub fn new(
-        rng: StdRng,
+        rng: ResumableRng,
         package_handler: Arc<RwLock<PackageHandler>>,
-        txn_factory: TransactionFactory,
+        txn_factory: SharedTransactionFactory,
+        num_modules: usize,
+        module_bytes_padding: usize,
     ) -> Self {
         Self {
             rng,
             package_handler,
             txn_factory,
+            num_modules,
+            module_bytes_padding,
         }
     }
 }
@@ -43,11 +51,12 @@ impl TransactionGenerator for PublishPackageGenerator {
         let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
         for account in accounts {
             // First publish the module and then use it
-            let package = self
+            let mut package = self
                 .package_handler
                 .write()
                 .pick_package(&mut self.rng, account);
-            let txn = package.publish_transaction(account, &self.txn_factory);
+            package.resize(self.num_modules, self.module_bytes_padding);
+            let txn = package.publish_transaction(&mut self.rng, account, &self.txn_factory);
             requests.push(txn);
             // use module published
             // for _ in 1..transactions_per_account - 1 {
@@ -66,18 +75,35 @@ impl TransactionGenerator for PublishPackageGenerator {
         }
         requests
     }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
 }
 
 pub struct PublishPackageCreator {
-    txn_factory: TransactionFactory,
+    txn_factory: SharedTransactionFactory,
     package_handler: Arc<RwLock<PackageHandler>>,
+    num_modules: usize,
+    module_bytes_padding: usize,
 }
 
 impl PublishPackageCreator {
-    pub fn new(txn_factory: TransactionFactory) -> Self {
+    pub fn new(
+        txn_factory: SharedTransactionFactory,
+        num_modules: usize,
+        module_bytes_padding: usize,
+    ) -> Self {
+        assert!(num_modules > 0, "Invalid number of modules {}", num_modules);
         Self {
             txn_factory,
             package_handler: Arc::new(RwLock::new(PackageHandler::new())),
+            num_modules,
+            module_bytes_padding,
         }
     }
 }
@@ -86,9 +112,77 @@ impl PublishPackageCreator {
 impl TransactionGeneratorCreator for PublishPackageCreator {
     async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
         Box::new(PublishPackageGenerator::new(
-            StdRng::from_entropy(),
+            ResumableRng::from_entropy(),
             self.package_handler.clone(),
             self.txn_factory.clone(),
+            self.num_modules,
+            self.module_bytes_padding,
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_framework::natives::code::PackageMetadata;
+    use aptos_sdk::{bcs, transaction_builder::TransactionFactory, types::chain_id::ChainId};
+    use move_binary_format::CompiledModule;
+
+    // Publishes a package from a fixed account and rng, and returns its metadata and modules
+    fn published_package(
+        num_modules: usize,
+        module_bytes_padding: usize,
+    ) -> (PackageMetadata, Vec<Vec<u8>>) {
+        let mut account = LocalAccount::generate(&mut ResumableRng::seed_from_u64(0));
+        let mut generator = PublishPackageGenerator::new(
+            ResumableRng::seed_from_u64(1),
+            Arc::new(RwLock::new(PackageHandler::new())),
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            num_modules,
+            module_bytes_padding,
+        );
+        let txns = generator.generate_transactions(vec![&mut account], 1);
+        assert_eq!(txns.len(), 1);
+        let entry_function = txns[0].payload().clone().into_entry_function();
+        assert_eq!(entry_function.function().as_str(), "publish_package_txn");
+        (
+            bcs::from_bytes(&bcs::from_bytes::<Vec<u8>>(&entry_function.args()[0]).unwrap())
+                .unwrap(),
+            bcs::from_bytes(&entry_function.args()[1]).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_module_count_and_padding() {
+        let (_, unpadded) = published_package(1, 0);
+        let (metadata, code) = published_package(3, 2_000);
+
+        assert_eq!(code.len(), 3);
+        assert_eq!(metadata.modules.len(), 3);
+        let names = code
+            .iter()
+            .map(|bytes| {
+                CompiledModule::deserialize(bytes)
+                    .unwrap()
+                    .self_id()
+                    .name()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            metadata
+                .modules
+                .iter()
+                .map(|module| module.name.clone())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(names[1], format!("{}_1", names[0]));
+        assert_eq!(names[2], format!("{}_2", names[0]));
+
+        // Same rng, so the modules only differ by the padding (and the name of the copies)
+        let size = unpadded[0].len();
+        assert!(code[0].len() >= size + 2_000 && code[0].len() < size + 2_100);
+        assert!(code.iter().all(|bytes| bytes.len() >= size + 2_000));
+    }
+}
@@ -2,13 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 #![allow(unused)]
 
-use crate::publishing::raw_module_data;
+use crate::{publishing::raw_module_data, resumable_rng::ResumableRng};
 use aptos_framework::natives::code::PackageMetadata;
 use aptos_sdk::{
     bcs,
     move_types::{
-        account_address::AccountAddress, ident_str, identifier::Identifier,
-        language_storage::ModuleId,
+        account_address::AccountAddress,
+        ident_str,
+        identifier::Identifier,
+        language_storage::{ModuleId, StructTag, TypeTag},
     },
     types::transaction::{EntryFunction, TransactionPayload},
 };
@@ -16,8 +18,9 @@ use move_binary_format::{
     file_format::{FunctionHandleIndex, IdentifierIndex, SignatureToken},
     CompiledModule,
 };
-use rand::{distributions::Alphanumeric, prelude::StdRng, seq::SliceRandom, Rng};
+use rand::{distributions::Alphanumeric, seq::SliceRandom, Rng};
 use rand_core::RngCore;
+use serde::{Deserialize, Serialize};
 
 //
 // Contains all the code to work on the Simple package
@@ -37,7 +40,7 @@ pub fn load_package() -> (Vec<CompiledModule>, PackageMetadata) {
     (modules, metadata)
 }
 
-pub fn version(module: &mut CompiledModule, rng: &mut StdRng) {
+pub fn version(module: &mut CompiledModule, rng: &mut ResumableRng) {
     // change `const COUNTER_STEP` in Simple.move
     // That is the only u64 in the constant pool
     for constant in &mut module.constant_pool {
@@ -50,7 +53,7 @@ pub fn version(module: &mut CompiledModule, rng: &mut StdRng) {
     }
 }
 
-pub fn scramble(module: &mut CompiledModule, fn_count: usize, rng: &mut StdRng) {
+pub fn scramble(module: &mut CompiledModule, fn_count: usize, rng: &mut ResumableRng) {
     // change `const RANDOM` in Simple.move
     // That is the only vector<u64> in the constant pool
     let const_len = rng.gen_range(0usize, 5000usize);
@@ -97,11 +100,22 @@ pub fn scramble(module: &mut CompiledModule, fn_count: usize, rng: &mut StdRng)
     }
 }
 
+// Type of the `SimpleCoin` struct in Simple.move, usable as a `managed_coin` type
+// once initialized by the publisher.
+pub fn simple_coin_type(module_id: ModuleId) -> TypeTag {
+    TypeTag::Struct(Box::new(StructTag {
+        address: *module_id.address(),
+        module: module_id.name().to_owned(),
+        name: ident_str!("SimpleCoin").to_owned(),
+        type_params: vec![],
+    }))
+}
+
 //
 // List of entry points to expose
 //
 // More info in the Simple.move
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Deserialize, Serialize)]
 pub enum EntryPoints {
     // 0 args
     /// Empty (NoOp) function
@@ -144,13 +158,109 @@ pub enum EntryPoints {
     BytesMakeOrChange {
         data_length: Option<usize>,
     },
+    /// Append `count` elements to `VectorResource`
+    AppendToVector {
+        count: u64,
+    },
+    /// Emit a `MarkerEvent` carrying `marker`
+    EmitMarker {
+        marker: u64,
+    },
+    /// Borrow from `LoanPool`, run `steps` operations and repay, atomically
+    FlashLoan {
+        steps: u64,
+    },
+    /// Empty (NoOp) function, with an unused argument to make the payload unique
+    NopWithNonce {
+        nonce: u64,
+    },
+    /// Empty (NoOp) function, signed by the sender and one secondary signer
+    NopTwoSigners,
+    /// Increment the `Counter` of the publisher (shared by all senders) `increments` times
+    IncrementSharedCounter {
+        increments: u64,
+    },
+    /// Set the price of `feed` in the `PriceFeeds` of the sender to `price`
+    UpdatePrice {
+        feed: u64,
+        price: u64,
+    },
+    /// Read and write the first `members_touched` members of the `ResourceGroup` of the sender
+    ResourceGroupOps {
+        members_touched: u64,
+    },
+    /// Create items `start` to `start + count` in the `WideReadSet` of the publisher
+    InitWideReadSet {
+        start: u64,
+        count: u64,
+    },
+    /// Read the first `resources_read` items of the `WideReadSet` of the publisher
+    ReadWideSet {
+        resources_read: u64,
+    },
+    /// Read the `Counter` of the publisher (shared by all senders) `iterations` times
+    HeavyRead {
+        iterations: u64,
+    },
 }
 
 impl EntryPoints {
+    /// Name of the entry function called for this entry point.
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            EntryPoints::Nop => "nop",
+            EntryPoints::Step => "step",
+            EntryPoints::GetCounter => "get_counter",
+            EntryPoints::ResetData => "reset_data",
+            EntryPoints::Double => "double",
+            EntryPoints::Half => "half",
+            EntryPoints::Loopy { .. } => "loopy",
+            EntryPoints::GetFromConst { .. } => "get_from_random_const",
+            EntryPoints::SetId => "set_id",
+            EntryPoints::SetName => "set_name",
+            EntryPoints::Maximize => "maximize",
+            EntryPoints::Minimize => "minimize",
+            EntryPoints::MakeOrChange { .. } => "make_or_change",
+            EntryPoints::BytesMakeOrChange { .. } => "bytes_make_or_change",
+            EntryPoints::AppendToVector { .. } => "append_to_vector",
+            EntryPoints::EmitMarker { .. } => "emit_marker",
+            EntryPoints::FlashLoan { .. } => "flash_loan",
+            EntryPoints::NopWithNonce { .. } => "nop_with_nonce",
+            EntryPoints::NopTwoSigners => "nop_2_signers",
+            EntryPoints::IncrementSharedCounter { .. } => "increment_shared_counter",
+            EntryPoints::UpdatePrice { .. } => "update_price",
+            EntryPoints::ResourceGroupOps { .. } => "touch_resource_group",
+            EntryPoints::InitWideReadSet { .. } => "init_wide_read_set",
+            EntryPoints::ReadWideSet { .. } => "read_wide_set",
+            EntryPoints::HeavyRead { .. } => "heavy_read",
+        }
+    }
+
+    /// Number of signers the entry function takes besides the sender,
+    /// which need to sign the transaction as secondary signers.
+    pub fn num_secondary_signers(&self) -> usize {
+        match self {
+            EntryPoints::NopTwoSigners => 1,
+            _ => 0,
+        }
+    }
+
+    /// Returns the equivalent entry point taking `nonce` as an (additional) argument,
+    /// or None if the entry point has no such variant.
+    pub fn with_nonce(&self, nonce: u64) -> Option<EntryPoints> {
+        match self {
+            EntryPoints::Nop | EntryPoints::NopWithNonce { .. } => {
+                Some(EntryPoints::NopWithNonce { nonce })
+            },
+            EntryPoints::EmitMarker { .. } => Some(EntryPoints::EmitMarker { marker: nonce }),
+            _ => None,
+        }
+    }
+
     pub fn create_payload(
         &self,
         module_id: ModuleId,
-        rng: Option<&mut StdRng>,
+        rng: Option<&mut ResumableRng>,
         other: Option<AccountAddress>,
     ) -> TransactionPayload {
         match self {
@@ -198,6 +308,27 @@ impl EntryPoints {
                 let data_len = data_length.unwrap_or_else(|| rng.gen_range(0usize, 1000usize));
                 bytes_make_or_change(rng, module_id, data_len)
             },
+            EntryPoints::AppendToVector { count } => append_to_vector(module_id, *count),
+            EntryPoints::EmitMarker { marker } => emit_marker(module_id, *marker),
+            EntryPoints::FlashLoan { steps } => flash_loan(module_id, *steps),
+            EntryPoints::NopWithNonce { nonce } => nop_with_nonce(module_id, *nonce),
+            EntryPoints::NopTwoSigners => {
+                get_payload_void(module_id, ident_str!("nop_2_signers").to_owned())
+            },
+            EntryPoints::IncrementSharedCounter { increments } => {
+                increment_shared_counter(module_id, *increments)
+            },
+            EntryPoints::UpdatePrice { feed, price } => update_price(module_id, *feed, *price),
+            EntryPoints::ResourceGroupOps { members_touched } => {
+                touch_resource_group(module_id, *members_touched)
+            },
+            EntryPoints::InitWideReadSet { start, count } => {
+                init_wide_read_set(module_id, *start, *count)
+            },
+            EntryPoints::ReadWideSet { resources_read } => {
+                read_wide_set(module_id, *resources_read)
+            },
+            EntryPoints::HeavyRead { iterations } => heavy_read(module_id, *iterations),
         }
     }
 }
@@ -245,21 +376,21 @@ const GEN_ENTRY_POINTS: &[EntryPoints; 12] = &[
     EntryPoints::BytesMakeOrChange { data_length: None },
 ];
 
-pub fn rand_simple_function(rng: &mut StdRng, module_id: ModuleId) -> TransactionPayload {
+pub fn rand_simple_function(rng: &mut ResumableRng, module_id: ModuleId) -> TransactionPayload {
     SIMPLE_ENTRY_POINTS
         .choose(rng)
         .unwrap()
         .create_payload(module_id, Some(rng), None)
 }
 
-pub fn zero_args_function(rng: &mut StdRng, module_id: ModuleId) -> TransactionPayload {
+pub fn zero_args_function(rng: &mut ResumableRng, module_id: ModuleId) -> TransactionPayload {
     ZERO_ARG_ENTRY_POINTS
         .choose(rng)
         .unwrap()
         .create_payload(module_id, Some(rng), None)
 }
 
-pub fn rand_gen_function(rng: &mut StdRng, module_id: ModuleId) -> TransactionPayload {
+pub fn rand_gen_function(rng: &mut ResumableRng, module_id: ModuleId) -> TransactionPayload {
     GEN_ENTRY_POINTS
         .choose(rng)
         .unwrap()
@@ -284,14 +415,14 @@ fn get_from_random_const(module_id: ModuleId, idx: u64) -> TransactionPayload {
     )
 }
 
-fn set_id(rng: &mut StdRng, module_id: ModuleId) -> TransactionPayload {
+fn set_id(rng: &mut ResumableRng, module_id: ModuleId) -> TransactionPayload {
     let id: u64 = rng.gen();
     get_payload(module_id, ident_str!("set_id").to_owned(), vec![
         bcs::to_bytes(&id).unwrap(),
     ])
 }
 
-fn set_name(rng: &mut StdRng, module_id: ModuleId) -> TransactionPayload {
+fn set_name(rng: &mut ResumableRng, module_id: ModuleId) -> TransactionPayload {
     let len = rng.gen_range(0usize, 1000usize);
     let name: String = rng
         .sample_iter(&Alphanumeric)
@@ -316,7 +447,7 @@ fn minimize(module_id: ModuleId, other: AccountAddress) -> TransactionPayload {
 }
 
 fn make_or_change(
-    rng: &mut StdRng,
+    rng: &mut ResumableRng,
     module_id: ModuleId,
     str_len: usize,
     data_len: usize,
@@ -337,7 +468,7 @@ fn make_or_change(
 }
 
 fn bytes_make_or_change(
-    rng: &mut StdRng,
+    rng: &mut ResumableRng,
     module_id: ModuleId,
     data_len: usize,
 ) -> TransactionPayload {
@@ -350,6 +481,76 @@ fn bytes_make_or_change(
     )
 }
 
+fn append_to_vector(module_id: ModuleId, count: u64) -> TransactionPayload {
+    get_payload(module_id, ident_str!("append_to_vector").to_owned(), vec![
+        bcs::to_bytes(&count).unwrap(),
+    ])
+}
+
+fn emit_marker(module_id: ModuleId, marker: u64) -> TransactionPayload {
+    get_payload(module_id, ident_str!("emit_marker").to_owned(), vec![
+        bcs::to_bytes(&marker).unwrap(),
+    ])
+}
+
+fn flash_loan(module_id: ModuleId, steps: u64) -> TransactionPayload {
+    get_payload(module_id, ident_str!("flash_loan").to_owned(), vec![
+        bcs::to_bytes(&steps).unwrap(),
+    ])
+}
+
+fn nop_with_nonce(module_id: ModuleId, nonce: u64) -> TransactionPayload {
+    get_payload(module_id, ident_str!("nop_with_nonce").to_owned(), vec![
+        bcs::to_bytes(&nonce).unwrap(),
+    ])
+}
+
+fn increment_shared_counter(module_id: ModuleId, increments: u64) -> TransactionPayload {
+    get_payload(
+        module_id,
+        ident_str!("increment_shared_counter").to_owned(),
+        vec![bcs::to_bytes(&increments).unwrap()],
+    )
+}
+
+fn update_price(module_id: ModuleId, feed: u64, price: u64) -> TransactionPayload {
+    get_payload(module_id, ident_str!("update_price").to_owned(), vec![
+        bcs::to_bytes(&feed).unwrap(),
+        bcs::to_bytes(&price).unwrap(),
+    ])
+}
+
+fn touch_resource_group(module_id: ModuleId, members_touched: u64) -> TransactionPayload {
+    get_payload(
+        module_id,
+        ident_str!("touch_resource_group").to_owned(),
+        vec![bcs::to_bytes(&members_touched).unwrap()],
+    )
+}
+
+fn init_wide_read_set(module_id: ModuleId, start: u64, count: u64) -> TransactionPayload {
+    get_payload(
+        module_id,
+        ident_str!("init_wide_read_set").to_owned(),
+        vec![
+            bcs::to_bytes(&start).unwrap(),
+            bcs::to_bytes(&count).unwrap(),
+        ],
+    )
+}
+
+fn read_wide_set(module_id: ModuleId, resources_read: u64) -> TransactionPayload {
+    get_payload(module_id, ident_str!("read_wide_set").to_owned(), vec![
+        bcs::to_bytes(&resources_read).unwrap(),
+    ])
+}
+
+fn heavy_read(module_id: ModuleId, iterations: u64) -> TransactionPayload {
+    get_payload(module_id, ident_str!("heavy_read").to_owned(), vec![
+        bcs::to_bytes(&iterations).unwrap(),
+    ])
+}
+
 fn get_payload_void(module_id: ModuleId, func: Identifier) -> TransactionPayload {
     get_payload(module_id, func, vec![])
 }
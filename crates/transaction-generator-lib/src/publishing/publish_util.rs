@@ -2,16 +2,35 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::module_simple::EntryPoints;
-use crate::publishing::module_simple;
-use aptos_framework::natives::code::PackageMetadata;
+use crate::{
+    publishing::module_simple, resumable_rng::ResumableRng,
+    shared_txn_factory::SharedTransactionFactory,
+};
+use aptos_framework::{
+    get_metadata_from_compiled_module,
+    natives::code::{ModuleMetadata, PackageMetadata},
+    KnownAttribute, APTOS_METADATA_KEY_V1,
+};
+use aptos_rest_client::aptos_api_types::MoveModule;
 use aptos_sdk::{
     bcs,
-    move_types::identifier::Identifier,
-    transaction_builder::{aptos_stdlib, TransactionFactory},
-    types::{account_address::AccountAddress, transaction::SignedTransaction, LocalAccount},
+    move_types::{
+        identifier::Identifier,
+        language_storage::{ModuleId, StructTag, TypeTag},
+    },
+    transaction_builder::aptos_stdlib,
+    types::{
+        account_address::AccountAddress,
+        transaction::{SignedTransaction, TransactionPayload},
+        LocalAccount,
+    },
+};
+use move_binary_format::{
+    access::ModuleAccess,
+    file_format::{Constant, SignatureToken},
+    CompiledModule,
 };
-use move_binary_format::{access::ModuleAccess, CompiledModule};
-use rand::{rngs::StdRng, Rng};
+use rand::{thread_rng, Rng};
 
 // Information used to track a publisher and what allows to identify and
 // version the package published.
@@ -65,7 +84,11 @@ impl PackageHandler {
     // Return a `Package` to be published. Packages are tracked by publisher so if
     // the same `LocalAccount` is used, the package will be an upgrade of the existing one
     // otherwise a "new" package will be generated (new suffix)
-    pub fn pick_package(&mut self, rng: &mut StdRng, publisher: &mut LocalAccount) -> Package {
+    pub fn pick_package(
+        &mut self,
+        rng: &mut ResumableRng,
+        publisher: &mut LocalAccount,
+    ) -> Package {
         let idx = rng.gen_range(0usize, self.packages.len());
         let tracker = self
             .packages
@@ -96,6 +119,26 @@ impl PackageHandler {
         // info!("PACKAGE: {:#?}", package);
         package
     }
+
+    // Return a new `Package` to be published, even if the publisher already published
+    // packages, so that each call adds a distinct module (with a new suffix) to the chain
+    pub fn pick_new_package(
+        &mut self,
+        rng: &mut ResumableRng,
+        publisher: &mut LocalAccount,
+    ) -> Package {
+        let tracker = &mut self.packages[0];
+        let info = PackageInfo {
+            publisher: publisher.address(),
+            suffix: tracker.suffix,
+            fn_count: rng.gen_range(0usize, 30usize),
+        };
+        tracker.suffix += 1;
+        let mut package = tracker.package.update(info.publisher, info.suffix);
+        package.scramble(info.fn_count, rng);
+        tracker.publishers.push(info);
+        package
+    }
 }
 
 // Enum to define all packages known to the publisher code.
@@ -121,7 +164,7 @@ impl Package {
     }
 
     // Change package "version"
-    pub fn version(&mut self, rng: &mut StdRng) {
+    pub fn version(&mut self, rng: &mut ResumableRng) {
         match self {
             Self::Simple(modules, _) => {
                 module_simple::version(&mut modules[0], rng);
@@ -130,8 +173,8 @@ impl Package {
     }
 
     // Scrambles the package, passing a function count for the functions that can
-    // be duplicated and a `StdRng` to generate random values
-    pub fn scramble(&mut self, fn_count: usize, rng: &mut StdRng) {
+    // be duplicated and a `ResumableRng` to generate random values
+    pub fn scramble(&mut self, fn_count: usize, rng: &mut ResumableRng) {
         match self {
             Self::Simple(modules, _) => {
                 module_simple::scramble(&mut modules[0], fn_count, rng);
@@ -139,15 +182,38 @@ impl Package {
         }
     }
 
+    // Grows the package to `num_modules` modules, copies of the first one (named after it,
+    // with an index suffix), and pads every module with a constant of `module_bytes_padding`
+    // bytes, so that the size of the published package can be controlled. The constant is
+    // never used, it only adds to the bytecode size.
+    pub fn resize(&mut self, num_modules: usize, module_bytes_padding: usize) {
+        match self {
+            Self::Simple(modules, metadata) => {
+                for i in modules.len()..num_modules {
+                    let (module, module_metadata) =
+                        copy_module(&modules[0], &metadata.modules[0], i);
+                    modules.push(module);
+                    metadata.modules.push(module_metadata);
+                }
+                if module_bytes_padding > 0 {
+                    for module in modules.iter_mut() {
+                        pad_module(module, module_bytes_padding);
+                    }
+                }
+            },
+        }
+    }
+
     // Return a transaction to publish the current package
     pub fn publish_transaction(
         &self,
+        rng: &mut ResumableRng,
         publisher: &mut LocalAccount,
-        txn_factory: &TransactionFactory,
+        txn_factory: &SharedTransactionFactory,
     ) -> SignedTransaction {
         match self {
             Self::Simple(modules, metadata) => {
-                publish_transaction(txn_factory, publisher, modules, metadata)
+                publish_transaction(rng, txn_factory, publisher, modules, metadata)
             },
         }
     }
@@ -155,34 +221,65 @@ impl Package {
     // Return a transaction to use the current package
     pub fn use_random_transaction(
         &self,
-        rng: &mut StdRng,
+        rng: &mut ResumableRng,
         account: &mut LocalAccount,
-        txn_factory: &TransactionFactory,
+        txn_factory: &SharedTransactionFactory,
     ) -> SignedTransaction {
         match self {
             Self::Simple(modules, _) => {
                 let module_id = modules[0].self_id();
                 // let payload = module_simple::rand_gen_function(rng, module_id);
                 let payload = module_simple::rand_simple_function(rng, module_id);
-                account.sign_with_transaction_builder(txn_factory.payload(payload))
+                account.sign_with_transaction_builder(txn_factory.payload(payload, rng))
             },
         }
     }
 
+    // Return the id of the module called by the entry points
+    pub fn module_id(&self) -> ModuleId {
+        match self {
+            Self::Simple(modules, _) => modules[0].self_id(),
+        }
+    }
+
+    // Return the ABI of the module called by the entry points, as published by this package
+    pub fn module_abi(&self) -> MoveModule {
+        match self {
+            Self::Simple(modules, _) => MoveModule::from(modules[0].clone()),
+        }
+    }
+
+    // Return the type of the coin defined in the current package
+    pub fn coin_type(&self) -> TypeTag {
+        match self {
+            Self::Simple(modules, _) => module_simple::simple_coin_type(modules[0].self_id()),
+        }
+    }
+
     pub fn use_specific_transaction(
         &self,
         fun: EntryPoints,
         account: &mut LocalAccount,
-        txn_factory: &TransactionFactory,
-        rng: Option<&mut StdRng>,
+        txn_factory: &SharedTransactionFactory,
+        mut rng: Option<&mut ResumableRng>,
         other: Option<AccountAddress>,
     ) -> SignedTransaction {
+        let payload = self.specific_payload(fun, rng.as_deref_mut(), other);
+        account.sign_with_transaction_builder(match rng {
+            Some(rng) => txn_factory.payload(payload, rng),
+            None => txn_factory.payload(payload, &mut thread_rng()),
+        })
+    }
+
+    // Return the payload of a transaction to use the current package, for the given entry point
+    pub fn specific_payload(
+        &self,
+        fun: EntryPoints,
+        rng: Option<&mut ResumableRng>,
+        other: Option<AccountAddress>,
+    ) -> TransactionPayload {
         match self {
-            Self::Simple(modules, _) => {
-                let module_id = modules[0].self_id();
-                let payload = fun.create_payload(module_id, rng, other);
-                account.sign_with_transaction_builder(txn_factory.payload(payload))
-            },
+            Self::Simple(modules, _) => fun.create_payload(modules[0].self_id(), rng, other),
         }
     }
 }
@@ -210,9 +307,12 @@ fn update(
             &mut new_module.identifiers[module_handle.name.0 as usize],
             Identifier::new(new_name).expect("Identifier must be legal"),
         );
+        update_resource_group_members(&mut new_module, &module.self_id());
         new_modules.push(new_module);
     }
     let mut metadata = metadata.clone();
+    // packages of the same publisher need distinct names, not to be seen as upgrades
+    metadata.name.push_str(suffix.to_string().as_str());
     for module in &mut metadata.modules {
         let mut new_name = module.name.clone();
         new_name.push_str(suffix.to_string().as_str());
@@ -221,8 +321,69 @@ fn update(
     (new_modules, metadata)
 }
 
+// Copy of the module (and its metadata), named after it with the `_<index>` suffix.
+fn copy_module(
+    module: &CompiledModule,
+    metadata: &ModuleMetadata,
+    index: usize,
+) -> (CompiledModule, ModuleMetadata) {
+    let mut new_module = module.clone();
+    let name_idx = new_module.module_handles[module.self_handle_idx().0 as usize].name;
+    let new_name = format!("{}_{}", module.self_id().name(), index);
+    new_module.identifiers[name_idx.0 as usize] =
+        Identifier::new(new_name.as_str()).expect("Identifier must be legal");
+    update_resource_group_members(&mut new_module, &module.self_id());
+
+    let mut metadata = metadata.clone();
+    metadata.name = new_name;
+    (new_module, metadata)
+}
+
+// Adds a `vector<u8>` constant of `padding` bytes to the constant pool of the module.
+// Constants in the pool must be distinct, so the bytes follow a pattern unlikely to
+// be found in the original module.
+fn pad_module(module: &mut CompiledModule, padding: usize) {
+    let data = (0..padding).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+    module.constant_pool.push(Constant {
+        type_: SignatureToken::Vector(Box::new(SignatureToken::U8)),
+        data: bcs::to_bytes(&data).expect("Padding must serialize"),
+    });
+}
+
+// Resource group members name their group by its fully qualified name, so groups
+// declared in the module itself have to follow the module to its new address and name.
+fn update_resource_group_members(module: &mut CompiledModule, old_module_id: &ModuleId) {
+    let mut metadata = match get_metadata_from_compiled_module(module) {
+        Some(metadata) => metadata,
+        None => return,
+    };
+    let new_module_id = module.self_id();
+    for attributes in metadata.struct_attributes.values_mut() {
+        for attribute in attributes.iter_mut() {
+            if let Some(group) = attribute.get_resource_group_member() {
+                if &group.module_id() == old_module_id {
+                    let group = StructTag {
+                        address: *new_module_id.address(),
+                        module: new_module_id.name().to_owned(),
+                        ..group
+                    };
+                    *attribute = KnownAttribute::resource_group_member(group.to_string());
+                }
+            }
+        }
+    }
+    if let Some(data) = module
+        .metadata
+        .iter_mut()
+        .find(|data| data.key == *APTOS_METADATA_KEY_V1)
+    {
+        data.value = bcs::to_bytes(&metadata).expect("Metadata must serialize");
+    }
+}
+
 fn publish_transaction(
-    txn_factory: &TransactionFactory,
+    rng: &mut ResumableRng,
+    txn_factory: &SharedTransactionFactory,
     publisher: &mut LocalAccount,
     modules: &[CompiledModule],
     metadata: &PackageMetadata,
@@ -237,5 +398,5 @@ fn publish_transaction(
         code.push(module_code);
     }
     let payload = aptos_stdlib::code_publish_package_txn(metadata, code);
-    publisher.sign_with_transaction_builder(txn_factory.payload(payload))
+    publisher.sign_with_transaction_builder(txn_factory.payload(payload, rng))
 }
@@ -18,10 +18,10 @@ use once_cell::sync::Lazy;
 pub static PACKAGE_METADATA_SIMPLE: Lazy<Vec<u8>> = Lazy::new(|| {
 	vec![
 		13, 71, 101, 110, 101, 114, 105, 99, 77, 111, 100, 117, 108, 101, 1, 0, 0, 0,
-		0, 0, 0, 0, 0, 64, 69, 56, 55, 48, 54, 52, 53, 49, 69, 53, 51, 57,
-		65, 54, 56, 49, 70, 50, 54, 49, 48, 55, 50, 55, 57, 52, 66, 66, 67, 69,
-		53, 56, 54, 55, 51, 67, 49, 70, 49, 57, 69, 69, 65, 52, 50, 69, 66, 66,
-		69, 70, 51, 51, 69, 51, 70, 67, 67, 53, 49, 56, 52, 48, 69, 56, 132, 1,
+		0, 0, 0, 0, 0, 64, 53, 54, 52, 70, 53, 65, 49, 49, 53, 70, 54, 48,
+		48, 52, 49, 53, 68, 48, 66, 68, 55, 57, 52, 65, 70, 57, 49, 69, 48, 56,
+		68, 53, 66, 54, 52, 50, 48, 68, 67, 68, 49, 54, 66, 67, 65, 69, 69, 70,
+		48, 52, 53, 65, 50, 49, 66, 52, 51, 53, 48, 69, 56, 55, 48, 49, 132, 1,
 		31, 139, 8, 0, 0, 0, 0, 0, 2, 255, 77, 139, 59, 14, 194, 48, 16, 68,
 		251, 61, 133, 229, 30, 135, 11, 80, 208, 64, 197, 9, 162, 20, 43, 123, 64, 86,
 		156, 93, 203, 134, 80, 32, 238, 142, 45, 1, 138, 102, 154, 249, 188, 49, 179, 159,
@@ -44,125 +44,289 @@ pub static PACKAGE_METADATA_SIMPLE: Lazy<Vec<u8>> = Lazy::new(|| {
 #[rustfmt::skip]
 pub static MODULE_SIMPLE: Lazy<Vec<u8>> = Lazy::new(|| {
 	vec![
-		161, 28, 235, 11, 6, 0, 0, 0, 10, 1, 0, 6, 2, 6, 20, 3, 26, 95,
-		5, 121, 150, 1, 7, 143, 2, 151, 2, 8, 166, 4, 64, 6, 230, 4, 115, 10,
-		217, 5, 28, 12, 245, 5, 179, 10, 13, 168, 16, 12, 0, 0, 1, 1, 1, 2,
-		0, 3, 8, 0, 0, 4, 8, 0, 0, 5, 7, 0, 0, 6, 8, 0, 2, 16,
-		7, 0, 0, 7, 0, 1, 0, 0, 8, 2, 1, 0, 0, 9, 3, 4, 0, 0,
-		10, 5, 1, 0, 0, 11, 5, 1, 0, 0, 12, 6, 1, 0, 0, 13, 5, 1,
-		0, 0, 14, 5, 1, 0, 0, 15, 6, 1, 0, 0, 17, 7, 1, 0, 0, 18,
-		8, 1, 0, 0, 19, 8, 1, 0, 0, 20, 5, 1, 0, 0, 21, 5, 1, 0,
-		0, 22, 6, 1, 0, 0, 23, 9, 1, 0, 0, 24, 5, 1, 0, 1, 29, 5,
-		13, 0, 2, 30, 16, 17, 0, 2, 7, 10, 2, 6, 10, 2, 0, 2, 6, 12,
-		10, 2, 4, 6, 8, 3, 6, 8, 3, 6, 8, 1, 6, 8, 1, 1, 6, 3,
-		1, 6, 12, 2, 6, 12, 3, 4, 6, 12, 3, 8, 4, 10, 2, 2, 6, 12,
-		5, 2, 6, 12, 8, 4, 1, 3, 1, 2, 2, 7, 8, 0, 8, 0, 1, 5,
-		3, 6, 3, 6, 3, 6, 3, 3, 3, 8, 3, 7, 8, 3, 1, 10, 2, 1,
-		8, 4, 3, 10, 3, 10, 3, 3, 3, 8, 2, 7, 8, 3, 8, 3, 8, 1,
-		10, 2, 7, 8, 3, 10, 2, 3, 3, 8, 3, 7, 8, 3, 9, 3, 7, 8,
-		3, 3, 3, 3, 8, 3, 7, 8, 3, 6, 8, 3, 6, 8, 3, 2, 7, 8,
-		3, 8, 3, 2, 8, 3, 7, 8, 3, 1, 7, 8, 1, 6, 83, 105, 109, 112,
-		108, 101, 6, 115, 105, 103, 110, 101, 114, 6, 115, 116, 114, 105, 110, 103, 12, 66,
-		121, 116, 101, 82, 101, 115, 111, 117, 114, 99, 101, 7, 67, 111, 117, 110, 116, 101,
-		114, 4, 68, 97, 116, 97, 8, 82, 101, 115, 111, 117, 114, 99, 101, 11, 97, 112,
-		112, 101, 110, 100, 95, 100, 97, 116, 97, 20, 98, 121, 116, 101, 115, 95, 109, 97,
-		107, 101, 95, 111, 114, 95, 99, 104, 97, 110, 103, 101, 14, 99, 111, 112, 121, 95,
-		112, 97, 115, 116, 97, 95, 114, 101, 102, 6, 100, 111, 117, 98, 108, 101, 11, 103,
-		101, 116, 95, 99, 111, 117, 110, 116, 101, 114, 21, 103, 101, 116, 95, 102, 114, 111,
-		109, 95, 114, 97, 110, 100, 111, 109, 95, 99, 111, 110, 115, 116, 4, 104, 97, 108,
-		102, 11, 105, 110, 105, 116, 95, 109, 111, 100, 117, 108, 101, 5, 108, 111, 111, 112,
-		121, 6, 83, 116, 114, 105, 110, 103, 14, 109, 97, 107, 101, 95, 111, 114, 95, 99,
-		104, 97, 110, 103, 101, 8, 109, 97, 120, 105, 109, 105, 122, 101, 8, 109, 105, 110,
-		105, 109, 105, 122, 101, 3, 110, 111, 112, 10, 114, 101, 115, 101, 116, 95, 100, 97,
-		116, 97, 6, 115, 101, 116, 95, 105, 100, 8, 115, 101, 116, 95, 110, 97, 109, 101,
-		4, 115, 116, 101, 112, 4, 100, 97, 116, 97, 5, 99, 111, 117, 110, 116, 2, 105,
-		100, 4, 110, 97, 109, 101, 10, 97, 100, 100, 114, 101, 115, 115, 95, 111, 102, 4,
-		117, 116, 102, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 205,
+		161, 28, 235, 11, 6, 0, 0, 0, 14, 1, 0, 16, 2, 16, 104, 3, 120, 151,
+		2, 4, 143, 3, 46, 5, 189, 3, 138, 3, 7, 199, 6, 200, 7, 8, 143, 14,
+		64, 6, 207, 14, 115, 16, 194, 15, 232, 1, 10, 170, 17, 123, 11, 165, 18, 2,
+		12, 167, 18, 217, 20, 13, 128, 39, 36, 14, 164, 39, 4, 0, 0, 1, 1, 1,
+		2, 1, 3, 1, 4, 1, 5, 1, 6, 1, 7, 0, 8, 8, 0, 0, 9, 8,
+		0, 0, 10, 7, 0, 0, 11, 8, 0, 0, 12, 8, 0, 0, 13, 8, 0, 0,
+		14, 8, 0, 0, 15, 8, 2, 0, 1, 0, 1, 0, 16, 8, 0, 0, 17, 0,
+		0, 0, 18, 6, 0, 0, 19, 8, 0, 0, 20, 8, 0, 0, 21, 8, 0, 0,
+		22, 0, 0, 0, 23, 0, 0, 0, 24, 8, 0, 0, 25, 8, 0, 5, 43, 7,
+		0, 2, 64, 4, 1, 0, 1, 3, 71, 4, 1, 6, 1, 6, 73, 4, 2, 3,
+		1, 0, 1, 7, 83, 7, 0, 0, 26, 0, 1, 0, 0, 27, 2, 1, 0, 0,
+		28, 3, 4, 0, 0, 29, 5, 1, 0, 0, 30, 6, 7, 0, 0, 31, 8, 1,
+		2, 0, 0, 0, 32, 9, 1, 0, 0, 33, 2, 1, 0, 0, 34, 2, 1, 0,
+		0, 35, 9, 1, 0, 0, 36, 2, 1, 0, 0, 37, 9, 1, 0, 0, 38, 2,
+		1, 0, 0, 39, 2, 1, 0, 0, 40, 9, 1, 0, 0, 41, 8, 1, 0, 0,
+		42, 2, 1, 0, 0, 44, 10, 1, 0, 0, 45, 11, 1, 0, 0, 46, 11, 1,
+		0, 0, 47, 9, 1, 0, 0, 48, 12, 1, 0, 0, 49, 2, 1, 0, 0, 50,
+		2, 1, 0, 0, 51, 13, 1, 0, 0, 52, 9, 1, 0, 0, 53, 2, 1, 0,
+		0, 54, 14, 1, 0, 0, 55, 9, 1, 0, 0, 56, 15, 1, 2, 0, 0, 0,
+		57, 16, 17, 0, 0, 58, 2, 1, 0, 0, 59, 8, 1, 0, 4, 78, 9, 20,
+		0, 2, 79, 2, 25, 1, 0, 5, 80, 29, 30, 0, 1, 81, 9, 32, 1, 6,
+		3, 82, 33, 1, 1, 6, 7, 84, 1, 38, 1, 0, 7, 85, 39, 20, 0, 6,
+		86, 1, 43, 2, 3, 4, 6, 87, 44, 45, 2, 3, 0, 6, 88, 46, 1, 2,
+		3, 0, 6, 28, 44, 52, 2, 3, 0, 2, 62, 58, 17, 1, 0, 2, 89, 59,
+		1, 1, 0, 2, 90, 20, 45, 1, 0, 2, 91, 9, 1, 1, 0, 2, 92, 60,
+		25, 1, 0, 2, 93, 61, 1, 1, 0, 6, 94, 46, 1, 2, 3, 2, 34, 24,
+		34, 26, 36, 31, 37, 31, 38, 37, 40, 42, 41, 42, 42, 42, 38, 51, 43, 42,
+		44, 24, 44, 26, 45, 24, 46, 26, 47, 26, 48, 26, 49, 26, 45, 26, 46, 24,
+		47, 24, 48, 24, 49, 24, 50, 42, 2, 7, 10, 2, 6, 10, 2, 0, 2, 6,
+		12, 3, 2, 5, 3, 2, 3, 8, 9, 2, 6, 12, 10, 2, 4, 6, 8, 13,
+		6, 8, 13, 6, 8, 1, 6, 8, 1, 1, 6, 3, 3, 6, 12, 3, 3, 1,
+		6, 12, 4, 6, 12, 3, 8, 18, 10, 2, 2, 6, 12, 5, 2, 6, 12, 6,
+		12, 3, 5, 3, 8, 9, 2, 6, 12, 8, 18, 5, 6, 12, 5, 3, 1, 3,
+		3, 3, 3, 3, 1, 3, 1, 2, 3, 7, 10, 3, 3, 3, 1, 5, 1, 7,
+		8, 8, 2, 7, 8, 0, 8, 0, 3, 6, 3, 6, 3, 6, 3, 1, 9, 0,
+		1, 11, 19, 1, 9, 0, 1, 9, 1, 2, 9, 0, 9, 1, 3, 3, 8, 13,
+		7, 8, 13, 1, 10, 2, 1, 8, 18, 1, 8, 10, 1, 11, 20, 1, 9, 0,
+		2, 7, 11, 20, 1, 9, 0, 9, 0, 5, 3, 3, 5, 7, 8, 8, 8, 9,
+		3, 10, 3, 10, 3, 3, 4, 8, 22, 3, 5, 3, 1, 8, 1, 1, 8, 22,
+		1, 6, 8, 22, 3, 8, 22, 7, 8, 1, 3, 4, 3, 5, 3, 7, 11, 21,
+		2, 3, 3, 2, 3, 3, 1, 11, 21, 2, 9, 0, 9, 1, 2, 6, 11, 21,
+		2, 9, 0, 9, 1, 9, 0, 1, 1, 3, 7, 11, 21, 2, 9, 0, 9, 1,
+		9, 0, 9, 1, 3, 8, 2, 7, 8, 13, 8, 13, 8, 1, 10, 2, 7, 8,
+		13, 10, 2, 3, 3, 8, 13, 7, 8, 13, 9, 3, 7, 8, 13, 3, 3, 3,
+		8, 13, 7, 8, 13, 6, 8, 13, 6, 8, 13, 4, 8, 22, 3, 6, 11, 21,
+		2, 3, 3, 3, 1, 8, 17, 1, 6, 9, 1, 2, 3, 7, 8, 8, 2, 7,
+		8, 13, 8, 13, 2, 8, 13, 7, 8, 13, 1, 7, 8, 1, 6, 3, 3, 7,
+		11, 7, 2, 9, 0, 9, 1, 3, 3, 5, 1, 6, 11, 19, 1, 9, 0, 2,
+		7, 11, 19, 1, 9, 0, 11, 19, 1, 9, 0, 2, 7, 11, 19, 1, 9, 0,
+		3, 2, 5, 11, 19, 1, 9, 0, 1, 4, 5, 5, 7, 8, 3, 7, 8, 4,
+		7, 8, 5, 7, 8, 6, 6, 83, 105, 109, 112, 108, 101, 7, 97, 99, 99, 111,
+		117, 110, 116, 4, 99, 111, 105, 110, 5, 101, 118, 101, 110, 116, 6, 115, 105, 103,
+		110, 101, 114, 6, 115, 116, 114, 105, 110, 103, 5, 116, 97, 98, 108, 101, 9, 116,
+		121, 112, 101, 95, 105, 110, 102, 111, 12, 66, 121, 116, 101, 82, 101, 115, 111, 117,
+		114, 99, 101, 7, 67, 111, 117, 110, 116, 101, 114, 4, 68, 97, 116, 97, 12, 71,
+		114, 111, 117, 112, 77, 101, 109, 98, 101, 114, 48, 12, 71, 114, 111, 117, 112, 77,
+		101, 109, 98, 101, 114, 49, 12, 71, 114, 111, 117, 112, 77, 101, 109, 98, 101, 114,
+		50, 12, 71, 114, 111, 117, 112, 77, 101, 109, 98, 101, 114, 51, 13, 76, 105, 113,
+		117, 105, 100, 105, 116, 121, 80, 111, 111, 108, 8, 76, 111, 97, 110, 80, 111, 111,
+		108, 11, 76, 111, 97, 110, 82, 101, 99, 101, 105, 112, 116, 11, 77, 97, 114, 107,
+		101, 114, 69, 118, 101, 110, 116, 12, 77, 97, 114, 107, 101, 114, 69, 118, 101, 110,
+		116, 115, 10, 80, 114, 105, 99, 101, 70, 101, 101, 100, 115, 8, 82, 101, 115, 111,
+		117, 114, 99, 101, 13, 82, 101, 115, 111, 117, 114, 99, 101, 71, 114, 111, 117, 112,
+		10, 83, 105, 109, 112, 108, 101, 67, 111, 105, 110, 14, 86, 101, 99, 116, 111, 114,
+		82, 101, 115, 111, 117, 114, 99, 101, 11, 87, 105, 100, 101, 82, 101, 97, 100, 83,
+		101, 116, 11, 97, 112, 112, 101, 110, 100, 95, 100, 97, 116, 97, 16, 97, 112, 112,
+		101, 110, 100, 95, 116, 111, 95, 118, 101, 99, 116, 111, 114, 6, 98, 111, 114, 114,
+		111, 119, 20, 98, 121, 116, 101, 115, 95, 109, 97, 107, 101, 95, 111, 114, 95, 99,
+		104, 97, 110, 103, 101, 14, 99, 111, 112, 121, 95, 112, 97, 115, 116, 97, 95, 114,
+		101, 102, 11, 99, 114, 101, 97, 116, 101, 95, 112, 111, 111, 108, 6, 100, 111, 117,
+		98, 108, 101, 11, 101, 109, 105, 116, 95, 109, 97, 114, 107, 101, 114, 10, 102, 108,
+		97, 115, 104, 95, 108, 111, 97, 110, 11, 103, 101, 116, 95, 99, 111, 117, 110, 116,
+		101, 114, 21, 103, 101, 116, 95, 102, 114, 111, 109, 95, 114, 97, 110, 100, 111, 109,
+		95, 99, 111, 110, 115, 116, 4, 104, 97, 108, 102, 10, 104, 101, 97, 118, 121, 95,
+		114, 101, 97, 100, 24, 105, 110, 99, 114, 101, 109, 101, 110, 116, 95, 115, 104, 97,
+		114, 101, 100, 95, 99, 111, 117, 110, 116, 101, 114, 11, 105, 110, 105, 116, 95, 109,
+		111, 100, 117, 108, 101, 18, 105, 110, 105, 116, 95, 119, 105, 100, 101, 95, 114, 101,
+		97, 100, 95, 115, 101, 116, 5, 108, 111, 111, 112, 121, 6, 83, 116, 114, 105, 110,
+		103, 14, 109, 97, 107, 101, 95, 111, 114, 95, 99, 104, 97, 110, 103, 101, 8, 109,
+		97, 120, 105, 109, 105, 122, 101, 8, 109, 105, 110, 105, 109, 105, 122, 101, 3, 110,
+		111, 112, 13, 110, 111, 112, 95, 50, 95, 115, 105, 103, 110, 101, 114, 115, 14, 110,
+		111, 112, 95, 119, 105, 116, 104, 95, 110, 111, 110, 99, 101, 13, 114, 101, 97, 100,
+		95, 119, 105, 100, 101, 95, 115, 101, 116, 5, 114, 101, 112, 97, 121, 10, 114, 101,
+		115, 101, 116, 95, 100, 97, 116, 97, 6, 115, 101, 116, 95, 105, 100, 8, 115, 101,
+		116, 95, 110, 97, 109, 101, 4, 115, 116, 101, 112, 4, 115, 119, 97, 112, 15, 115,
+		119, 97, 112, 95, 97, 109, 111, 117, 110, 116, 95, 111, 117, 116, 20, 116, 111, 117,
+		99, 104, 95, 114, 101, 115, 111, 117, 114, 99, 101, 95, 103, 114, 111, 117, 112, 12,
+		117, 112, 100, 97, 116, 101, 95, 112, 114, 105, 99, 101, 4, 100, 97, 116, 97, 5,
+		99, 111, 117, 110, 116, 5, 118, 97, 108, 117, 101, 9, 114, 101, 115, 101, 114, 118,
+		101, 95, 120, 4, 67, 111, 105, 110, 9, 114, 101, 115, 101, 114, 118, 101, 95, 121,
+		7, 114, 101, 115, 101, 114, 118, 101, 10, 111, 112, 101, 114, 97, 116, 105, 111, 110,
+		115, 6, 97, 109, 111, 117, 110, 116, 6, 109, 97, 114, 107, 101, 114, 6, 101, 118,
+		101, 110, 116, 115, 11, 69, 118, 101, 110, 116, 72, 97, 110, 100, 108, 101, 6, 112,
+		114, 105, 99, 101, 115, 5, 84, 97, 98, 108, 101, 2, 105, 100, 4, 110, 97, 109,
+		101, 11, 100, 117, 109, 109, 121, 95, 102, 105, 101, 108, 100, 5, 105, 116, 101, 109,
+		115, 10, 97, 100, 100, 114, 101, 115, 115, 95, 111, 102, 8, 119, 105, 116, 104, 100,
+		114, 97, 119, 4, 117, 116, 102, 56, 16, 110, 101, 119, 95, 101, 118, 101, 110, 116,
+		95, 104, 97, 110, 100, 108, 101, 10, 101, 109, 105, 116, 95, 101, 118, 101, 110, 116,
+		8, 84, 121, 112, 101, 73, 110, 102, 111, 7, 116, 121, 112, 101, 95, 111, 102, 15,
+		97, 99, 99, 111, 117, 110, 116, 95, 97, 100, 100, 114, 101, 115, 115, 3, 110, 101,
+		119, 8, 99, 111, 110, 116, 97, 105, 110, 115, 3, 97, 100, 100, 5, 109, 101, 114,
+		103, 101, 21, 105, 115, 95, 97, 99, 99, 111, 117, 110, 116, 95, 114, 101, 103, 105,
+		115, 116, 101, 114, 101, 100, 8, 114, 101, 103, 105, 115, 116, 101, 114, 7, 101, 120,
+		116, 114, 97, 99, 116, 7, 100, 101, 112, 111, 115, 105, 116, 6, 117, 112, 115, 101,
+		114, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 205, 0, 0,
 		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 3, 8, 1, 0,
-		0, 0, 0, 0, 0, 0, 10, 2, 9, 8, 1, 35, 69, 103, 137, 171, 205, 239,
-		10, 2, 6, 5, 104, 101, 108, 108, 111, 10, 3, 81, 10, 0, 0, 0, 0, 0,
-		0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0,
-		0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 5,
-		0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0,
-		0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0,
-		0, 0, 0, 0, 2, 1, 25, 10, 2, 1, 2, 1, 26, 3, 2, 2, 1, 25,
-		10, 2, 3, 2, 3, 27, 3, 28, 8, 4, 25, 8, 2, 0, 0, 0, 0, 10,
-		26, 10, 1, 65, 11, 12, 2, 10, 2, 6, 0, 0, 0, 0, 0, 0, 0, 0,
-		36, 4, 21, 5, 8, 10, 0, 10, 1, 10, 2, 6, 1, 0, 0, 0, 0, 0,
-		0, 0, 23, 66, 11, 20, 68, 11, 11, 2, 6, 1, 0, 0, 0, 0, 0, 0,
-		0, 23, 12, 2, 5, 3, 11, 1, 1, 11, 0, 1, 2, 1, 1, 4, 1, 0,
-		12, 20, 10, 0, 17, 17, 41, 0, 4, 13, 11, 0, 17, 17, 42, 0, 12, 2,
-		11, 1, 11, 2, 15, 0, 21, 5, 19, 11, 1, 18, 0, 12, 3, 11, 0, 11,
-		3, 45, 0, 2, 2, 0, 0, 0, 14, 103, 10, 0, 16, 1, 12, 5, 10, 1,
-		16, 1, 12, 6, 11, 5, 20, 10, 6, 20, 35, 4, 18, 11, 6, 12, 5, 10,
-		2, 16, 2, 12, 6, 5, 26, 11, 6, 1, 10, 1, 16, 1, 12, 5, 10, 3,
-		16, 2, 12, 6, 10, 6, 20, 10, 1, 16, 1, 20, 35, 4, 47, 11, 5, 1,
-		11, 1, 1, 11, 0, 1, 11, 2, 1, 11, 6, 12, 5, 11, 3, 16, 2, 12,
-		6, 5, 69, 11, 3, 1, 10, 5, 11, 0, 16, 1, 34, 4, 65, 11, 6, 1,
-		11, 5, 1, 11, 2, 16, 2, 12, 5, 11, 1, 16, 1, 12, 6, 5, 69, 11,
-		1, 1, 11, 2, 1, 10, 5, 20, 10, 6, 20, 35, 4, 82, 11, 6, 1, 10,
-		5, 12, 6, 10, 5, 1, 5, 88, 11, 5, 1, 10, 6, 12, 5, 10, 6, 1,
-		10, 5, 10, 6, 33, 4, 97, 11, 6, 1, 11, 5, 12, 4, 5, 101, 11, 5,
-		1, 11, 6, 12, 4, 11, 4, 2, 3, 1, 4, 1, 3, 15, 44, 10, 0, 17,
-		17, 41, 3, 32, 4, 16, 6, 0, 0, 0, 0, 0, 0, 0, 0, 7, 2, 17,
-		18, 7, 1, 18, 2, 18, 3, 12, 2, 11, 0, 11, 2, 45, 3, 5, 43, 11,
-		0, 17, 17, 42, 3, 12, 3, 10, 3, 16, 3, 16, 4, 65, 11, 6, 2, 0,
-		0, 0, 0, 0, 0, 0, 24, 12, 1, 10, 3, 16, 3, 16, 4, 65, 11, 10,
-		1, 35, 4, 41, 5, 35, 10, 3, 15, 3, 15, 4, 49, 255, 68, 11, 5, 27,
-		11, 3, 1, 2, 4, 1, 4, 1, 1, 1, 7, 11, 0, 17, 17, 43, 1, 16,
-		2, 20, 1, 2, 5, 1, 4, 0, 18, 25, 7, 3, 12, 2, 14, 2, 65, 10,
-		12, 4, 10, 4, 6, 0, 0, 0, 0, 0, 0, 0, 0, 34, 4, 24, 10, 1,
-		10, 4, 38, 4, 17, 11, 4, 6, 1, 0, 0, 0, 0, 0, 0, 0, 23, 12,
-		1, 7, 3, 12, 3, 14, 3, 11, 1, 66, 10, 20, 1, 2, 6, 1, 4, 1,
-		3, 15, 44, 10, 0, 17, 17, 41, 3, 32, 4, 16, 6, 0, 0, 0, 0, 0,
-		0, 0, 0, 7, 2, 17, 18, 7, 1, 18, 2, 18, 3, 12, 2, 11, 0, 11,
-		2, 45, 3, 5, 43, 11, 0, 17, 17, 42, 3, 12, 3, 10, 3, 16, 3, 16,
-		4, 65, 11, 6, 2, 0, 0, 0, 0, 0, 0, 0, 26, 12, 1, 10, 3, 16,
-		3, 16, 4, 65, 11, 10, 1, 36, 4, 41, 5, 35, 10, 3, 15, 3, 15, 4,
-		69, 11, 1, 5, 27, 11, 3, 1, 2, 7, 0, 0, 0, 1, 5, 11, 0, 6,
-		0, 0, 0, 0, 0, 0, 0, 0, 18, 1, 45, 1, 2, 8, 1, 4, 0, 1,
-		11, 10, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 36, 4, 10, 5, 5, 11,
-		1, 6, 1, 0, 0, 0, 0, 0, 0, 0, 23, 12, 1, 5, 0, 2, 9, 1,
-		4, 1, 3, 19, 34, 10, 0, 17, 17, 41, 3, 4, 22, 11, 0, 17, 17, 42,
-		3, 12, 5, 11, 1, 10, 5, 15, 1, 21, 11, 2, 10, 5, 15, 5, 21, 11,
-		3, 11, 5, 15, 3, 15, 4, 21, 5, 33, 11, 3, 18, 2, 12, 4, 11, 1,
-		11, 2, 11, 4, 18, 3, 12, 6, 11, 0, 11, 6, 45, 3, 2, 10, 1, 4,
-		1, 3, 20, 93, 10, 1, 41, 3, 4, 6, 11, 0, 1, 2, 10, 0, 17, 17,
-		41, 3, 32, 4, 21, 6, 0, 0, 0, 0, 0, 0, 0, 0, 7, 2, 17, 18,
-		7, 1, 18, 2, 18, 3, 12, 8, 10, 0, 11, 8, 45, 3, 10, 0, 17, 17,
-		43, 3, 16, 3, 16, 4, 65, 11, 12, 6, 10, 1, 43, 3, 16, 3, 16, 4,
-		65, 11, 12, 7, 11, 6, 11, 7, 36, 4, 49, 11, 0, 17, 17, 43, 3, 16,
-		3, 16, 4, 20, 11, 1, 42, 3, 12, 4, 12, 3, 5, 59, 11, 1, 43, 3,
-		16, 3, 16, 4, 20, 11, 0, 17, 17, 42, 3, 12, 4, 12, 3, 11, 3, 11,
-		4, 12, 9, 12, 5, 14, 5, 65, 11, 10, 9, 16, 3, 16, 4, 65, 11, 36,
-		4, 75, 5, 72, 8, 12, 2, 5, 82, 10, 9, 16, 3, 16, 4, 65, 11, 6,
-		16, 39, 0, 0, 0, 0, 0, 0, 35, 12, 2, 11, 2, 4, 90, 10, 9, 15,
-		3, 15, 4, 14, 5, 17, 0, 5, 63, 11, 9, 1, 2, 11, 1, 4, 1, 3,
-		21, 81, 10, 1, 41, 3, 4, 6, 11, 0, 1, 2, 10, 0, 17, 17, 41, 3,
-		32, 4, 21, 6, 0, 0, 0, 0, 0, 0, 0, 0, 7, 2, 17, 18, 7, 1,
-		18, 2, 18, 3, 12, 7, 10, 0, 11, 7, 45, 3, 10, 0, 17, 17, 43, 3,
-		12, 9, 10, 1, 43, 3, 12, 10, 11, 9, 16, 3, 16, 4, 65, 11, 11, 10,
-		16, 3, 16, 4, 65, 11, 12, 5, 12, 4, 10, 4, 10, 5, 36, 4, 51, 11,
-		5, 6, 2, 0, 0, 0, 0, 0, 0, 0, 26, 11, 0, 17, 17, 42, 3, 12,
-		3, 12, 2, 5, 60, 11, 0, 1, 11, 4, 6, 2, 0, 0, 0, 0, 0, 0,
-		0, 26, 11, 1, 42, 3, 12, 3, 12, 2, 11, 2, 11, 3, 12, 8, 12, 6,
-		10, 8, 16, 3, 16, 4, 65, 11, 10, 6, 36, 4, 78, 5, 72, 10, 8, 15,
-		3, 15, 4, 69, 11, 1, 5, 64, 11, 8, 1, 2, 12, 1, 4, 0, 1, 1,
-		2, 13, 1, 4, 1, 3, 22, 34, 10, 0, 17, 17, 41, 3, 4, 23, 11, 0,
-		17, 17, 42, 3, 12, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 10, 1, 15,
-		1, 21, 7, 2, 17, 18, 10, 1, 15, 5, 21, 7, 1, 11, 1, 15, 3, 15,
-		4, 21, 5, 33, 6, 0, 0, 0, 0, 0, 0, 0, 0, 7, 2, 17, 18, 7,
-		1, 18, 2, 18, 3, 12, 2, 11, 0, 11, 2, 45, 3, 2, 14, 1, 4, 1,
-		3, 23, 25, 10, 0, 17, 17, 41, 3, 32, 4, 16, 11, 1, 7, 2, 17, 18,
-		7, 1, 18, 2, 18, 3, 12, 2, 11, 0, 11, 2, 45, 3, 5, 24, 11, 0,
-		17, 17, 42, 3, 12, 3, 11, 1, 11, 3, 15, 1, 21, 2, 15, 1, 4, 1,
-		3, 23, 24, 10, 0, 17, 17, 41, 3, 32, 4, 15, 6, 0, 0, 0, 0, 0,
-		0, 0, 0, 11, 1, 7, 1, 18, 2, 18, 3, 12, 2, 11, 0, 11, 2, 45,
-		3, 5, 23, 11, 0, 17, 17, 42, 3, 12, 3, 11, 1, 11, 3, 15, 5, 21,
-		2, 16, 1, 4, 1, 1, 24, 13, 11, 0, 17, 17, 42, 1, 12, 1, 10, 1,
-		16, 2, 20, 7, 0, 22, 11, 1, 15, 2, 21, 2, 0, 0, 3, 0, 1, 0,
-		3, 2, 2, 0, 3, 1, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 3, 8, 1, 0, 0, 0,
+		0, 0, 0, 0, 10, 2, 9, 8, 1, 35, 69, 103, 137, 171, 205, 239, 10, 2,
+		6, 5, 104, 101, 108, 108, 111, 10, 3, 81, 10, 0, 0, 0, 0, 0, 0, 0,
+		0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3,
+		0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0,
+		0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0,
+		0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0,
+		0, 18, 97, 112, 116, 111, 115, 58, 58, 109, 101, 116, 97, 100, 97, 116, 97, 95,
+		118, 49, 211, 1, 0, 5, 12, 71, 114, 111, 117, 112, 77, 101, 109, 98, 101, 114,
+		48, 1, 3, 1, 29, 48, 120, 97, 98, 99, 100, 58, 58, 83, 105, 109, 112, 108,
+		101, 58, 58, 82, 101, 115, 111, 117, 114, 99, 101, 71, 114, 111, 117, 112, 12, 71,
+		114, 111, 117, 112, 77, 101, 109, 98, 101, 114, 49, 1, 3, 1, 29, 48, 120, 97,
+		98, 99, 100, 58, 58, 83, 105, 109, 112, 108, 101, 58, 58, 82, 101, 115, 111, 117,
+		114, 99, 101, 71, 114, 111, 117, 112, 12, 71, 114, 111, 117, 112, 77, 101, 109, 98,
+		101, 114, 50, 1, 3, 1, 29, 48, 120, 97, 98, 99, 100, 58, 58, 83, 105, 109,
+		112, 108, 101, 58, 58, 82, 101, 115, 111, 117, 114, 99, 101, 71, 114, 111, 117, 112,
+		12, 71, 114, 111, 117, 112, 77, 101, 109, 98, 101, 114, 51, 1, 3, 1, 29, 48,
+		120, 97, 98, 99, 100, 58, 58, 83, 105, 109, 112, 108, 101, 58, 58, 82, 101, 115,
+		111, 117, 114, 99, 101, 71, 114, 111, 117, 112, 13, 82, 101, 115, 111, 117, 114, 99,
+		101, 71, 114, 111, 117, 112, 1, 2, 1, 6, 103, 108, 111, 98, 97, 108, 0, 0,
+		2, 1, 60, 10, 2, 1, 2, 1, 61, 3, 2, 2, 1, 60, 10, 2, 3, 2,
+		1, 62, 3, 4, 2, 1, 62, 3, 5, 2, 1, 62, 3, 6, 2, 1, 62, 3,
+		7, 2, 2, 63, 11, 19, 1, 9, 0, 65, 11, 19, 1, 9, 1, 8, 2, 2,
+		66, 3, 67, 3, 9, 2, 1, 68, 3, 10, 2, 1, 69, 3, 11, 2, 1, 70,
+		11, 20, 1, 8, 10, 12, 2, 1, 72, 11, 21, 2, 3, 3, 13, 2, 3, 74,
+		3, 75, 8, 18, 60, 8, 2, 14, 2, 1, 76, 1, 15, 2, 1, 76, 1, 16,
+		2, 1, 60, 10, 3, 17, 2, 1, 77, 11, 21, 2, 3, 3, 7, 27, 0, 0,
+		0, 0, 17, 26, 10, 1, 65, 18, 12, 2, 10, 2, 6, 0, 0, 0, 0, 0,
+		0, 0, 0, 36, 4, 21, 5, 8, 10, 0, 10, 1, 10, 2, 6, 1, 0, 0,
+		0, 0, 0, 0, 0, 23, 66, 18, 20, 68, 18, 11, 2, 6, 1, 0, 0, 0,
+		0, 0, 0, 0, 23, 12, 2, 5, 3, 11, 1, 1, 11, 0, 1, 2, 1, 1,
+		4, 1, 16, 19, 38, 10, 0, 17, 33, 41, 16, 32, 4, 9, 10, 0, 64, 17,
+		0, 0, 0, 0, 0, 0, 0, 0, 18, 16, 45, 16, 11, 0, 17, 33, 42, 16,
+		15, 0, 12, 2, 10, 2, 46, 65, 17, 12, 4, 6, 0, 0, 0, 0, 0, 0,
+		0, 0, 12, 3, 10, 3, 10, 1, 35, 4, 35, 5, 25, 10, 2, 10, 4, 10,
+		3, 22, 68, 17, 11, 3, 6, 1, 0, 0, 0, 0, 0, 0, 0, 22, 12, 3,
+		5, 20, 11, 2, 1, 2, 2, 1, 0, 1, 8, 21, 26, 11, 0, 42, 8, 12,
+		2, 10, 2, 16, 1, 20, 10, 1, 38, 4, 10, 5, 14, 11, 2, 1, 6, 1,
+		0, 0, 0, 0, 0, 0, 0, 39, 10, 2, 16, 1, 20, 10, 1, 23, 11, 2,
+		15, 1, 21, 10, 1, 11, 1, 18, 9, 2, 3, 1, 4, 1, 0, 22, 20, 10,
+		0, 17, 33, 41, 0, 4, 13, 11, 0, 17, 33, 42, 0, 12, 2, 11, 1, 11,
+		2, 15, 2, 21, 5, 19, 11, 1, 18, 0, 12, 3, 11, 0, 11, 3, 45, 0,
+		2, 4, 0, 0, 0, 23, 103, 10, 0, 16, 3, 12, 5, 10, 1, 16, 3, 12,
+		6, 11, 5, 20, 10, 6, 20, 35, 4, 18, 11, 6, 12, 5, 10, 2, 16, 4,
+		12, 6, 5, 26, 11, 6, 1, 10, 1, 16, 3, 12, 5, 10, 3, 16, 4, 12,
+		6, 10, 6, 20, 10, 1, 16, 3, 20, 35, 4, 47, 11, 5, 1, 11, 1, 1,
+		11, 0, 1, 11, 2, 1, 11, 6, 12, 5, 11, 3, 16, 4, 12, 6, 5, 69,
+		11, 3, 1, 10, 5, 11, 0, 16, 3, 34, 4, 65, 11, 6, 1, 11, 5, 1,
+		11, 2, 16, 4, 12, 5, 11, 1, 16, 3, 12, 6, 5, 69, 11, 1, 1, 11,
+		2, 1, 10, 5, 20, 10, 6, 20, 35, 4, 82, 11, 6, 1, 10, 5, 12, 6,
+		10, 5, 1, 5, 88, 11, 5, 1, 10, 6, 12, 5, 10, 6, 1, 10, 5, 10,
+		6, 33, 4, 97, 11, 6, 1, 11, 5, 12, 4, 5, 101, 11, 5, 1, 11, 6,
+		12, 4, 11, 4, 2, 5, 1, 4, 0, 1, 10, 10, 0, 10, 0, 11, 1, 56,
+		0, 11, 0, 11, 2, 56, 1, 57, 0, 63, 0, 2, 6, 1, 4, 1, 13, 28,
+		44, 10, 0, 17, 33, 41, 13, 32, 4, 16, 6, 0, 0, 0, 0, 0, 0, 0,
+		0, 7, 2, 17, 35, 7, 1, 18, 2, 18, 13, 12, 2, 11, 0, 11, 2, 45,
+		13, 5, 43, 11, 0, 17, 33, 42, 13, 12, 3, 10, 3, 16, 5, 16, 6, 65,
+		18, 6, 2, 0, 0, 0, 0, 0, 0, 0, 24, 12, 1, 10, 3, 16, 5, 16,
+		6, 65, 18, 10, 1, 35, 4, 41, 5, 35, 10, 3, 15, 5, 15, 6, 49, 255,
+		68, 18, 5, 27, 11, 3, 1, 2, 7, 1, 4, 1, 11, 1, 18, 10, 0, 17,
+		33, 41, 11, 32, 4, 10, 10, 0, 10, 0, 56, 2, 18, 11, 45, 11, 11, 0,
+		17, 33, 42, 11, 15, 7, 11, 1, 18, 10, 56, 3, 2, 8, 1, 4, 1, 8,
+		34, 52, 10, 0, 17, 33, 12, 4, 10, 4, 41, 8, 32, 4, 13, 11, 0, 6,
+		64, 66, 15, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 18,
+		8, 45, 8, 5, 15, 11, 0, 1, 10, 4, 6, 232, 3, 0, 0, 0, 0, 0,
+		0, 17, 2, 12, 6, 12, 2, 6, 0, 0, 0, 0, 0, 0, 0, 0, 12, 3,
+		10, 3, 10, 1, 35, 4, 47, 5, 27, 11, 2, 6, 1, 0, 0, 0, 0, 0,
+		0, 0, 22, 12, 2, 10, 4, 42, 8, 12, 5, 10, 5, 16, 8, 20, 6, 1,
+		0, 0, 0, 0, 0, 0, 0, 22, 11, 5, 15, 8, 21, 11, 3, 6, 1, 0,
+		0, 0, 0, 0, 0, 0, 22, 12, 3, 5, 22, 11, 4, 11, 2, 11, 6, 17,
+		24, 2, 9, 1, 4, 1, 1, 1, 7, 11, 0, 17, 33, 43, 1, 16, 4, 20,
+		1, 2, 10, 1, 4, 0, 35, 25, 7, 3, 12, 2, 14, 2, 65, 17, 12, 4,
+		10, 4, 6, 0, 0, 0, 0, 0, 0, 0, 0, 34, 4, 24, 10, 1, 10, 4,
+		38, 4, 17, 11, 4, 6, 1, 0, 0, 0, 0, 0, 0, 0, 23, 12, 1, 7,
+		3, 12, 3, 14, 3, 11, 1, 66, 17, 20, 1, 2, 11, 1, 4, 1, 13, 28,
+		44, 10, 0, 17, 33, 41, 13, 32, 4, 16, 6, 0, 0, 0, 0, 0, 0, 0,
+		0, 7, 2, 17, 35, 7, 1, 18, 2, 18, 13, 12, 2, 11, 0, 11, 2, 45,
+		13, 5, 43, 11, 0, 17, 33, 42, 13, 12, 3, 10, 3, 16, 5, 16, 6, 65,
+		18, 6, 2, 0, 0, 0, 0, 0, 0, 0, 26, 12, 1, 10, 3, 16, 5, 16,
+		6, 65, 18, 10, 1, 36, 4, 41, 5, 35, 10, 3, 15, 5, 15, 6, 69, 18,
+		1, 5, 27, 11, 3, 1, 2, 12, 1, 4, 1, 1, 36, 29, 56, 4, 12, 2,
+		14, 2, 17, 39, 12, 4, 6, 0, 0, 0, 0, 0, 0, 0, 0, 12, 5, 6,
+		0, 0, 0, 0, 0, 0, 0, 0, 12, 3, 10, 3, 10, 1, 35, 4, 26, 5,
+		14, 11, 5, 10, 4, 43, 1, 16, 4, 20, 22, 12, 5, 11, 3, 6, 1, 0,
+		0, 0, 0, 0, 0, 0, 22, 12, 3, 5, 9, 11, 5, 1, 2, 13, 1, 4,
+		1, 1, 40, 29, 56, 4, 12, 2, 14, 2, 17, 39, 42, 1, 12, 3, 6, 0,
+		0, 0, 0, 0, 0, 0, 0, 12, 4, 10, 4, 10, 1, 35, 4, 26, 5, 13,
+		10, 3, 16, 4, 20, 6, 1, 0, 0, 0, 0, 0, 0, 0, 22, 10, 3, 15,
+		4, 21, 11, 4, 6, 1, 0, 0, 0, 0, 0, 0, 0, 22, 12, 4, 5, 8,
+		11, 3, 1, 2, 14, 0, 0, 0, 1, 5, 11, 0, 6, 0, 0, 0, 0, 0,
+		0, 0, 0, 18, 1, 45, 1, 2, 15, 1, 4, 1, 17, 41, 47, 10, 0, 17,
+		33, 12, 4, 10, 4, 41, 17, 32, 4, 12, 11, 0, 56, 5, 18, 17, 45, 17,
+		5, 14, 11, 0, 1, 11, 4, 42, 17, 15, 9, 12, 6, 10, 1, 12, 5, 10,
+		5, 10, 1, 10, 2, 22, 35, 4, 44, 5, 27, 10, 6, 10, 5, 12, 3, 46,
+		11, 3, 56, 6, 32, 4, 39, 10, 6, 10, 5, 10, 5, 56, 7, 11, 5, 6,
+		1, 0, 0, 0, 0, 0, 0, 0, 22, 12, 5, 5, 20, 11, 6, 1, 2, 16,
+		1, 4, 0, 1, 11, 10, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 36, 4,
+		10, 5, 5, 11, 1, 6, 1, 0, 0, 0, 0, 0, 0, 0, 23, 12, 1, 5,
+		0, 2, 17, 1, 4, 1, 13, 47, 34, 10, 0, 17, 33, 41, 13, 4, 22, 11,
+		0, 17, 33, 42, 13, 12, 5, 11, 1, 10, 5, 15, 3, 21, 11, 2, 10, 5,
+		15, 10, 21, 11, 3, 11, 5, 15, 5, 15, 6, 21, 5, 33, 11, 3, 18, 2,
+		12, 4, 11, 1, 11, 2, 11, 4, 18, 13, 12, 6, 11, 0, 11, 6, 45, 13,
+		2, 18, 1, 4, 1, 13, 48, 93, 10, 1, 41, 13, 4, 6, 11, 0, 1, 2,
+		10, 0, 17, 33, 41, 13, 32, 4, 21, 6, 0, 0, 0, 0, 0, 0, 0, 0,
+		7, 2, 17, 35, 7, 1, 18, 2, 18, 13, 12, 8, 10, 0, 11, 8, 45, 13,
+		10, 0, 17, 33, 43, 13, 16, 5, 16, 6, 65, 18, 12, 6, 10, 1, 43, 13,
+		16, 5, 16, 6, 65, 18, 12, 7, 11, 6, 11, 7, 36, 4, 49, 11, 0, 17,
+		33, 43, 13, 16, 5, 16, 6, 20, 11, 1, 42, 13, 12, 4, 12, 3, 5, 59,
+		11, 1, 43, 13, 16, 5, 16, 6, 20, 11, 0, 17, 33, 42, 13, 12, 4, 12,
+		3, 11, 3, 11, 4, 12, 9, 12, 5, 14, 5, 65, 18, 10, 9, 16, 5, 16,
+		6, 65, 18, 36, 4, 75, 5, 72, 8, 12, 2, 5, 82, 10, 9, 16, 5, 16,
+		6, 65, 18, 6, 16, 39, 0, 0, 0, 0, 0, 0, 35, 12, 2, 11, 2, 4,
+		90, 10, 9, 15, 5, 15, 6, 14, 5, 17, 0, 5, 63, 11, 9, 1, 2, 19,
+		1, 4, 1, 13, 49, 81, 10, 1, 41, 13, 4, 6, 11, 0, 1, 2, 10, 0,
+		17, 33, 41, 13, 32, 4, 21, 6, 0, 0, 0, 0, 0, 0, 0, 0, 7, 2,
+		17, 35, 7, 1, 18, 2, 18, 13, 12, 7, 10, 0, 11, 7, 45, 13, 10, 0,
+		17, 33, 43, 13, 12, 9, 10, 1, 43, 13, 12, 10, 11, 9, 16, 5, 16, 6,
+		65, 18, 11, 10, 16, 5, 16, 6, 65, 18, 12, 5, 12, 4, 10, 4, 10, 5,
+		36, 4, 51, 11, 5, 6, 2, 0, 0, 0, 0, 0, 0, 0, 26, 11, 0, 17,
+		33, 42, 13, 12, 3, 12, 2, 5, 60, 11, 0, 1, 11, 4, 6, 2, 0, 0,
+		0, 0, 0, 0, 0, 26, 11, 1, 42, 13, 12, 3, 12, 2, 11, 2, 11, 3,
+		12, 8, 12, 6, 10, 8, 16, 5, 16, 6, 65, 18, 10, 6, 36, 4, 78, 5,
+		72, 10, 8, 15, 5, 15, 6, 69, 18, 1, 5, 64, 11, 8, 1, 2, 20, 1,
+		4, 0, 1, 1, 2, 21, 1, 4, 0, 1, 1, 2, 22, 1, 4, 0, 1, 1,
+		2, 23, 1, 4, 1, 17, 50, 33, 56, 8, 12, 2, 14, 2, 17, 39, 43, 17,
+		16, 9, 12, 4, 6, 0, 0, 0, 0, 0, 0, 0, 0, 12, 5, 6, 0, 0,
+		0, 0, 0, 0, 0, 0, 12, 3, 10, 3, 10, 1, 35, 4, 28, 5, 16, 11,
+		5, 10, 4, 10, 3, 56, 9, 20, 22, 12, 5, 11, 3, 6, 1, 0, 0, 0,
+		0, 0, 0, 0, 22, 12, 3, 5, 11, 11, 4, 1, 11, 5, 1, 2, 24, 1,
+		0, 1, 8, 53, 22, 11, 2, 19, 9, 12, 3, 10, 1, 11, 3, 38, 4, 8,
+		5, 10, 6, 2, 0, 0, 0, 0, 0, 0, 0, 39, 11, 0, 42, 8, 12, 4,
+		10, 4, 16, 1, 20, 11, 1, 22, 11, 4, 15, 1, 21, 2, 25, 1, 4, 1,
+		13, 54, 34, 10, 0, 17, 33, 41, 13, 4, 23, 11, 0, 17, 33, 42, 13, 12,
+		1, 6, 0, 0, 0, 0, 0, 0, 0, 0, 10, 1, 15, 3, 21, 7, 2, 17,
+		35, 10, 1, 15, 10, 21, 7, 1, 11, 1, 15, 5, 15, 6, 21, 5, 33, 6,
+		0, 0, 0, 0, 0, 0, 0, 0, 7, 2, 17, 35, 7, 1, 18, 2, 18, 13,
+		12, 2, 11, 0, 11, 2, 45, 13, 2, 26, 1, 4, 1, 13, 55, 25, 10, 0,
+		17, 33, 41, 13, 32, 4, 16, 11, 1, 7, 2, 17, 35, 7, 1, 18, 2, 18,
+		13, 12, 2, 11, 0, 11, 2, 45, 13, 5, 24, 11, 0, 17, 33, 42, 13, 12,
+		3, 11, 1, 11, 3, 15, 3, 21, 2, 27, 1, 4, 1, 13, 55, 24, 10, 0,
+		17, 33, 41, 13, 32, 4, 15, 6, 0, 0, 0, 0, 0, 0, 0, 0, 11, 1,
+		7, 1, 18, 2, 18, 13, 12, 2, 11, 0, 11, 2, 45, 13, 5, 23, 11, 0,
+		17, 33, 42, 13, 12, 3, 11, 1, 11, 3, 15, 10, 21, 2, 28, 1, 4, 1,
+		1, 56, 13, 11, 0, 17, 33, 42, 1, 12, 1, 10, 1, 16, 4, 20, 7, 0,
+		22, 11, 1, 15, 4, 21, 2, 29, 1, 4, 1, 7, 57, 92, 10, 0, 17, 33,
+		12, 10, 11, 1, 60, 0, 12, 7, 10, 7, 55, 0, 56, 10, 12, 8, 10, 7,
+		55, 1, 56, 11, 12, 9, 11, 3, 4, 54, 10, 2, 11, 8, 11, 9, 17, 30,
+		12, 5, 10, 5, 11, 4, 38, 4, 26, 5, 32, 11, 0, 1, 11, 7, 1, 6,
+		3, 0, 0, 0, 0, 0, 0, 0, 39, 10, 7, 54, 0, 10, 0, 11, 2, 56,
+		0, 56, 12, 10, 10, 56, 13, 32, 4, 45, 11, 0, 56, 14, 5, 47, 11, 0,
+		1, 11, 10, 11, 7, 54, 1, 11, 5, 56, 15, 56, 16, 5, 91, 10, 2, 11,
+		9, 11, 8, 17, 30, 12, 6, 10, 6, 11, 4, 38, 4, 64, 5, 70, 11, 0,
+		1, 11, 7, 1, 6, 3, 0, 0, 0, 0, 0, 0, 0, 39, 10, 7, 54, 1,
+		10, 0, 11, 2, 56, 1, 56, 17, 10, 10, 56, 18, 32, 4, 83, 11, 0, 56,
+		19, 5, 85, 11, 0, 1, 11, 10, 11, 7, 54, 0, 11, 6, 56, 20, 56, 21,
+		2, 30, 0, 0, 0, 62, 14, 11, 0, 53, 12, 3, 10, 3, 11, 2, 53, 24,
+		11, 1, 53, 11, 3, 22, 26, 52, 2, 31, 1, 4, 4, 3, 4, 5, 6, 63,
+		101, 10, 0, 17, 33, 12, 2, 10, 1, 6, 0, 0, 0, 0, 0, 0, 0, 0,
+		36, 4, 26, 10, 2, 41, 3, 4, 22, 10, 2, 42, 3, 12, 3, 10, 3, 16,
+		13, 20, 6, 1, 0, 0, 0, 0, 0, 0, 0, 22, 11, 3, 15, 13, 21, 5,
+		26, 10, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 18, 3, 45, 3, 10, 1,
+		6, 1, 0, 0, 0, 0, 0, 0, 0, 36, 4, 49, 10, 2, 41, 4, 4, 45,
+		10, 2, 42, 4, 12, 4, 10, 4, 16, 14, 20, 6, 1, 0, 0, 0, 0, 0,
+		0, 0, 22, 11, 4, 15, 14, 21, 5, 49, 10, 0, 6, 0, 0, 0, 0, 0,
+		0, 0, 0, 18, 4, 45, 4, 10, 1, 6, 2, 0, 0, 0, 0, 0, 0, 0,
+		36, 4, 72, 10, 2, 41, 5, 4, 68, 10, 2, 42, 5, 12, 5, 10, 5, 16,
+		15, 20, 6, 1, 0, 0, 0, 0, 0, 0, 0, 22, 11, 5, 15, 15, 21, 5,
+		72, 10, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 18, 5, 45, 5, 11, 1,
+		6, 3, 0, 0, 0, 0, 0, 0, 0, 36, 4, 98, 10, 2, 41, 6, 4, 93,
+		11, 0, 1, 11, 2, 42, 6, 12, 6, 10, 6, 16, 16, 20, 6, 1, 0, 0,
+		0, 0, 0, 0, 0, 22, 11, 6, 15, 16, 21, 5, 97, 11, 0, 6, 0, 0,
+		0, 0, 0, 0, 0, 0, 18, 6, 45, 6, 5, 100, 11, 0, 1, 2, 32, 1,
+		4, 1, 12, 1, 17, 10, 0, 17, 33, 41, 12, 32, 4, 9, 10, 0, 56, 5,
+		18, 12, 45, 12, 11, 0, 17, 33, 42, 12, 15, 17, 11, 1, 11, 2, 56, 22,
+		2, 16, 0, 8, 0, 0, 0, 13, 0, 1, 0, 13, 2, 2, 0, 11, 0, 8,
+		1, 17, 0, 13, 1, 7, 0, 7, 1, 3, 0, 4, 0, 5, 0, 6, 0, 12,
+		0, 11, 27, 12, 27, 0,
 	]
 });
@@ -0,0 +1,76 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use rand::{rngs::StdRng, CryptoRng, RngCore, SeedableRng};
+use rand_core::impls;
+use serde::{Deserialize, Serialize};
+
+/// State of a `ResumableRng`, to be persisted and later restored with `ResumableRng::restore`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct RngSnapshot {
+    seed: [u8; 32],
+    words_used: u64,
+}
+
+/// RNG of the generators, whose state can be snapshotted and restored, so that a long run
+/// (e.g. one that crashed) can be resumed mid-stream, with identical subsequent output.
+///
+/// All randomness is drawn from the seeded `StdRng` one word at a time, so its state is fully
+/// determined by the seed and the number of words drawn, and restoring replays the draws.
+#[derive(Clone, Debug)]
+pub struct ResumableRng {
+    seed: [u8; 32],
+    words_used: u64,
+    rng: StdRng,
+}
+
+impl ResumableRng {
+    pub fn snapshot(&self) -> RngSnapshot {
+        RngSnapshot {
+            seed: self.seed,
+            words_used: self.words_used,
+        }
+    }
+
+    pub fn restore(snapshot: &RngSnapshot) -> Self {
+        let mut rng = Self::from_seed(snapshot.seed);
+        for _ in 0..snapshot.words_used {
+            rng.next_u32();
+        }
+        rng
+    }
+}
+
+impl SeedableRng for ResumableRng {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self {
+            seed,
+            words_used: 0,
+            rng: StdRng::from_seed(seed),
+        }
+    }
+}
+
+impl RngCore for ResumableRng {
+    fn next_u32(&mut self) -> u32 {
+        self.words_used += 1;
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for ResumableRng {}
@@ -0,0 +1,158 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{TransactionGenerator, TransactionGeneratorCreator};
+use aptos_sdk::types::{transaction::SignedTransaction, LocalAccount};
+use async_trait::async_trait;
+
+/// Interleaves its generators strictly round-robin, one transaction at a time, for a
+/// deterministic composition of blocks (unlike the weighted random picks of
+/// `PhasedTxnMixGenerator`). The cycle carries over from one call to the next.
+pub struct RoundRobinTxnGenerator {
+    generators: Vec<Box<dyn TransactionGenerator>>,
+    next: usize,
+}
+
+impl RoundRobinTxnGenerator {
+    pub fn new(generators: Vec<Box<dyn TransactionGenerator>>) -> Self {
+        assert!(!generators.is_empty(), "No generators to interleave");
+        Self {
+            generators,
+            next: 0,
+        }
+    }
+}
+
+impl TransactionGenerator for RoundRobinTxnGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
+        for account in accounts {
+            for _ in 0..transactions_per_account {
+                requests.extend(
+                    self.generators[self.next].generate_transactions(vec![&mut *account], 1),
+                );
+                self.next = (self.next + 1) % self.generators.len();
+            }
+        }
+        requests
+    }
+}
+
+pub struct RoundRobinTxnGeneratorCreator {
+    creators: Vec<Box<dyn TransactionGeneratorCreator>>,
+}
+
+impl RoundRobinTxnGeneratorCreator {
+    pub fn new(creators: Vec<Box<dyn TransactionGeneratorCreator>>) -> Self {
+        assert!(!creators.is_empty(), "No generator creators to interleave");
+        Self { creators }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for RoundRobinTxnGeneratorCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        let mut generators = Vec::with_capacity(self.creators.len());
+        for creator in &mut self.creators {
+            generators.push(creator.create_transaction_generator().await);
+        }
+        Box::new(RoundRobinTxnGenerator::new(generators))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resumable_rng::ResumableRng;
+    use aptos_sdk::{
+        bcs,
+        move_types::account_address::AccountAddress,
+        transaction_builder::{aptos_stdlib, TransactionFactory},
+        types::chain_id::ChainId,
+    };
+    use rand::SeedableRng;
+
+    /// Generates transfers of a fixed amount, identifying the generator.
+    struct FixedAmountGenerator {
+        amount: u64,
+    }
+
+    impl TransactionGenerator for FixedAmountGenerator {
+        fn generate_transactions(
+            &mut self,
+            accounts: Vec<&mut LocalAccount>,
+            transactions_per_account: usize,
+        ) -> Vec<SignedTransaction> {
+            let txn_factory = TransactionFactory::new(ChainId::test());
+            let mut requests = Vec::new();
+            for account in accounts {
+                for _ in 0..transactions_per_account {
+                    requests.push(account.sign_with_transaction_builder(txn_factory.payload(
+                        aptos_stdlib::aptos_coin_transfer(AccountAddress::ONE, self.amount),
+                    )));
+                }
+            }
+            requests
+        }
+    }
+
+    struct FixedAmountCreator {
+        amount: u64,
+    }
+
+    #[async_trait]
+    impl TransactionGeneratorCreator for FixedAmountCreator {
+        async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+            Box::new(FixedAmountGenerator {
+                amount: self.amount,
+            })
+        }
+    }
+
+    fn amounts(txns: &[SignedTransaction]) -> Vec<u64> {
+        txns.iter()
+            .map(|txn| {
+                let entry_function = txn.payload().clone().into_entry_function();
+                bcs::from_bytes(&entry_function.args()[1]).unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_round_robin_order() {
+        let mut creator = RoundRobinTxnGeneratorCreator::new(
+            (1..=3)
+                .map(|amount| {
+                    Box::new(FixedAmountCreator { amount }) as Box<dyn TransactionGeneratorCreator>
+                })
+                .collect(),
+        );
+        let mut generator = creator.create_transaction_generator().await;
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut accounts = (0..2)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 2);
+        assert_eq!(amounts(&txns), vec![1, 2, 3, 1]);
+        assert_eq!(
+            txns.iter()
+                .map(|txn| (txn.sender(), txn.sequence_number()))
+                .collect::<Vec<_>>(),
+            vec![
+                (accounts[0].address(), 0),
+                (accounts[0].address(), 1),
+                (accounts[1].address(), 0),
+                (accounts[1].address(), 1),
+            ]
+        );
+
+        // The cycle continues where the previous call left off
+        let txns = generator.generate_transactions(accounts.iter_mut().collect(), 1);
+        assert_eq!(amounts(&txns), vec![2, 3]);
+    }
+}
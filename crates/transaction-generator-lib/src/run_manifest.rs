@@ -0,0 +1,246 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    create_txn_generator_creator, gas_price_distribution::GasPriceDistribution, MixMode,
+    TransactionExecutor, TransactionGeneratorCreator, TransactionType, TransactionTypeCounters,
+};
+use anyhow::Result;
+use aptos_sdk::{
+    transaction_builder::TransactionFactory,
+    types::{chain_id::ChainId, LocalAccount},
+};
+use serde::{Deserialize, Serialize};
+use std::sync::{atomic::AtomicUsize, Arc};
+
+/// Settings of a `TransactionFactory`, enough to build an equivalent one.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FactorySettings {
+    pub chain_id: ChainId,
+    pub gas_unit_price: u64,
+    pub max_gas_amount: u64,
+    pub transaction_expiration_time: u64,
+}
+
+impl FactorySettings {
+    pub fn from_factory(txn_factory: &TransactionFactory) -> Self {
+        Self {
+            chain_id: txn_factory.get_chain_id(),
+            gas_unit_price: txn_factory.get_gas_unit_price(),
+            max_gas_amount: txn_factory.get_max_gas_amount(),
+            transaction_expiration_time: txn_factory.get_transaction_expiration_time(),
+        }
+    }
+
+    pub fn to_factory(&self) -> TransactionFactory {
+        TransactionFactory::new(self.chain_id)
+            .with_gas_unit_price(self.gas_unit_price)
+            .with_max_gas_amount(self.max_gas_amount)
+            .with_transaction_expiration_time(self.transaction_expiration_time)
+    }
+}
+
+/// Factories the generators were created with, before the minimum gas unit price of the
+/// network is enforced (which happens again when recreating the run).
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FactorySummary {
+    pub txn_factory: FactorySettings,
+    pub init_txn_factory: FactorySettings,
+    // gas unit prices of the generated transactions are sampled from it, if set
+    #[serde(default)]
+    pub gas_price_distribution: Option<GasPriceDistribution>,
+}
+
+/// Everything `create_txn_generator_creator` was called with, apart from the accounts and the
+/// executor, to export along with the results of a run, and recreate an equivalent one later.
+/// Generators holding a `ResumableRng`, and the accounts and packages created to set them up, are
+/// all seeded from `master_seed`, so the recreated ones produce the same transactions (gas
+/// included), given the same accounts.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RunManifest {
+    pub mix: Vec<Vec<(TransactionType, usize)>>,
+    pub mix_mode: MixMode,
+    pub master_seed: u64,
+    pub num_workers: usize,
+    pub factory_summary: FactorySummary,
+}
+
+impl RunManifest {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Run manifest must serialize")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Same as the `create_txn_generator_creator` call the manifest was produced by.
+    pub async fn create_txn_generator_creator(
+        &self,
+        all_accounts: &mut [LocalAccount],
+        txn_executor: &dyn TransactionExecutor,
+        cur_phase: Arc<AtomicUsize>,
+    ) -> Result<(
+        Box<dyn TransactionGeneratorCreator>,
+        TransactionTypeCounters,
+    )> {
+        let (creator, type_counters, _) = create_txn_generator_creator(
+            &self.mix,
+            self.num_workers,
+            all_accounts,
+            txn_executor,
+            &self.factory_summary.txn_factory.to_factory(),
+            &self.factory_summary.init_txn_factory.to_factory(),
+            self.factory_summary.gas_price_distribution,
+            cur_phase,
+            self.mix_mode,
+            self.master_seed,
+            None,
+        )
+        .await?;
+        Ok((creator, type_counters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        in_memory_executor::InMemoryExecutor, resumable_rng::ResumableRng, GasOverrides,
+        InvalidTransactionType,
+    };
+    use aptos_sdk::{
+        move_types::account_address::AccountAddress,
+        types::transaction::{SignedTransaction, TransactionPayload},
+    };
+    use rand::SeedableRng;
+
+    fn accounts() -> Vec<LocalAccount> {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        (0..8).map(|_| LocalAccount::generate(&mut rng)).collect()
+    }
+
+    fn manifest() -> RunManifest {
+        let txn_factory = TransactionFactory::new(ChainId::test()).with_gas_unit_price(100);
+        RunManifest {
+            mix: vec![vec![
+                (
+                    TransactionType::CoinTransfer {
+                        amount_range: (1, 1000),
+                        invalid_transaction_ratio: 0,
+                        invalid_kinds: vec![(InvalidTransactionType::Expired, 1)],
+                        sender_use_account_pool: false,
+                        account_pool: Some("senders".to_string()),
+                        gas_overrides: GasOverrides::default(),
+                    },
+                    3,
+                ),
+                (TransactionType::SelfTransfer { amount: 7 }, 1),
+            ]],
+            mix_mode: MixMode::Probabilistic,
+            master_seed: 42,
+            num_workers: 2,
+            factory_summary: FactorySummary {
+                txn_factory: FactorySettings::from_factory(&txn_factory),
+                init_txn_factory: FactorySettings::from_factory(
+                    &txn_factory.clone().with_transaction_expiration_time(60),
+                ),
+                gas_price_distribution: Some(GasPriceDistribution::UniformRange {
+                    low: 100,
+                    high: 200,
+                }),
+            },
+        }
+    }
+
+    #[test]
+    fn test_manifest_round_trip() {
+        let json = manifest().to_json();
+        let manifest = RunManifest::from_json(&json).unwrap();
+        assert_eq!(manifest.to_json(), json);
+        assert_eq!(manifest.master_seed, 42);
+        assert_eq!(
+            manifest
+                .factory_summary
+                .init_txn_factory
+                .transaction_expiration_time,
+            60
+        );
+        match &manifest.mix[0][0].0 {
+            TransactionType::CoinTransfer {
+                invalid_kinds,
+                account_pool,
+                ..
+            } => {
+                assert_eq!(invalid_kinds, &[(InvalidTransactionType::Expired, 1)]);
+                assert_eq!(account_pool.as_deref(), Some("senders"));
+            },
+            _ => panic!("Mix must keep its order"),
+        }
+    }
+
+    // Expiration timestamps depend on when the transactions are generated, so aren't compared
+    type TxnFields = (AccountAddress, u64, TransactionPayload, u64, u64);
+
+    fn fields(txn: &SignedTransaction) -> TxnFields {
+        (
+            txn.sender(),
+            txn.sequence_number(),
+            txn.payload().clone(),
+            txn.gas_unit_price(),
+            txn.max_gas_amount(),
+        )
+    }
+
+    /// Recreates the run from the manifest, and returns the transactions executed while setting
+    /// up the generators, along with the ones the generators then produce.
+    async fn run(manifest: &RunManifest) -> (Vec<TxnFields>, Vec<TxnFields>) {
+        let mut accounts = accounts();
+        let executor = InMemoryExecutor::default();
+        let (mut creator, _) = manifest
+            .create_txn_generator_creator(&mut accounts, &executor, Arc::new(AtomicUsize::new(0)))
+            .await
+            .unwrap();
+        let mut txns = Vec::new();
+        for _ in 0..manifest.num_workers {
+            let mut generator = creator.create_transaction_generator().await;
+            for _ in 0..10 {
+                txns.extend(generator.generate_transactions(accounts.iter_mut().collect(), 2));
+            }
+        }
+        let setup_txns = executor.executed.lock().iter().map(fields).collect();
+        (setup_txns, txns.iter().map(fields).collect())
+    }
+
+    #[tokio::test]
+    async fn test_same_manifest_same_transactions() {
+        let manifest = RunManifest::from_json(&manifest().to_json()).unwrap();
+        let (_, txns) = run(&manifest).await;
+        assert_eq!(txns.len(), 2 * 10 * 8 * 2);
+        // Gas unit prices are sampled from the distribution
+        assert!(txns
+            .iter()
+            .any(|(.., gas_unit_price, _)| *gas_unit_price != 100));
+        assert_eq!(run(&manifest).await.1, txns);
+    }
+
+    #[tokio::test]
+    async fn test_same_manifest_same_setup() {
+        let mut manifest = manifest();
+        manifest.mix = vec![vec![
+            (
+                TransactionType::Marketplace {
+                    listings_per_round: 4,
+                },
+                1,
+            ),
+            (TransactionType::DexSwap { pairs: 2 }, 1),
+        ]];
+        let manifest = RunManifest::from_json(&manifest.to_json()).unwrap();
+        let (setup_txns, txns) = run(&manifest).await;
+        assert!(!setup_txns.is_empty());
+        assert!(!txns.is_empty());
+        // Sellers and liquidity pools are created under the same accounts, with the same packages
+        assert_eq!(run(&manifest).await, (setup_txns, txns));
+    }
+}
@@ -0,0 +1,294 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::gas_price_distribution::GasPriceDistribution;
+use anyhow::{bail, Result};
+use aptos_sdk::{
+    transaction_builder::{TransactionBuilder, TransactionFactory},
+    types::{chain_id::ChainId, transaction::TransactionPayload},
+};
+use rand::{distributions::Uniform, Rng};
+use serde::{Deserialize, Serialize};
+use std::{
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Gas settings of a transaction type, overriding the ones of the shared transaction factory.
+/// Each is an inclusive range, from which a value is picked for every transaction.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+pub struct GasOverrides {
+    pub gas_unit_price: Option<(u64, u64)>,
+    pub max_gas_amount: Option<(u64, u64)>,
+}
+
+impl GasOverrides {
+    pub fn validate(&self) -> Result<()> {
+        if let Some((min, max)) = self.gas_unit_price {
+            if min > max {
+                bail!("Invalid gas unit price range {}..={}", min, max);
+            }
+        }
+        if let Some((min, max)) = self.max_gas_amount {
+            if min > max {
+                bail!("Invalid max gas amount range {}..={}", min, max);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn apply(&self, txn_factory: SharedTransactionFactory) -> SharedTransactionFactory {
+        let mut txn_factory = txn_factory;
+        if let Some((min, max)) = self.gas_unit_price {
+            txn_factory = txn_factory.with_gas_unit_price_range(min, max);
+        }
+        if let Some((min, max)) = self.max_gas_amount {
+            txn_factory = txn_factory.with_max_gas_amount_range(min, max);
+        }
+        txn_factory
+    }
+}
+
+/// Transaction factory shared by all generators, instead of each of them owning a copy.
+/// Gas settings for a single transaction type can be overridden on top of the shared base,
+/// without copying it, and cloning only bumps the reference count of the base.
+#[derive(Clone, Debug)]
+pub struct SharedTransactionFactory {
+    base: Arc<TransactionFactory>,
+    // inclusive ranges, a value is picked for every transaction
+    max_gas_amount: Option<(u64, u64)>,
+    gas_unit_price: Option<(u64, u64)>,
+    // gas unit price is sampled from it instead of using the base one, unless overridden
+    gas_price_distribution: Option<GasPriceDistribution>,
+    // floor of the gas unit price, whatever the base or the override
+    min_gas_unit_price: Option<u64>,
+    chain_id: Option<ChainId>,
+    // seconds from now, negative for transactions that are already expired
+    expiration_offset_secs: Option<i64>,
+}
+
+impl SharedTransactionFactory {
+    pub fn new(base: Arc<TransactionFactory>) -> Self {
+        Self {
+            base,
+            max_gas_amount: None,
+            gas_unit_price: None,
+            gas_price_distribution: None,
+            min_gas_unit_price: None,
+            chain_id: None,
+            expiration_offset_secs: None,
+        }
+    }
+
+    pub fn with_max_gas_amount(self, max_gas_amount: u64) -> Self {
+        self.with_max_gas_amount_range(max_gas_amount, max_gas_amount)
+    }
+
+    pub fn with_gas_unit_price(self, gas_unit_price: u64) -> Self {
+        self.with_gas_unit_price_range(gas_unit_price, gas_unit_price)
+    }
+
+    pub fn with_max_gas_amount_range(mut self, min: u64, max: u64) -> Self {
+        assert!(min <= max, "Invalid max gas amount range {}..={}", min, max);
+        self.max_gas_amount = Some((min, max));
+        self
+    }
+
+    pub fn with_gas_unit_price_range(mut self, min: u64, max: u64) -> Self {
+        assert!(min <= max, "Invalid gas unit price range {}..={}", min, max);
+        self.gas_unit_price = Some((min, max));
+        self
+    }
+
+    /// Samples the gas unit price of every transaction from the distribution, never going below
+    /// the gas unit price of the base.
+    pub fn with_gas_price_distribution(
+        mut self,
+        gas_price_distribution: GasPriceDistribution,
+    ) -> Self {
+        self.gas_price_distribution = Some(gas_price_distribution);
+        self
+    }
+
+    pub fn with_min_gas_unit_price(mut self, min_gas_unit_price: u64) -> Self {
+        self.min_gas_unit_price = Some(min_gas_unit_price);
+        self
+    }
+
+    pub fn with_chain_id(mut self, chain_id: ChainId) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Expires transactions that many seconds from now, instead of after the expiration time
+    /// of the base. Unlike the base, it can be negative, to build already expired transactions.
+    pub fn with_expiration_offset_secs(mut self, expiration_offset_secs: i64) -> Self {
+        self.expiration_offset_secs = Some(expiration_offset_secs);
+        self
+    }
+
+    pub fn base(&self) -> &Arc<TransactionFactory> {
+        &self.base
+    }
+
+    /// Gas ranges and the gas price distribution are sampled from `rng`, so that a generator
+    /// passing its own RNG builds the same transactions when seeded the same.
+    pub fn payload<R: Rng>(&self, payload: TransactionPayload, rng: &mut R) -> TransactionBuilder {
+        let mut builder = self.base.payload(payload);
+        if let Some(max_gas_amount) = self.max_gas_amount {
+            builder = builder.max_gas_amount(pick(max_gas_amount, rng));
+        }
+        let gas_unit_price = match (self.gas_unit_price, self.gas_price_distribution) {
+            (Some(gas_unit_price), _) => pick(gas_unit_price, rng),
+            (None, Some(distribution)) => distribution.sample(rng, self.base.get_gas_unit_price()),
+            (None, None) => self.base.get_gas_unit_price(),
+        };
+        builder = builder.gas_unit_price(
+            self.min_gas_unit_price
+                .map_or(gas_unit_price, |min| gas_unit_price.max(min)),
+        );
+        if let Some(chain_id) = self.chain_id {
+            builder = builder.chain_id(chain_id);
+        }
+        if let Some(expiration_offset_secs) = self.expiration_offset_secs {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs() as i64;
+            builder =
+                builder.expiration_timestamp_secs(
+                    now.saturating_add(expiration_offset_secs).max(0) as u64,
+                );
+        }
+        builder
+    }
+}
+
+fn pick<R: Rng>((min, max): (u64, u64), rng: &mut R) -> u64 {
+    if min == max {
+        min
+    } else {
+        rng.sample(Uniform::new_inclusive(min, max))
+    }
+}
+
+impl From<TransactionFactory> for SharedTransactionFactory {
+    fn from(base: TransactionFactory) -> Self {
+        Self::new(Arc::new(base))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resumable_rng::ResumableRng;
+    use aptos_sdk::{
+        transaction_builder::aptos_stdlib,
+        types::{account_address::AccountAddress, chain_id::ChainId, LocalAccount},
+    };
+    use rand::{thread_rng, SeedableRng};
+
+    fn build(txn_factory: &SharedTransactionFactory) -> (u64, u64) {
+        let txn = LocalAccount::generate(&mut thread_rng()).sign_with_transaction_builder(
+            txn_factory.payload(
+                aptos_stdlib::aptos_coin_transfer(AccountAddress::ONE, 1),
+                &mut thread_rng(),
+            ),
+        );
+        (txn.max_gas_amount(), txn.gas_unit_price())
+    }
+
+    #[test]
+    fn test_overrides_share_base() {
+        let base = SharedTransactionFactory::from(
+            TransactionFactory::new(ChainId::test())
+                .with_max_gas_amount(1_000)
+                .with_gas_unit_price(100),
+        );
+        let overridden = base
+            .clone()
+            .with_max_gas_amount(5_000)
+            .with_gas_unit_price(300);
+        let gas_only = base.clone().with_max_gas_amount(2_000);
+
+        assert!(Arc::ptr_eq(base.base(), overridden.base()));
+        assert!(Arc::ptr_eq(base.base(), gas_only.base()));
+        assert_eq!(build(&base), (1_000, 100));
+        assert_eq!(build(&overridden), (5_000, 300));
+        assert_eq!(build(&gas_only), (2_000, 100));
+    }
+
+    #[test]
+    fn test_gas_overrides_sampled_per_transaction() {
+        let base = SharedTransactionFactory::from(
+            TransactionFactory::new(ChainId::test())
+                .with_max_gas_amount(1_000)
+                .with_gas_unit_price(100),
+        );
+        let overridden = GasOverrides {
+            gas_unit_price: Some((100, 10_000)),
+            max_gas_amount: None,
+        }
+        .apply(base);
+
+        let built = (0..50).map(|_| build(&overridden)).collect::<Vec<_>>();
+        assert!(built.iter().all(|(max_gas_amount, gas_unit_price)| {
+            *max_gas_amount == 1_000 && (100..=10_000).contains(gas_unit_price)
+        }));
+        // Transactions paying more than the base price are produced, to test mempool ordering
+        assert!(built
+            .iter()
+            .any(|(_, gas_unit_price)| *gas_unit_price > 100));
+    }
+
+    #[test]
+    fn test_gas_sampled_from_rng() {
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()))
+            .with_max_gas_amount_range(1_000, 2_000)
+            .with_gas_price_distribution(GasPriceDistribution::UniformRange {
+                low: 100,
+                high: 10_000,
+            });
+        let mut account = LocalAccount::generate(&mut thread_rng());
+        let mut gas = |seed: u64| {
+            let mut rng = ResumableRng::seed_from_u64(seed);
+            (0..20)
+                .map(|_| {
+                    let txn = account.sign_with_transaction_builder(txn_factory.payload(
+                        aptos_stdlib::aptos_coin_transfer(AccountAddress::ONE, 1),
+                        &mut rng,
+                    ));
+                    (txn.max_gas_amount(), txn.gas_unit_price())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(gas(7), gas(7));
+        assert_ne!(gas(7), gas(8));
+    }
+
+    #[test]
+    fn test_expiration_offset() {
+        let base = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let mut account = LocalAccount::generate(&mut thread_rng());
+        let mut expiration = |txn_factory: SharedTransactionFactory| {
+            account
+                .sign_with_transaction_builder(txn_factory.payload(
+                    aptos_stdlib::aptos_coin_transfer(AccountAddress::ONE, 1),
+                    &mut thread_rng(),
+                ))
+                .expiration_timestamp_secs()
+        };
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let expired = expiration(base.clone().with_expiration_offset_secs(-60));
+        assert!((now - 60..now - 55).contains(&expired));
+        let expiring = expiration(base.clone().with_expiration_offset_secs(5));
+        assert!((now + 5..now + 10).contains(&expiring));
+        // The base expiration time is kept otherwise
+        assert!(expiration(base) >= now + 30);
+    }
+}
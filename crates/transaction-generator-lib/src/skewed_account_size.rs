@@ -0,0 +1,235 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{
+    publishing::{module_simple::EntryPoints, publish_util::Package},
+    TransactionExecutor,
+};
+use crate::{
+    publishing::publish_util::PackageHandler,
+    resumable_rng::{ResumableRng, RngSnapshot},
+    shared_txn_factory::SharedTransactionFactory,
+    TransactionGenerator, TransactionGeneratorCreator,
+};
+use aptos_logger::info;
+use aptos_sdk::{
+    move_types::account_address::AccountAddress,
+    types::{transaction::SignedTransaction, LocalAccount},
+};
+use async_trait::async_trait;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc};
+
+/// Pareto distribution of the number of elements held by an account, truncated to
+/// `[min_size, max_size]`: an account holds more than `x` elements with probability
+/// `(min_size / x) ^ shape`. The smaller the shape, the longer the tail (1.16 gives the
+/// "80-20 rule", where 20% of the accounts hold 80% of the elements).
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AccountSizeDistribution {
+    pub min_size: usize,
+    pub max_size: usize,
+    pub shape: f64,
+}
+
+impl AccountSizeDistribution {
+    pub fn sample(&self, rng: &mut ResumableRng) -> usize {
+        // inverse transform sampling, from a uniform value in (0, 1]
+        let uniform = 1.0 - rng.gen::<f64>();
+        let size = self.min_size as f64 / uniform.powf(1.0 / self.shape);
+        (size as usize).clamp(self.min_size, self.max_size)
+    }
+}
+
+/// Grows a vector stored in a resource under each account, up to a size picked for the account
+/// from `distribution` the first time it is seen, so that the state holds a long tail of account
+/// sizes: most accounts with few elements, some with many. Transactions append up to
+/// `pushes_per_txn` elements each, and accounts that reached their size don't send anything.
+pub struct SkewedAccountSizeGenerator {
+    rng: ResumableRng,
+    txn_factory: SharedTransactionFactory,
+    package: Arc<Package>,
+    distribution: AccountSizeDistribution,
+    pushes_per_txn: usize,
+    // (target size, size once all transactions commit) of each account
+    account_sizes: HashMap<AccountAddress, (usize, usize)>,
+}
+
+impl SkewedAccountSizeGenerator {
+    pub fn new(
+        rng: ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        package: Arc<Package>,
+        distribution: AccountSizeDistribution,
+        pushes_per_txn: usize,
+    ) -> Self {
+        assert!(
+            distribution.min_size <= distribution.max_size && distribution.shape > 0.0,
+            "Invalid account size distribution {:?}",
+            distribution
+        );
+        assert!(pushes_per_txn > 0, "Invalid pushes per transaction");
+        Self {
+            rng,
+            txn_factory,
+            package,
+            distribution,
+            pushes_per_txn,
+            account_sizes: HashMap::new(),
+        }
+    }
+
+    /// Size picked for the account, if it was seen already.
+    pub fn target_size(&self, address: &AccountAddress) -> Option<usize> {
+        self.account_sizes.get(address).map(|(target, _)| *target)
+    }
+}
+
+impl TransactionGenerator for SkewedAccountSizeGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        // not reserving for all, accounts near their size send less
+        let mut requests = Vec::new();
+        for account in accounts {
+            let (target, size) = self
+                .account_sizes
+                .entry(account.address())
+                .or_insert_with(|| (self.distribution.sample(&mut self.rng), 0));
+            for _ in 0..transactions_per_account {
+                let count = (*target - *size).min(self.pushes_per_txn);
+                if count == 0 {
+                    break;
+                }
+                requests.push(self.package.use_specific_transaction(
+                    EntryPoints::AppendToVector {
+                        count: count as u64,
+                    },
+                    account,
+                    &self.txn_factory,
+                    Some(&mut self.rng),
+                    None,
+                ));
+                *size += count;
+            }
+        }
+        requests
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        Some(self.rng.snapshot())
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.rng = ResumableRng::restore(snapshot);
+    }
+}
+
+pub struct SkewedAccountSizeCreator {
+    txn_factory: SharedTransactionFactory,
+    package: Arc<Package>,
+    distribution: AccountSizeDistribution,
+    pushes_per_txn: usize,
+}
+
+impl SkewedAccountSizeCreator {
+    pub async fn new(
+        rng: &mut ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        init_txn_factory: SharedTransactionFactory,
+        publisher: &mut LocalAccount,
+        txn_executor: &dyn TransactionExecutor,
+        distribution: AccountSizeDistribution,
+        pushes_per_txn: usize,
+    ) -> Self {
+        let mut package_handler = PackageHandler::new();
+        let package = package_handler.pick_package(rng, publisher);
+        let txn = package.publish_transaction(rng, publisher, &init_txn_factory);
+        info!("Publishing skewed account size package");
+        txn_executor.execute_transactions(&[txn]).await.unwrap();
+        info!("Done publishing skewed account size package");
+
+        Self {
+            txn_factory,
+            package: Arc::new(package),
+            distribution,
+            pushes_per_txn,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for SkewedAccountSizeCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(SkewedAccountSizeGenerator::new(
+            ResumableRng::from_entropy(),
+            self.txn_factory.clone(),
+            self.package.clone(),
+            self.distribution,
+            self.pushes_per_txn,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{bcs, transaction_builder::TransactionFactory, types::chain_id::ChainId};
+
+    #[test]
+    fn test_account_sizes_follow_skew() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut accounts = (0..2000)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let distribution = AccountSizeDistribution {
+            min_size: 10,
+            max_size: 10_000,
+            shape: 1.5,
+        };
+        let mut generator = SkewedAccountSizeGenerator::new(
+            rng,
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            Arc::new(Package::simple()),
+            distribution,
+            100,
+        );
+
+        // Enough transactions for most accounts to reach their size, then more than enough
+        let mut pushed = HashMap::new();
+        for transactions_per_account in [2, 1000] {
+            for txn in generator
+                .generate_transactions(accounts.iter_mut().collect(), transactions_per_account)
+            {
+                let entry_function = txn.payload().clone().into_entry_function();
+                assert_eq!(entry_function.function().as_str(), "append_to_vector");
+                let count: u64 = bcs::from_bytes(&entry_function.args()[0]).unwrap();
+                assert!(count as usize <= 100);
+                *pushed.entry(txn.sender()).or_insert(0) += count as usize;
+            }
+        }
+
+        let sizes = accounts
+            .iter()
+            .map(|account| {
+                let size = generator.target_size(&account.address()).unwrap();
+                assert_eq!(pushed.get(&account.address()).copied().unwrap_or(0), size);
+                assert!((10..=10_000).contains(&size));
+                size
+            })
+            .collect::<Vec<_>>();
+        // Accounts at their size don't send anything anymore
+        assert!(generator
+            .generate_transactions(accounts.iter_mut().collect(), 1)
+            .is_empty());
+
+        // P(size >= x) = (10 / x) ^ 1.5: half of the accounts under 15.9, 1/8 of them over 40
+        let fraction_over = |x| sizes.iter().filter(|size| **size >= x).count() as f64 / 2000.0;
+        assert!((fraction_over(16) - 0.5).abs() < 0.05);
+        assert!((fraction_over(40) - 0.125).abs() < 0.03);
+        assert!((fraction_over(100) - 0.0316).abs() < 0.015);
+        assert!(sizes.iter().max().unwrap() > &200);
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::TransactionExecutor;
+use anyhow::Result;
+use aptos_sdk::types::{transaction::SignedTransaction, LocalAccount};
+
+/// Per account limit of transactions in mempool.
+const MAX_TXNS_PER_ACCOUNT: usize = 100;
+
+/// Signs setup transactions (e.g. funding of new accounts), spreading them round-robin across
+/// `source_accounts`, so that setup is not serialized behind the sequence number of a single
+/// account. Transactions of each source account are signed in order, with consecutive
+/// sequence numbers.
+pub fn sign_across_source_accounts<T>(
+    source_accounts: &mut [LocalAccount],
+    requests: impl IntoIterator<Item = T>,
+    mut sign: impl FnMut(&mut LocalAccount, T) -> SignedTransaction,
+) -> Vec<SignedTransaction> {
+    assert!(!source_accounts.is_empty(), "Must have source accounts");
+    let num_source_accounts = source_accounts.len();
+    requests
+        .into_iter()
+        .enumerate()
+        .map(|(i, request)| sign(&mut source_accounts[i % num_source_accounts], request))
+        .collect()
+}
+
+/// Executes transactions signed by `sign_across_source_accounts`, in chunks that
+/// keep each of the `num_source_accounts` accounts within the per account limit of mempool.
+pub async fn execute_across_source_accounts(
+    txn_executor: &dyn TransactionExecutor,
+    txns: &[SignedTransaction],
+    num_source_accounts: usize,
+) -> Result<()> {
+    for chunk in txns.chunks(MAX_TXNS_PER_ACCOUNT * num_source_accounts.max(1)) {
+        txn_executor.execute_transactions(chunk).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_sdk::{
+        move_types::account_address::AccountAddress,
+        transaction_builder::{aptos_stdlib, TransactionFactory},
+        types::chain_id::ChainId,
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_spread_across_source_accounts() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let txn_factory = TransactionFactory::new(ChainId::test());
+        let mut source_accounts = (0..3)
+            .map(|i| {
+                let mut account = LocalAccount::generate(&mut rng);
+                *account.sequence_number_mut() = i * 10;
+                account
+            })
+            .collect::<Vec<_>>();
+        let receivers = (0..10)
+            .map(|_| AccountAddress::random())
+            .collect::<Vec<_>>();
+
+        let txns = sign_across_source_accounts(&mut source_accounts, &receivers, |source, to| {
+            source.sign_with_transaction_builder(
+                txn_factory.payload(aptos_stdlib::aptos_coin_transfer(*to, 1)),
+            )
+        });
+        assert_eq!(txns.len(), receivers.len());
+
+        let mut sequence_numbers: HashMap<_, Vec<u64>> = HashMap::new();
+        for txn in &txns {
+            sequence_numbers
+                .entry(txn.sender())
+                .or_default()
+                .push(txn.sequence_number());
+        }
+        assert_eq!(sequence_numbers.len(), source_accounts.len());
+        for (i, source) in source_accounts.iter().enumerate() {
+            let start = i as u64 * 10;
+            let num_txns =
+                (receivers.len() + source_accounts.len() - 1 - i) / source_accounts.len();
+            assert_eq!(
+                sequence_numbers[&source.address()],
+                (start..start + num_txns as u64).collect::<Vec<_>>()
+            );
+            assert_eq!(source.sequence_number(), start + num_txns as u64);
+        }
+    }
+}
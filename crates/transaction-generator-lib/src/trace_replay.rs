@@ -0,0 +1,214 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    shared_txn_factory::SharedTransactionFactory, TransactionGenerator, TransactionGeneratorCreator,
+};
+use anyhow::{Context, Result};
+use aptos_infallible::Mutex;
+use aptos_logger::info;
+use aptos_sdk::{
+    bcs,
+    types::{
+        account_address::AccountAddress,
+        transaction::{SignedTransaction, TransactionPayload},
+        LocalAccount,
+    },
+};
+use async_trait::async_trait;
+use once_cell::sync::OnceCell;
+use rand::thread_rng;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::Path, sync::Arc, time::Instant};
+
+/// Single transaction of a captured workload. The trace file is the bcs encoded
+/// `Vec<TraceEntry>`, ordered by `relative_timestamp_ms`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TraceEntry {
+    /// Time at which the transaction was submitted, relative to the start of the trace.
+    pub relative_timestamp_ms: u64,
+    /// Original sender, only used to keep transactions of the same sender on the same account.
+    pub sender: AccountAddress,
+    pub payload: TransactionPayload,
+}
+
+pub fn load_trace(trace_path: &Path) -> Result<Vec<TraceEntry>> {
+    let bytes = std::fs::read(trace_path)
+        .with_context(|| format!("Failed reading trace {}", trace_path.display()))?;
+    let mut entries: Vec<TraceEntry> = bcs::from_bytes(&bytes)
+        .with_context(|| format!("Failed decoding trace {}", trace_path.display()))?;
+    entries.sort_by_key(|entry| entry.relative_timestamp_ms);
+    Ok(entries)
+}
+
+/// Position in the trace, shared by the generators of all workers, so that the
+/// trace is replayed once overall, and not once per worker.
+struct TraceCursor {
+    entries: Vec<TraceEntry>,
+    next: Mutex<usize>,
+    start: OnceCell<Instant>,
+}
+
+/// Replays a trace at its recorded cadence: every call re-signs (with the current
+/// keys and sequence numbers) the entries that became due since the first call.
+/// Original senders are not available, so each of them is remapped to one of the
+/// accounts handed to the generator, consistently for the lifetime of the generator.
+pub struct TraceReplayGenerator {
+    txn_factory: SharedTransactionFactory,
+    cursor: Arc<TraceCursor>,
+    remapped_senders: HashMap<AccountAddress, usize>,
+}
+
+impl TraceReplayGenerator {
+    fn new(txn_factory: SharedTransactionFactory, cursor: Arc<TraceCursor>) -> Self {
+        Self {
+            txn_factory,
+            cursor,
+            remapped_senders: HashMap::new(),
+        }
+    }
+
+    /// Takes the entries that are due, at most `max_entries` of them.
+    fn take_due_entries(&self, max_entries: usize) -> Vec<TraceEntry> {
+        let start = *self.cursor.start.get_or_init(Instant::now);
+        let elapsed_ms = start.elapsed().as_millis() as u64;
+        let mut next = self.cursor.next.lock();
+        let due = self.cursor.entries[*next..]
+            .iter()
+            .take(max_entries)
+            .take_while(|entry| entry.relative_timestamp_ms <= elapsed_ms)
+            .cloned()
+            .collect::<Vec<_>>();
+        *next += due.len();
+        due
+    }
+
+    fn sender_index(&mut self, accounts: &[&mut LocalAccount], sender: AccountAddress) -> usize {
+        // Senders still held by us are used as is
+        if let Some(index) = accounts
+            .iter()
+            .position(|account| account.address() == sender)
+        {
+            return index;
+        }
+        let num_remapped = self.remapped_senders.len();
+        *self.remapped_senders.entry(sender).or_insert(num_remapped) % accounts.len()
+    }
+}
+
+impl TransactionGenerator for TraceReplayGenerator {
+    fn generate_transactions(
+        &mut self,
+        mut accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        if accounts.is_empty() {
+            return Vec::new();
+        }
+        self.take_due_entries(accounts.len() * transactions_per_account)
+            .into_iter()
+            .map(|entry| {
+                let index = self.sender_index(&accounts, entry.sender);
+                accounts[index].sign_with_transaction_builder(
+                    self.txn_factory.payload(entry.payload, &mut thread_rng()),
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct TraceReplayCreator {
+    txn_factory: SharedTransactionFactory,
+    cursor: Arc<TraceCursor>,
+}
+
+impl TraceReplayCreator {
+    pub fn new(txn_factory: SharedTransactionFactory, entries: Vec<TraceEntry>) -> Self {
+        Self {
+            txn_factory,
+            cursor: Arc::new(TraceCursor {
+                entries,
+                next: Mutex::new(0),
+                start: OnceCell::new(),
+            }),
+        }
+    }
+
+    pub fn from_file(txn_factory: SharedTransactionFactory, trace_path: &Path) -> Result<Self> {
+        let entries = load_trace(trace_path)?;
+        info!(
+            "Replaying {} transactions from trace {}",
+            entries.len(),
+            trace_path.display()
+        );
+        Ok(Self::new(txn_factory, entries))
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for TraceReplayCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(TraceReplayGenerator::new(
+            self.txn_factory.clone(),
+            self.cursor.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resumable_rng::ResumableRng;
+    use aptos_sdk::{
+        transaction_builder::{aptos_stdlib, TransactionFactory},
+        types::chain_id::ChainId,
+    };
+    use rand::SeedableRng;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_replay_preserves_relative_timing() {
+        let offsets = [0, 0, 150, 300, 300, 450];
+        let entries = offsets
+            .iter()
+            .enumerate()
+            .map(|(i, offset)| TraceEntry {
+                relative_timestamp_ms: *offset,
+                // None of the original senders exist anymore
+                sender: AccountAddress::from_hex_literal(&format!("0x{}", i % 2 + 10)).unwrap(),
+                payload: aptos_stdlib::aptos_coin_transfer(AccountAddress::ONE, i as u64),
+            })
+            .collect::<Vec<_>>();
+        let mut creator = TraceReplayCreator::new(
+            SharedTransactionFactory::from(TransactionFactory::new(ChainId::test())),
+            entries,
+        );
+        let mut generator = creator.create_transaction_generator().await;
+
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut accounts = (0..3)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let start = Instant::now();
+        let mut replayed = Vec::new();
+        while replayed.len() < offsets.len() && start.elapsed() < Duration::from_secs(5) {
+            for txn in generator.generate_transactions(accounts.iter_mut().collect(), 10) {
+                replayed.push((start.elapsed().as_millis() as u64, txn));
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        assert_eq!(replayed.len(), offsets.len());
+        for (i, ((elapsed_ms, txn), offset)) in replayed.iter().zip(offsets.iter()).enumerate() {
+            assert!(
+                *elapsed_ms >= *offset && *elapsed_ms <= offset + 100,
+                "Replayed at {}ms, recorded at {}ms",
+                elapsed_ms,
+                offset
+            );
+            // Original senders are remapped to our accounts, consistently
+            assert_eq!(txn.sender(), replayed[i % 2].1.sender());
+        }
+        assert_ne!(replayed[0].1.sender(), replayed[1].1.sender());
+    }
+}
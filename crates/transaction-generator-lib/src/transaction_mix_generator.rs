@@ -1,25 +1,48 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
-use crate::{TransactionGenerator, TransactionGeneratorCreator};
+use crate::{resumable_rng::ResumableRng, TransactionGenerator, TransactionGeneratorCreator};
 use aptos_sdk::types::{transaction::SignedTransaction, LocalAccount};
 use async_trait::async_trait;
-use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 use std::sync::{
     atomic::{AtomicUsize, Ordering},
     Arc,
 };
 
+/// How the generators of a phase are picked according to their weights.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum MixMode {
+    /// Every call is handed entirely to a single generator, picked at random by weight,
+    /// so the realized mix only matches the weights on average.
+    Probabilistic,
+    /// The accounts of every call are split across generators in proportion to the weights,
+    /// so that e.g. a 70/30 mix over 10 accounts yields exactly 7 and 3. Shares that don't
+    /// divide evenly are carried over to the next calls, to keep the mix exact over time.
+    Exact,
+}
+
+impl Default for MixMode {
+    fn default() -> Self {
+        MixMode::Probabilistic
+    }
+}
+
 pub struct PhasedTxnMixGenerator {
-    rng: StdRng,
+    rng: ResumableRng,
     // for each phase, list of transaction mixes.
     txn_mix_per_phase: Vec<Vec<(Box<dyn TransactionGenerator>, usize)>>,
     total_weight_per_phase: Vec<usize>,
     phase: Arc<AtomicUsize>,
+    mix_mode: MixMode,
+    // for each phase, how much each generator is owed by the Exact mode
+    // (smooth weighted round-robin), summing up to 0.
+    credits_per_phase: Vec<Vec<i64>>,
 }
 
 impl PhasedTxnMixGenerator {
     pub fn new(
-        rng: StdRng,
+        rng: ResumableRng,
         txn_mix_per_phase: Vec<Vec<(Box<dyn TransactionGenerator>, usize)>>,
         phase: Arc<AtomicUsize>,
     ) -> Self {
@@ -27,12 +50,44 @@ impl PhasedTxnMixGenerator {
             .iter()
             .map(|txn_mix| txn_mix.iter().map(|(_, weight)| weight).sum())
             .collect();
+        let credits_per_phase = txn_mix_per_phase
+            .iter()
+            .map(|txn_mix| vec![0; txn_mix.len()])
+            .collect();
         Self {
             rng,
             txn_mix_per_phase,
             total_weight_per_phase,
             phase,
+            mix_mode: MixMode::default(),
+            credits_per_phase,
+        }
+    }
+
+    pub fn with_mix_mode(mut self, mix_mode: MixMode) -> Self {
+        self.mix_mode = mix_mode;
+        self
+    }
+
+    /// Number of accounts to hand to each generator of the phase, adding up to `num_accounts`.
+    /// Every account goes to the generator owed the most, once all are credited their weight,
+    /// so any `total weight` consecutive accounts are split exactly by weight.
+    fn exact_counts(&mut self, phase: usize, num_accounts: usize) -> Vec<usize> {
+        let total_weight = self.total_weight_per_phase[phase] as i64;
+        let txn_mix = &self.txn_mix_per_phase[phase];
+        let credits = &mut self.credits_per_phase[phase];
+        let mut counts = vec![0; credits.len()];
+        for _ in 0..num_accounts {
+            for (credit, (_, weight)) in credits.iter_mut().zip(txn_mix) {
+                *credit += *weight as i64;
+            }
+            let most_owed = (0..credits.len())
+                .max_by_key(|i| (credits[*i], std::cmp::Reverse(*i)))
+                .unwrap();
+            credits[most_owed] -= total_weight;
+            counts[most_owed] += 1;
         }
+        counts
     }
 }
 
@@ -49,6 +104,21 @@ impl TransactionGenerator for PhasedTxnMixGenerator {
             self.phase.load(Ordering::Relaxed)
         };
 
+        if self.mix_mode == MixMode::Exact {
+            let counts = self.exact_counts(phase, accounts.len());
+            let mut accounts = accounts.into_iter();
+            let mut requests = Vec::new();
+            for ((gen, _), count) in self.txn_mix_per_phase[phase].iter_mut().zip(counts) {
+                if count > 0 {
+                    requests.extend(gen.generate_transactions(
+                        accounts.by_ref().take(count).collect(),
+                        transactions_per_account,
+                    ));
+                }
+            }
+            return requests;
+        }
+
         let mut picked = self.rng.gen_range(0, self.total_weight_per_phase[phase]);
         for (gen, weight) in &mut self.txn_mix_per_phase[phase] {
             if picked < *weight {
@@ -66,6 +136,9 @@ impl TransactionGenerator for PhasedTxnMixGenerator {
 pub struct PhasedTxnMixGeneratorCreator {
     txn_mix_per_phase_creators: Vec<Vec<(Box<dyn TransactionGeneratorCreator>, usize)>>,
     phase: Arc<AtomicUsize>,
+    mix_mode: MixMode,
+    // source of the seeds of the RNGs of all generators, if they are to be reproducible
+    seed_rng: Option<ResumableRng>,
 }
 
 impl PhasedTxnMixGeneratorCreator {
@@ -76,8 +149,29 @@ impl PhasedTxnMixGeneratorCreator {
         Self {
             txn_mix_per_phase_creators,
             phase,
+            mix_mode: MixMode::default(),
+            seed_rng: None,
         }
     }
+
+    pub fn with_mix_mode(mut self, mix_mode: MixMode) -> Self {
+        self.mix_mode = mix_mode;
+        self
+    }
+
+    /// Seeds the RNGs of the created generators (through `restore_rng`) and of the mix from
+    /// `master_seed`, in creation order, so that the same seed reproduces the same transaction
+    /// streams. Generators not holding a `ResumableRng` aren't affected.
+    pub fn with_master_seed(mut self, master_seed: u64) -> Self {
+        self.seed_rng = Some(ResumableRng::seed_from_u64(master_seed));
+        self
+    }
+
+    fn next_rng(&mut self) -> Option<ResumableRng> {
+        self.seed_rng
+            .as_mut()
+            .map(|seed_rng| ResumableRng::from_seed(seed_rng.gen()))
+    }
 }
 
 #[async_trait]
@@ -87,18 +181,78 @@ impl TransactionGeneratorCreator for PhasedTxnMixGeneratorCreator {
         for txn_mix_creators in self.txn_mix_per_phase_creators.iter_mut() {
             let mut txn_mix = Vec::<(Box<dyn TransactionGenerator>, usize)>::new();
             for (generator_creator, weight) in txn_mix_creators.iter_mut() {
-                txn_mix.push((
-                    generator_creator.create_transaction_generator().await,
-                    *weight,
-                ));
+                let mut generator = generator_creator.create_transaction_generator().await;
+                if let Some(seed_rng) = self.seed_rng.as_mut() {
+                    let rng = ResumableRng::from_seed(seed_rng.gen());
+                    generator.restore_rng(&rng.snapshot());
+                }
+                txn_mix.push((generator, *weight));
             }
             txn_mix_per_phase.push(txn_mix);
         }
 
-        Box::new(PhasedTxnMixGenerator::new(
-            StdRng::from_entropy(),
-            txn_mix_per_phase,
-            self.phase.clone(),
-        ))
+        Box::new(
+            PhasedTxnMixGenerator::new(
+                self.next_rng().unwrap_or_else(ResumableRng::from_entropy),
+                txn_mix_per_phase,
+                self.phase.clone(),
+            )
+            .with_mix_mode(self.mix_mode),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Generator producing nothing, so that only the split of the accounts is checked.
+    struct NoopGenerator;
+
+    impl TransactionGenerator for NoopGenerator {
+        fn generate_transactions(
+            &mut self,
+            _accounts: Vec<&mut LocalAccount>,
+            _transactions_per_account: usize,
+        ) -> Vec<SignedTransaction> {
+            Vec::new()
+        }
+    }
+
+    fn mix_generator(weights: &[usize]) -> PhasedTxnMixGenerator {
+        let txn_mix = weights
+            .iter()
+            .map(|weight| {
+                (
+                    Box::new(NoopGenerator) as Box<dyn TransactionGenerator>,
+                    *weight,
+                )
+            })
+            .collect();
+        PhasedTxnMixGenerator::new(
+            ResumableRng::seed_from_u64(0),
+            vec![txn_mix],
+            Arc::new(AtomicUsize::new(0)),
+        )
+        .with_mix_mode(MixMode::Exact)
+    }
+
+    #[test]
+    fn test_exact_mix() {
+        let mut generator = mix_generator(&[70, 30]);
+        assert_eq!(generator.exact_counts(0, 10), vec![7, 3]);
+        assert_eq!(generator.exact_counts(0, 100), vec![70, 30]);
+
+        // Shares that don't divide evenly are made up for by the next calls
+        let mut generator = mix_generator(&[1, 1, 1]);
+        let mut totals = vec![0; 3];
+        for _ in 0..3 {
+            let counts = generator.exact_counts(0, 2);
+            assert_eq!(counts.iter().sum::<usize>(), 2);
+            for (total, count) in totals.iter_mut().zip(counts) {
+                *total += count;
+            }
+        }
+        assert_eq!(totals, vec![2, 2, 2]);
     }
 }
@@ -0,0 +1,100 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{resumable_rng::RngSnapshot, TransactionGenerator, TransactionGeneratorCreator};
+use aptos_sdk::types::{transaction::SignedTransaction, LocalAccount};
+use async_trait::async_trait;
+use dashmap::DashMap;
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+/// Number of transactions generated per transaction type, across all generators, to check
+/// the realized mix against the (probabilistic) weights. Cloning shares the counts.
+#[derive(Clone, Default)]
+pub struct TransactionTypeCounters {
+    counts: Arc<DashMap<&'static str, AtomicU64>>,
+}
+
+impl TransactionTypeCounters {
+    fn add(&self, transaction_type: &'static str, count: u64) {
+        self.counts
+            .entry(transaction_type)
+            .or_default()
+            .fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Returns the counts so far, of every transaction type that was part of the mix.
+    pub fn snapshot(&self) -> BTreeMap<&'static str, u64> {
+        self.counts
+            .iter()
+            .map(|entry| (*entry.key(), entry.value().load(Ordering::Relaxed)))
+            .collect()
+    }
+}
+
+/// Wrapper counting the transactions generated by the inner generator, under its type.
+pub struct CountingGenerator {
+    generator: Box<dyn TransactionGenerator>,
+    transaction_type: &'static str,
+    counters: TransactionTypeCounters,
+}
+
+impl TransactionGenerator for CountingGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let txns = self
+            .generator
+            .generate_transactions(accounts, transactions_per_account);
+        self.counters.add(self.transaction_type, txns.len() as u64);
+        txns
+    }
+
+    fn rng_snapshot(&self) -> Option<RngSnapshot> {
+        self.generator.rng_snapshot()
+    }
+
+    fn restore_rng(&mut self, snapshot: &RngSnapshot) {
+        self.generator.restore_rng(snapshot)
+    }
+}
+
+pub struct CountingGeneratorCreator {
+    creator: Box<dyn TransactionGeneratorCreator>,
+    transaction_type: &'static str,
+    counters: TransactionTypeCounters,
+}
+
+impl CountingGeneratorCreator {
+    pub fn new(
+        creator: Box<dyn TransactionGeneratorCreator>,
+        transaction_type: &'static str,
+        counters: TransactionTypeCounters,
+    ) -> Self {
+        // Types in the mix are reported even if they never generate anything
+        counters.add(transaction_type, 0);
+        Self {
+            creator,
+            transaction_type,
+            counters,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for CountingGeneratorCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(CountingGenerator {
+            generator: self.creator.create_transaction_generator().await,
+            transaction_type: self.transaction_type,
+            counters: self.counters.clone(),
+        })
+    }
+}
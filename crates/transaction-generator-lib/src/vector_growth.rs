@@ -0,0 +1,190 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{
+    publishing::{module_simple::EntryPoints, publish_util::Package},
+    TransactionExecutor,
+};
+use crate::{
+    publishing::publish_util::PackageHandler, resumable_rng::ResumableRng,
+    shared_txn_factory::SharedTransactionFactory, TransactionGenerator,
+    TransactionGeneratorCreator,
+};
+use aptos_logger::{info, sample, sample::SampleRate};
+use aptos_sdk::{
+    move_types::account_address::AccountAddress,
+    types::{transaction::SignedTransaction, LocalAccount},
+};
+use async_trait::async_trait;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+/// Repeatedly appends to a vector stored in a resource under each account,
+/// to observe how the cost of a transaction scales with the size of the resource it touches.
+pub struct VectorGrowthGenerator {
+    txn_factory: SharedTransactionFactory,
+    package: Arc<Package>,
+    pushes_per_txn: usize,
+    // expected size of the vector under each account, assuming all transactions commit
+    vector_sizes: HashMap<AccountAddress, usize>,
+}
+
+impl VectorGrowthGenerator {
+    pub fn new(
+        txn_factory: SharedTransactionFactory,
+        package: Arc<Package>,
+        pushes_per_txn: usize,
+    ) -> Self {
+        Self {
+            txn_factory,
+            package,
+            pushes_per_txn,
+            vector_sizes: HashMap::new(),
+        }
+    }
+
+    pub fn vector_size(&self, address: &AccountAddress) -> usize {
+        self.vector_sizes.get(address).copied().unwrap_or(0)
+    }
+}
+
+impl TransactionGenerator for VectorGrowthGenerator {
+    fn generate_transactions(
+        &mut self,
+        accounts: Vec<&mut LocalAccount>,
+        transactions_per_account: usize,
+    ) -> Vec<SignedTransaction> {
+        let mut requests = Vec::with_capacity(accounts.len() * transactions_per_account);
+        let entry_point = EntryPoints::AppendToVector {
+            count: self.pushes_per_txn as u64,
+        };
+        for account in accounts {
+            for _ in 0..transactions_per_account {
+                requests.push(self.package.use_specific_transaction(
+                    entry_point,
+                    account,
+                    &self.txn_factory,
+                    None,
+                    None,
+                ));
+            }
+            *self.vector_sizes.entry(account.address()).or_insert(0) +=
+                transactions_per_account * self.pushes_per_txn;
+        }
+
+        sample!(
+            SampleRate::Duration(Duration::from_secs(120)),
+            info!(
+                "Vector growth: {} accounts, max vector size {}",
+                self.vector_sizes.len(),
+                self.vector_sizes.values().max().copied().unwrap_or(0)
+            )
+        );
+        requests
+    }
+}
+
+pub struct VectorGrowthCreator {
+    txn_factory: SharedTransactionFactory,
+    package: Arc<Package>,
+    pushes_per_txn: usize,
+}
+
+impl VectorGrowthCreator {
+    pub async fn new(
+        rng: &mut ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        init_txn_factory: SharedTransactionFactory,
+        publisher: &mut LocalAccount,
+        txn_executor: &dyn TransactionExecutor,
+        pushes_per_txn: usize,
+    ) -> Self {
+        let mut package_handler = PackageHandler::new();
+        let package = package_handler.pick_package(rng, publisher);
+        let txn = package.publish_transaction(rng, publisher, &init_txn_factory);
+        info!("Publishing vector growth package");
+        txn_executor.execute_transactions(&[txn]).await.unwrap();
+        info!("Done publishing vector growth package");
+
+        Self {
+            txn_factory,
+            package: Arc::new(package),
+            pushes_per_txn,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for VectorGrowthCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(VectorGrowthGenerator::new(
+            self.txn_factory.clone(),
+            self.package.clone(),
+            self.pushes_per_txn,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::in_memory_executor::InMemoryExecutor;
+    use aptos_sdk::{
+        bcs,
+        transaction_builder::TransactionFactory,
+        types::{chain_id::ChainId, transaction::TransactionPayload},
+    };
+    use rand::SeedableRng;
+
+    #[tokio::test]
+    async fn test_publishes_then_pushes() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let mut publisher = LocalAccount::generate(&mut rng);
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let executor = InMemoryExecutor::default();
+        let creator = VectorGrowthCreator::new(
+            &mut rng,
+            txn_factory.clone(),
+            txn_factory,
+            &mut publisher,
+            &executor,
+            3,
+        )
+        .await;
+
+        // The package is published during setup, by the publisher
+        {
+            let executed = executor.executed.lock();
+            assert_eq!(executed.len(), 1);
+            assert_eq!(executed[0].sender(), publisher.address());
+            match executed[0].payload() {
+                TransactionPayload::EntryFunction(entry_function) => {
+                    assert_eq!(entry_function.function().as_str(), "publish_package_txn")
+                },
+                _ => panic!("Publishing must call an entry function"),
+            }
+        }
+
+        let mut generator = VectorGrowthGenerator::new(
+            creator.txn_factory.clone(),
+            creator.package.clone(),
+            creator.pushes_per_txn,
+        );
+        let mut accounts = (0..4)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        for _ in 0..2 {
+            let txns = generator.generate_transactions(accounts.iter_mut().collect(), 5);
+            assert_eq!(txns.len(), 20);
+            for txn in txns {
+                let entry_function = txn.payload().clone().into_entry_function();
+                assert_eq!(entry_function.module(), &creator.package.module_id());
+                assert_eq!(entry_function.function().as_str(), "append_to_vector");
+                // Each transaction pushes as many elements as asked for
+                assert_eq!(entry_function.args(), &[bcs::to_bytes(&3u64).unwrap()]);
+            }
+        }
+        for account in &accounts {
+            assert_eq!(generator.vector_size(&account.address()), 2 * 5 * 3);
+        }
+    }
+}
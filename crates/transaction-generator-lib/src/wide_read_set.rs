@@ -0,0 +1,184 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{
+    publishing::{module_simple::EntryPoints, publish_util::Package},
+    TransactionExecutor,
+};
+use crate::{
+    call_custom_modules::CallCustomModulesGenerator, publishing::publish_util::PackageHandler,
+    resumable_rng::ResumableRng, shared_txn_factory::SharedTransactionFactory,
+    TransactionGenerator, TransactionGeneratorCreator,
+};
+use aptos_infallible::RwLock;
+use aptos_logger::{info, warn};
+use aptos_sdk::types::LocalAccount;
+use async_trait::async_trait;
+use rand::SeedableRng;
+use std::sync::Arc;
+
+/// Largest number of resources read by a single transaction. Every read is charged for,
+/// so this keeps the transactions well within the max gas amount.
+pub const MAX_RESOURCES_READ: usize = 1_000;
+// items created per setup transaction, so that the storage fee of each stays within the max gas
+const ITEMS_PER_INIT_TXN: usize = 100;
+
+/// Largest number of resources read, clamped to what a transaction can afford.
+pub fn clamp_resources_read(resources_read: usize) -> usize {
+    if resources_read > MAX_RESOURCES_READ {
+        warn!(
+            "{} resources read requested, using the maximum of {}",
+            resources_read, MAX_RESOURCES_READ
+        );
+    }
+    resources_read.min(MAX_RESOURCES_READ)
+}
+
+/// Creates generators of read-only transactions, each reading `resources_read` distinct
+/// resources (table items of the package publisher), to benchmark large read sets.
+/// The package is published, and the resources created, during setup.
+pub struct WideReadSetCreator {
+    txn_factory: SharedTransactionFactory,
+    package: Arc<Vec<Package>>,
+    resources_read: usize,
+}
+
+impl WideReadSetCreator {
+    pub async fn new(
+        rng: &mut ResumableRng,
+        txn_factory: SharedTransactionFactory,
+        init_txn_factory: SharedTransactionFactory,
+        root_account: &mut LocalAccount,
+        txn_executor: &dyn TransactionExecutor,
+        resources_read: usize,
+    ) -> Self {
+        let resources_read = clamp_resources_read(resources_read);
+        let package = PackageHandler::new().pick_package(rng, root_account);
+        let txn = package.publish_transaction(rng, root_account, &init_txn_factory);
+        info!("Publishing wide read set package");
+        txn_executor.execute_transactions(&[txn]).await.unwrap();
+        info!("Done publishing wide read set package");
+
+        let txns = (0..resources_read)
+            .step_by(ITEMS_PER_INIT_TXN)
+            .map(|start| {
+                let count = ITEMS_PER_INIT_TXN.min(resources_read - start);
+                package.use_specific_transaction(
+                    EntryPoints::InitWideReadSet {
+                        start: start as u64,
+                        count: count as u64,
+                    },
+                    root_account,
+                    &init_txn_factory,
+                    Some(rng),
+                    None,
+                )
+            })
+            .collect::<Vec<_>>();
+        info!("Creating {} resources to read", resources_read);
+        txn_executor.execute_transactions(&txns).await.unwrap();
+        info!("Done creating {} resources to read", resources_read);
+
+        Self {
+            txn_factory,
+            package: Arc::new(vec![package]),
+            resources_read,
+        }
+    }
+}
+
+#[async_trait]
+impl TransactionGeneratorCreator for WideReadSetCreator {
+    async fn create_transaction_generator(&mut self) -> Box<dyn TransactionGenerator> {
+        Box::new(CallCustomModulesGenerator::new(
+            ResumableRng::from_entropy(),
+            self.txn_factory.clone(),
+            self.package.clone(),
+            EntryPoints::ReadWideSet {
+                resources_read: self.resources_read as u64,
+            },
+            Arc::new(RwLock::new(Vec::new())),
+            None,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::in_memory_executor::InMemoryExecutor;
+    use aptos_sdk::{
+        bcs,
+        transaction_builder::TransactionFactory,
+        types::{chain_id::ChainId, transaction::SignedTransaction},
+    };
+
+    fn function_and_args(txn: &SignedTransaction) -> (String, Vec<u64>) {
+        let entry_function = txn.payload().clone().into_entry_function();
+        let args = entry_function
+            .args()
+            .iter()
+            .map(|arg| bcs::from_bytes(arg).unwrap())
+            .collect();
+        (entry_function.function().to_string(), args)
+    }
+
+    #[tokio::test]
+    async fn test_publish_and_read_count() {
+        let mut rng = ResumableRng::seed_from_u64(0);
+        let txn_factory = SharedTransactionFactory::from(TransactionFactory::new(ChainId::test()));
+        let mut root_account = LocalAccount::generate(&mut rng);
+        let executor = InMemoryExecutor::default();
+
+        let mut creator = WideReadSetCreator::new(
+            &mut rng,
+            txn_factory.clone(),
+            txn_factory,
+            &mut root_account,
+            &executor,
+            250,
+        )
+        .await;
+
+        // The package is published first, then the resources created in chunks
+        let setup = executor.executed.lock().clone();
+        assert_eq!(setup.len(), 4);
+        assert!(setup
+            .iter()
+            .all(|txn| txn.sender() == root_account.address()));
+        assert_eq!(
+            setup[0]
+                .payload()
+                .clone()
+                .into_entry_function()
+                .function()
+                .as_str(),
+            "publish_package_txn"
+        );
+        assert_eq!(
+            setup[1..].iter().map(function_and_args).collect::<Vec<_>>(),
+            vec![
+                ("init_wide_read_set".to_string(), vec![0, 100]),
+                ("init_wide_read_set".to_string(), vec![100, 100]),
+                ("init_wide_read_set".to_string(), vec![200, 50]),
+            ]
+        );
+
+        let mut accounts = (0..3)
+            .map(|_| LocalAccount::generate(&mut rng))
+            .collect::<Vec<_>>();
+        let txns = creator
+            .create_transaction_generator()
+            .await
+            .generate_transactions(accounts.iter_mut().collect(), 2);
+        assert_eq!(txns.len(), 6);
+        for txn in &txns {
+            assert_eq!(
+                function_and_args(txn),
+                ("read_wide_set".to_string(), vec![250])
+            );
+        }
+
+        assert_eq!(clamp_resources_read(1_000_000), MAX_RESOURCES_READ);
+    }
+}
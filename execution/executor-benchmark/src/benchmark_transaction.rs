@@ -2,30 +2,39 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::Result;
+use aptos_compression::{metrics::CompressionClient, CompressedData};
 use aptos_executor::{
     block_executor::TransactionBlockExecutor, components::chunk_output::ChunkOutput,
 };
 use aptos_storage_interface::cached_state_view::CachedStateView;
 use aptos_types::{account_address::AccountAddress, transaction::Transaction};
 use aptos_vm::AptosVM;
+use serde::{Deserialize, Serialize};
 
+/// Upper bound of the (uncompressed) size of a compressed block.
+const MAX_BLOCK_BYTES: usize = i32::MAX as usize;
+
+#[derive(Deserialize, Serialize)]
 pub struct TransferInfo {
     pub sender: AccountAddress,
     pub receiver: AccountAddress,
     pub amount: u64,
 }
 
+#[derive(Deserialize, Serialize)]
 pub struct AccountCreationInfo {
     pub sender: AccountAddress,
     pub new_account: AccountAddress,
     pub initial_balance: u64,
 }
 
+#[derive(Deserialize, Serialize)]
 pub enum ExtraInfo {
     TransferInfo(TransferInfo),
     AccountCreationInfo(AccountCreationInfo),
 }
 
+#[derive(Deserialize, Serialize)]
 pub struct BenchmarkTransaction {
     pub transaction: Transaction,
     pub extra_info: Option<ExtraInfo>,
@@ -69,6 +78,46 @@ impl From<Transaction> for BenchmarkTransaction {
     }
 }
 
+/// A block of transactions, as sent from the generator to the execution pipeline.
+/// Huge blocks can be bcs-serialized and compressed before sending, trading CPU for memory.
+pub enum BenchmarkBlock {
+    Raw(Vec<BenchmarkTransaction>),
+    Compressed(CompressedData),
+}
+
+impl BenchmarkBlock {
+    pub fn new(transactions: Vec<BenchmarkTransaction>, compress: bool) -> Self {
+        if compress {
+            let bytes = bcs::to_bytes(&transactions).expect("Block serialization can't fail");
+            Self::Compressed(
+                aptos_compression::compress(
+                    bytes,
+                    CompressionClient::ExecutorBenchmark,
+                    MAX_BLOCK_BYTES,
+                )
+                .expect("Block compression failed"),
+            )
+        } else {
+            Self::Raw(transactions)
+        }
+    }
+
+    pub fn into_transactions(self) -> Vec<BenchmarkTransaction> {
+        match self {
+            Self::Raw(transactions) => transactions,
+            Self::Compressed(data) => {
+                let bytes = aptos_compression::decompress(
+                    &data,
+                    CompressionClient::ExecutorBenchmark,
+                    MAX_BLOCK_BYTES,
+                )
+                .expect("Block decompression failed");
+                bcs::from_bytes(&bytes).expect("Block deserialization failed")
+            },
+        }
+    }
+}
+
 impl TransactionBlockExecutor<BenchmarkTransaction> for AptosVM {
     fn execute_transaction_block(
         transactions: Vec<BenchmarkTransaction>,
@@ -83,3 +132,27 @@ impl TransactionBlockExecutor<BenchmarkTransaction> for AptosVM {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_crypto::HashValue;
+
+    #[test]
+    fn test_compressed_block_round_trip() {
+        let sender = AccountAddress::random();
+        let receiver = AccountAddress::random();
+        let transactions = vec![
+            BenchmarkTransaction::new(
+                Transaction::StateCheckpoint(HashValue::random()),
+                ExtraInfo::TransferInfo(TransferInfo::new(sender, receiver, 1)),
+            ),
+            Transaction::StateCheckpoint(HashValue::random()).into(),
+        ];
+        let expected = bcs::to_bytes(&transactions).unwrap();
+
+        let block = BenchmarkBlock::new(transactions, true);
+        assert!(matches!(block, BenchmarkBlock::Compressed(_)));
+        assert_eq!(bcs::to_bytes(&block.into_transactions()).unwrap(), expected);
+    }
+}
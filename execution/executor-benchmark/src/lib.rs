@@ -103,6 +103,7 @@ pub fn run_benchmark<V>(
         block_sender,
         source_dir,
         version,
+        false, /* compress_blocks */
     );
 
     let start_time = Instant::now();
@@ -181,6 +182,7 @@ fn add_accounts_impl<V>(
         block_sender,
         &source_dir,
         version,
+        false, /* compress_blocks */
     );
 
     let start_time = Instant::now();
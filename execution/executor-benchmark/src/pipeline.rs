@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    benchmark_transaction::BenchmarkTransaction, TransactionCommitter, TransactionExecutor,
+    benchmark_transaction::{BenchmarkBlock, BenchmarkTransaction},
+    TransactionCommitter, TransactionExecutor,
 };
 use aptos_executor::block_executor::{BlockExecutor, TransactionBlockExecutor};
 use aptos_executor_types::BlockExecutorTrait;
@@ -26,13 +27,13 @@ where
     pub fn new(
         executor: BlockExecutor<V, BenchmarkTransaction>,
         version: Version,
-    ) -> (Self, mpsc::SyncSender<Vec<BenchmarkTransaction>>) {
+    ) -> (Self, mpsc::SyncSender<BenchmarkBlock>) {
         let parent_block_id = executor.committed_block_id();
         let executor_1 = Arc::new(executor);
         let executor_2 = executor_1.clone();
 
         let (block_sender, block_receiver) =
-            mpsc::sync_channel::<Vec<BenchmarkTransaction>>(50 /* bound */);
+            mpsc::sync_channel::<BenchmarkBlock>(50 /* bound */);
         let (commit_sender, commit_receiver) = mpsc::sync_channel(3 /* bound */);
 
         let exe_thread = std::thread::Builder::new()
@@ -44,7 +45,8 @@ where
                     version,
                     Some(commit_sender),
                 );
-                while let Ok(transactions) = block_receiver.recv() {
+                while let Ok(block) = block_receiver.recv() {
+                    let transactions = block.into_transactions();
                     info!("Received block of size {:?} to execute", transactions.len());
                     exe.execute_block(transactions);
                 }
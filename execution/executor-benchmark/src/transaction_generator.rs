@@ -4,7 +4,9 @@
 
 use crate::{
     account_generator::{AccountCache, AccountGenerator},
-    benchmark_transaction::{AccountCreationInfo, BenchmarkTransaction, ExtraInfo, TransferInfo},
+    benchmark_transaction::{
+        AccountCreationInfo, BenchmarkBlock, BenchmarkTransaction, ExtraInfo, TransferInfo,
+    },
 };
 use aptos_crypto::{ed25519::Ed25519PrivateKey, HashValue};
 use aptos_sdk::{transaction_builder::TransactionFactory, types::LocalAccount};
@@ -88,7 +90,11 @@ pub struct TransactionGenerator {
 
     /// Each generated block of transactions are sent to this channel. Using `SyncSender` to make
     /// sure if execution is slow to consume the transactions, we do not run out of memory.
-    block_sender: Option<mpsc::SyncSender<Vec<BenchmarkTransaction>>>,
+    block_sender: Option<mpsc::SyncSender<BenchmarkBlock>>,
+
+    /// Whether blocks are bcs-serialized and compressed before being sent to the channel,
+    /// trading CPU for memory when generating huge blocks.
+    compress_blocks: bool,
 
     /// Transaction Factory
     transaction_factory: TransactionFactory,
@@ -141,9 +147,10 @@ impl TransactionGenerator {
     pub fn new_with_existing_db<P: AsRef<Path>>(
         db: DbReaderWriter,
         genesis_key: Ed25519PrivateKey,
-        block_sender: mpsc::SyncSender<Vec<BenchmarkTransaction>>,
+        block_sender: mpsc::SyncSender<BenchmarkBlock>,
         db_dir: P,
         version: Version,
+        compress_blocks: bool,
     ) -> Self {
         let path = db_dir.as_ref().join(META_FILENAME);
 
@@ -170,6 +177,7 @@ impl TransactionGenerator {
             num_existing_accounts,
             version,
             block_sender: Some(block_sender),
+            compress_blocks,
             transaction_factory: Self::create_transaction_factory(),
         }
     }
@@ -193,6 +201,14 @@ impl TransactionGenerator {
         file.write_all(&serialized).unwrap();
     }
 
+    fn send_block(&self, transactions: Vec<BenchmarkTransaction>) {
+        if let Some(sender) = &self.block_sender {
+            sender
+                .send(BenchmarkBlock::new(transactions, self.compress_blocks))
+                .unwrap();
+        }
+    }
+
     pub fn num_existing_accounts(&self) -> usize {
         self.num_existing_accounts
     }
@@ -287,9 +303,7 @@ impl TransactionGenerator {
                 .collect();
             self.version += transactions.len() as Version;
             bar.inc(transactions.len() as u64 - 1);
-            if let Some(sender) = &self.block_sender {
-                sender.send(transactions).unwrap();
-            }
+            self.send_block(transactions);
         }
         bar.finish();
         println!("[{}] done.", now_fmt!());
@@ -340,9 +354,7 @@ impl TransactionGenerator {
                 ))
                 .collect();
             self.version += transactions.len() as Version;
-            if let Some(sender) = &self.block_sender {
-                sender.send(transactions).unwrap();
-            }
+            self.send_block(transactions);
             bar.inc(block_size as u64);
         }
         bar.finish();
@@ -390,9 +402,7 @@ impl TransactionGenerator {
                 .collect();
             self.version += transactions.len() as Version;
 
-            if let Some(sender) = &self.block_sender {
-                sender.send(transactions).unwrap();
-            }
+            self.send_block(transactions);
         }
     }
 
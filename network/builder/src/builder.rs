@@ -12,10 +12,10 @@
 //! long as the latter is in its trusted peers set.
 use aptos_config::{
     config::{
-        DiscoveryMethod, NetworkConfig, Peer, PeerRole, PeerSet, RoleType, CONNECTION_BACKOFF_BASE,
-        CONNECTIVITY_CHECK_INTERVAL_MS, MAX_CONCURRENT_NETWORK_REQS, MAX_CONNECTION_DELAY_MS,
-        MAX_FRAME_SIZE, MAX_FULLNODE_OUTBOUND_CONNECTIONS, MAX_INBOUND_CONNECTIONS,
-        NETWORK_CHANNEL_SIZE,
+        DiscoveryMethod, Error, KeyProvider, NetworkConfig, Peer, PeerRole, PeerSet, RoleType,
+        CONNECTION_BACKOFF_BASE, CONNECTIVITY_CHECK_INTERVAL_MS, MAX_CONCURRENT_NETWORK_REQS,
+        MAX_CONNECTION_DELAY_MS, MAX_FRAME_SIZE, MAX_FULLNODE_OUTBOUND_CONNECTIONS,
+        MAX_INBOUND_CONNECTIONS, NETWORK_CHANNEL_SIZE,
     },
     network_id::NetworkContext,
 };
@@ -161,7 +161,8 @@ impl NetworkBuilder {
         builder
     }
 
-    /// Create a new NetworkBuilder based on the provided configuration.
+    /// Create a new NetworkBuilder based on the provided configuration. The `key_provider` is
+    /// only used for identity keys held in a KMS, and fails if such a key can't be fetched.
     pub fn create(
         chain_id: ChainId,
         role: RoleType,
@@ -169,9 +170,10 @@ impl NetworkBuilder {
         time_service: TimeService,
         mut reconfig_subscription_service: Option<&mut EventSubscriptionService>,
         peers_and_metadata: Arc<PeersAndMetadata>,
-    ) -> NetworkBuilder {
+        key_provider: Option<&dyn KeyProvider>,
+    ) -> Result<NetworkBuilder, Error> {
         let peer_id = config.peer_id();
-        let identity_key = config.identity_key();
+        let identity_key = config.resolve_identity_key(key_provider)?;
         let pubkey = identity_key.public_key();
 
         let authentication_mode = if config.mutual_authentication {
@@ -257,7 +259,7 @@ impl NetworkBuilder {
             network_builder.discovery_listeners.as_ref().unwrap().len()
         );
 
-        network_builder
+        Ok(network_builder)
     }
 
     /// Create the configured Networking components.
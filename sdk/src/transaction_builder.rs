@@ -117,6 +117,22 @@ impl TransactionFactory {
         self
     }
 
+    pub fn get_gas_unit_price(&self) -> u64 {
+        self.gas_unit_price
+    }
+
+    pub fn get_max_gas_amount(&self) -> u64 {
+        self.max_gas_amount
+    }
+
+    pub fn get_transaction_expiration_time(&self) -> u64 {
+        self.transaction_expiration_time
+    }
+
+    pub fn get_chain_id(&self) -> ChainId {
+        self.chain_id
+    }
+
     pub fn with_transaction_expiration_time(mut self, transaction_expiration_time: u64) -> Self {
         self.transaction_expiration_time = transaction_expiration_time;
         self
@@ -910,33 +910,44 @@ fn individual_workload_tests(test_name: String, config: ForgeConfig) -> ForgeCon
                     add_created_accounts_to_pool: true,
                     max_account_working_set: 20_000_000,
                     creation_balance: 200_000_000,
+                    account_pool: None,
+                    max_pool_size: None,
                 };
                 let write_type = TransactionType::CallCustomModules {
                     entry_point: EntryPoints::BytesMakeOrChange {
                         data_length: Some(32),
                     },
                     num_modules: 1,
+                    module_address_space: 0,
                     use_account_pool: true,
+                    account_pool: None,
+                    gas_overrides: GasOverrides::default(),
                 };
                 job.transaction_mix_per_phase(vec![
                     // warmup
+                    vec![(account_creation_type.clone(), 1)],
                     vec![(account_creation_type, 1)],
-                    vec![(account_creation_type, 1)],
-                    vec![(write_type, 1)],
+                    vec![(write_type.clone(), 1)],
                     // cooldown
                     vec![(write_type, 1)],
                 ])
             } else {
                 job.transaction_type(match test_name.as_str() {
                     "account_creation" => TransactionType::default_account_generation(),
-                    "nft_mint" => TransactionType::NftMintAndTransfer,
+                    "nft_mint" => TransactionType::default_nft(),
                     "publishing" => TransactionType::PublishPackage {
                         use_account_pool: false,
+                        account_pool: None,
+                        num_modules: 1,
+                        module_bytes_padding: 0,
                     },
                     "module_loading" => TransactionType::CallCustomModules {
                         entry_point: EntryPoints::Nop,
                         num_modules: 1000,
+                        module_address_space: 0,
                         use_account_pool: false,
+                        account_pool: None,
+                        gas_overrides: GasOverrides::default(),
                     },
                     _ => unreachable!("{}", test_name),
                 })
@@ -1411,7 +1422,7 @@ fn large_db_test(
                 .transaction_mix(vec![
                     (TransactionType::default_coin_transfer(), 75),
                     (TransactionType::default_account_generation(), 20),
-                    (TransactionType::NftMintAndTransfer, 5),
+                    (TransactionType::default_nft(), 5),
                 ]),
         )
         .with_success_criteria(
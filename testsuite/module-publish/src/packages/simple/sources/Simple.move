@@ -11,6 +11,11 @@ module 0xABCD::Simple {
     use std::signer;
     use std::string::{Self, String, utf8};
     use std::vector;
+    use aptos_framework::account;
+    use aptos_framework::coin::{Self, Coin};
+    use aptos_framework::event::{Self, EventHandle};
+    use aptos_std::table::{Self, Table};
+    use aptos_std::type_info;
 
     // Through the constant pool it will be possible to change this
     // constant to be as big or as small as desired.
@@ -41,6 +46,16 @@ module 0xABCD::Simple {
     public entry fun nop(_s: &signer) {
     }
 
+    // Same as `nop`, with an unused `nonce` argument, so that callers can make
+    // every transaction payload unique (e.g. to defeat caching in the execution pipeline).
+    public entry fun nop_with_nonce(_s: &signer, _nonce: u64) {
+    }
+
+    // Same as `nop`, taking a second signer, to be called by multi-agent transactions
+    // signed by the sender and one secondary signer.
+    public entry fun nop_2_signers(_s1: &signer, _s2: &signer) {
+    }
+
     // Test simple CPU usage. Loop as defined by the input `count`.
     // Not a true test of CPU usage given the number of instructions
     // used, but a simple reference to computation with no data access.
@@ -91,6 +106,33 @@ module 0xABCD::Simple {
         counter.count;
     }
 
+    // Increment the `Counter` of the module publisher `count` times.
+    // All callers write the same resource, so transactions calling it
+    // are in write-write conflict with each other.
+    public entry fun increment_shared_counter(_s: &signer, count: u64) acquires Counter {
+        let publisher = type_info::account_address(&type_info::type_of<Counter>());
+        let counter = borrow_global_mut<Counter>(publisher);
+        let i = 0;
+        while (i < count) {
+            counter.count = counter.count + 1;
+            i = i + 1;
+        };
+    }
+
+    // Read the `Counter` of the module publisher `count` times, without writing anything.
+    // All callers only read the same resource, so transactions calling it don't conflict,
+    // and the cost is dominated by the reads.
+    public entry fun heavy_read(_s: &signer, count: u64) acquires Counter {
+        let publisher = type_info::account_address(&type_info::type_of<Counter>());
+        let sum = 0;
+        let i = 0;
+        while (i < count) {
+            sum = sum + borrow_global<Counter>(publisher).count;
+            i = i + 1;
+        };
+        sum;
+    }
+
     //
     // Resource
     //
@@ -109,6 +151,301 @@ module 0xABCD::Simple {
         }
     }
 
+    //
+    // Vector growth
+    //
+
+    struct VectorResource has key {
+        data: vector<u64>,
+    }
+
+    // Append `count` elements to `VectorResource`, creating it if it does not exist.
+    // The resource grows with every call, to observe how the cost scales with its size.
+    public entry fun append_to_vector(owner: &signer, count: u64) acquires VectorResource {
+        if (!exists<VectorResource>(signer::address_of(owner))) {
+            move_to<VectorResource>(owner, VectorResource { data: vector::empty() });
+        };
+        let data = &mut borrow_global_mut<VectorResource>(signer::address_of(owner)).data;
+        let len = vector::length(data);
+        let i = 0;
+        while (i < count) {
+            vector::push_back(data, len + i);
+            i = i + 1;
+        };
+    }
+
+    //
+    // Marked events
+    //
+
+    struct MarkerEvent has drop, store {
+        marker: u64,
+    }
+
+    struct MarkerEvents has key {
+        events: EventHandle<MarkerEvent>,
+    }
+
+    // Emit an event carrying `marker`, creating the event handle if it does not exist.
+    // Markers are increasing across transactions, so consumers of the event stream can detect gaps.
+    public entry fun emit_marker(owner: &signer, marker: u64) acquires MarkerEvents {
+        if (!exists<MarkerEvents>(signer::address_of(owner))) {
+            move_to<MarkerEvents>(owner, MarkerEvents {
+                events: account::new_event_handle<MarkerEvent>(owner),
+            });
+        };
+        let events = &mut borrow_global_mut<MarkerEvents>(signer::address_of(owner)).events;
+        event::emit_event(events, MarkerEvent { marker });
+    }
+
+    //
+    // Oracle
+    //
+
+    struct PriceFeeds has key {
+        prices: Table<u64, u64>,
+    }
+
+    // Set the price of `feed` to `price`, creating the feeds of `oracle` if they do not exist.
+    // Every feed is a separate table item, so updates to different feeds don't conflict.
+    public entry fun update_price(oracle: &signer, feed: u64, price: u64) acquires PriceFeeds {
+        if (!exists<PriceFeeds>(signer::address_of(oracle))) {
+            move_to<PriceFeeds>(oracle, PriceFeeds { prices: table::new() });
+        };
+        let prices = &mut borrow_global_mut<PriceFeeds>(signer::address_of(oracle)).prices;
+        table::upsert(prices, feed, price);
+    }
+
+    //
+    // Wide read set
+    //
+
+    struct WideReadSet has key {
+        items: Table<u64, u64>,
+    }
+
+    // Create items `start` to `start + count` (excluded) in the `WideReadSet` of the module
+    // publisher, for `read_wide_set` to read. Items that already exist are left as they are.
+    public entry fun init_wide_read_set(
+        publisher: &signer,
+        start: u64,
+        count: u64,
+    ) acquires WideReadSet {
+        let addr = signer::address_of(publisher);
+        if (!exists<WideReadSet>(addr)) {
+            move_to<WideReadSet>(publisher, WideReadSet { items: table::new() });
+        };
+        let items = &mut borrow_global_mut<WideReadSet>(addr).items;
+        let i = start;
+        while (i < start + count) {
+            if (!table::contains(items, i)) {
+                table::add(items, i, i);
+            };
+            i = i + 1;
+        };
+    }
+
+    // Read the first `resources_read` items of the `WideReadSet` of the module publisher,
+    // without writing anything. Every item is a separate state item, so the read set
+    // of the transaction grows with `resources_read`.
+    public entry fun read_wide_set(_s: &signer, resources_read: u64) acquires WideReadSet {
+        let publisher = type_info::account_address(&type_info::type_of<WideReadSet>());
+        let items = &borrow_global<WideReadSet>(publisher).items;
+        let sum = 0;
+        let i = 0;
+        while (i < resources_read) {
+            sum = sum + *table::borrow(items, i);
+            i = i + 1;
+        };
+        sum;
+    }
+
+    //
+    // Resource group
+    //
+
+    #[resource_group(scope = global)]
+    struct ResourceGroup { }
+
+    #[resource_group_member(group = 0xABCD::Simple::ResourceGroup)]
+    struct GroupMember0 has key {
+        value: u64,
+    }
+
+    #[resource_group_member(group = 0xABCD::Simple::ResourceGroup)]
+    struct GroupMember1 has key {
+        value: u64,
+    }
+
+    #[resource_group_member(group = 0xABCD::Simple::ResourceGroup)]
+    struct GroupMember2 has key {
+        value: u64,
+    }
+
+    #[resource_group_member(group = 0xABCD::Simple::ResourceGroup)]
+    struct GroupMember3 has key {
+        value: u64,
+    }
+
+    // Read and write the first `members_touched` (up to 4) members of the resource group
+    // of `owner`, creating the ones that do not exist. All members of the group are stored
+    // together, under a single key, so this touches a single state item however many there are.
+    public entry fun touch_resource_group(
+        owner: &signer,
+        members_touched: u64,
+    ) acquires GroupMember0, GroupMember1, GroupMember2, GroupMember3 {
+        let addr = signer::address_of(owner);
+        if (members_touched > 0) {
+            if (exists<GroupMember0>(addr)) {
+                let member = borrow_global_mut<GroupMember0>(addr);
+                member.value = member.value + 1;
+            } else {
+                move_to<GroupMember0>(owner, GroupMember0 { value: 0 });
+            };
+        };
+        if (members_touched > 1) {
+            if (exists<GroupMember1>(addr)) {
+                let member = borrow_global_mut<GroupMember1>(addr);
+                member.value = member.value + 1;
+            } else {
+                move_to<GroupMember1>(owner, GroupMember1 { value: 0 });
+            };
+        };
+        if (members_touched > 2) {
+            if (exists<GroupMember2>(addr)) {
+                let member = borrow_global_mut<GroupMember2>(addr);
+                member.value = member.value + 1;
+            } else {
+                move_to<GroupMember2>(owner, GroupMember2 { value: 0 });
+            };
+        };
+        if (members_touched > 3) {
+            if (exists<GroupMember3>(addr)) {
+                let member = borrow_global_mut<GroupMember3>(addr);
+                member.value = member.value + 1;
+            } else {
+                move_to<GroupMember3>(owner, GroupMember3 { value: 0 });
+            };
+        };
+    }
+
+    //
+    // Flash loan
+    //
+
+    // Literals are used instead of constants below, as `COUNTER_STEP`
+    // has to stay the only u64 in the constant pool.
+
+    struct LoanPool has key {
+        reserve: u64,
+        operations: u64,
+    }
+
+    // Without abilities, the receipt can't be dropped or stored,
+    // so it has to be passed to `repay` within the same transaction.
+    struct LoanReceipt {
+        amount: u64,
+    }
+
+    public fun borrow(pool_address: address, amount: u64): (u64, LoanReceipt) acquires LoanPool {
+        let pool = borrow_global_mut<LoanPool>(pool_address);
+        assert!(pool.reserve >= amount, 1);
+        pool.reserve = pool.reserve - amount;
+        (amount, LoanReceipt { amount })
+    }
+
+    public fun repay(pool_address: address, funds: u64, receipt: LoanReceipt) acquires LoanPool {
+        let LoanReceipt { amount } = receipt;
+        assert!(funds >= amount, 2);
+        let pool = borrow_global_mut<LoanPool>(pool_address);
+        pool.reserve = pool.reserve + funds;
+    }
+
+    // Borrow from the pool under the signer (creating it if it does not exist),
+    // run `steps` operations on the borrowed funds and the pool, and repay.
+    // It all happens in a single transaction: if any step aborts, so does the loan.
+    public entry fun flash_loan(owner: &signer, steps: u64) acquires LoanPool {
+        let owner_address = signer::address_of(owner);
+        if (!exists<LoanPool>(owner_address)) {
+            move_to<LoanPool>(owner, LoanPool { reserve: 1000000, operations: 0 });
+        };
+        let (funds, receipt) = borrow(owner_address, 1000);
+        let i = 0;
+        while (i < steps) {
+            // each operation earns a fee, and is recorded in the pool
+            funds = funds + 1;
+            let pool = borrow_global_mut<LoanPool>(owner_address);
+            pool.operations = pool.operations + 1;
+            i = i + 1;
+        };
+        repay(owner_address, funds, receipt);
+    }
+
+    //
+    // Liquidity pools
+    //
+
+    // Constant product pool of coins X and Y, stored under the account providing the liquidity.
+    struct LiquidityPool<phantom X, phantom Y> has key {
+        reserve_x: Coin<X>,
+        reserve_y: Coin<Y>,
+    }
+
+    // Create a pool under the signer, with reserves of `amount_x` and `amount_y`
+    // withdrawn from the signer.
+    public entry fun create_pool<X, Y>(owner: &signer, amount_x: u64, amount_y: u64) {
+        move_to(owner, LiquidityPool<X, Y> {
+            reserve_x: coin::withdraw<X>(owner, amount_x),
+            reserve_y: coin::withdraw<Y>(owner, amount_y),
+        });
+    }
+
+    // Swap `amount_in` of X for Y (or of Y for X, if `x_to_y` is false) in the pool under
+    // `pool_address`, aborting if less than `min_out` would come out.
+    // The trader is registered for the coin coming out, if it isn't yet.
+    public entry fun swap<X, Y>(
+        trader: &signer,
+        pool_address: address,
+        amount_in: u64,
+        x_to_y: bool,
+        min_out: u64,
+    ) acquires LiquidityPool {
+        let trader_address = signer::address_of(trader);
+        let pool = borrow_global_mut<LiquidityPool<X, Y>>(pool_address);
+        let reserve_x = coin::value(&pool.reserve_x);
+        let reserve_y = coin::value(&pool.reserve_y);
+        if (x_to_y) {
+            let amount_out = swap_amount_out(amount_in, reserve_x, reserve_y);
+            assert!(amount_out >= min_out, 3);
+            coin::merge(&mut pool.reserve_x, coin::withdraw<X>(trader, amount_in));
+            if (!coin::is_account_registered<Y>(trader_address)) {
+                coin::register<Y>(trader);
+            };
+            coin::deposit(trader_address, coin::extract(&mut pool.reserve_y, amount_out));
+        } else {
+            let amount_out = swap_amount_out(amount_in, reserve_y, reserve_x);
+            assert!(amount_out >= min_out, 3);
+            coin::merge(&mut pool.reserve_y, coin::withdraw<Y>(trader, amount_in));
+            if (!coin::is_account_registered<X>(trader_address)) {
+                coin::register<X>(trader);
+            };
+            coin::deposit(trader_address, coin::extract(&mut pool.reserve_x, amount_out));
+        }
+    }
+
+    // Amount coming out of a swap, keeping the product of the reserves constant.
+    fun swap_amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64): u64 {
+        let amount_in = (amount_in as u128);
+        ((amount_in * (reserve_out as u128) / ((reserve_in as u128) + amount_in)) as u64)
+    }
+
+    //
+    // Custom coin
+    //
+
+    // Coin type for `aptos_framework::managed_coin`, to be initialized by the publisher.
+    struct SimpleCoin {}
+
     // used to initialize `Resource`
     const NAME: vector<u8> = b"hello";
     const DATA: vector<u8> = x"0123456789ABCDEF";
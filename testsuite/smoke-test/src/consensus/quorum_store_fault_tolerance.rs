@@ -8,7 +8,8 @@ use crate::{
 use aptos::test::CliTestFramework;
 use aptos_consensus::QUORUM_STORE_DB_NAME;
 use aptos_forge::{
-    reconfig, wait_for_all_nodes_to_catchup, NodeExt, Swarm, SwarmExt, TransactionType,
+    reconfig, wait_for_all_nodes_to_catchup, GasOverrides, NodeExt, Swarm, SwarmExt,
+    TransactionType,
 };
 use aptos_logger::info;
 use aptos_rest_client::Client;
@@ -38,8 +39,12 @@ async fn generate_traffic_and_assert_committed(
     let txn_stat = generate_traffic(swarm, nodes, duration, 1, vec![vec![
         (
             TransactionType::CoinTransfer {
+                amount_range: (1, 1),
                 invalid_transaction_ratio: 0,
+                invalid_kinds: Vec::new(),
                 sender_use_account_pool: false,
+                account_pool: None,
+                gas_overrides: GasOverrides::default(),
             },
             70,
         ),
@@ -48,6 +53,8 @@ async fn generate_traffic_and_assert_committed(
                 add_created_accounts_to_pool: true,
                 max_account_working_set: 1_000_000,
                 creation_balance: 1_000_000,
+                account_pool: None,
+                max_pool_size: None,
             },
             20,
         ),
@@ -4,8 +4,8 @@
 use crate::smoke_test_environment::new_local_swarm_with_aptos;
 use anyhow::ensure;
 use aptos_forge::{
-    EmitJobMode, EmitJobRequest, EntryPoints, NodeExt, Result, Swarm, TransactionType, TxnEmitter,
-    TxnStats,
+    EmitJobMode, EmitJobRequest, EntryPoints, GasOverrides, NodeExt, Result, Swarm,
+    TransactionType, TxnEmitter, TxnStats,
 };
 use aptos_sdk::{transaction_builder::TransactionFactory, types::PeerId};
 use rand::{rngs::OsRng, SeedableRng};
@@ -60,6 +60,8 @@ async fn test_txn_emmitter() {
                     add_created_accounts_to_pool: true,
                     max_account_working_set: 1_000_000,
                     creation_balance: 1_000_000,
+                    account_pool: None,
+                    max_pool_size: None,
                 },
                 20,
             )],
@@ -67,10 +69,13 @@ async fn test_txn_emmitter() {
                 (TransactionType::default_coin_transfer(), 20),
                 // // commenting this out given it consistently fails smoke test
                 // // and it seems to be called only from `test_txn_emmitter`
-                // (TransactionType::NftMintAndTransfer, 20),
+                // (TransactionType::default_nft(), 20),
                 (
                     TransactionType::PublishPackage {
                         use_account_pool: false,
+                        account_pool: None,
+                        num_modules: 1,
+                        module_bytes_padding: 0,
                     },
                     20,
                 ),
@@ -83,7 +88,10 @@ async fn test_txn_emmitter() {
                             data_length: Some(32),
                         },
                         num_modules: 1,
+                        module_address_space: 0,
                         use_account_pool: true,
+                        account_pool: None,
+                        gas_overrides: GasOverrides::default(),
                     },
                     20,
                 ),
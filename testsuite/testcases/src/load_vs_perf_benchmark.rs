@@ -3,8 +3,8 @@
 
 use crate::NetworkLoadTest;
 use aptos_forge::{
-    EmitJobMode, EmitJobRequest, EntryPoints, NetworkContext, NetworkTest, Result, Test,
-    TransactionType, TxnStats,
+    EmitJobMode, EmitJobRequest, EntryPoints, GasOverrides, NetworkContext, NetworkTest, Result,
+    Test, TransactionType, TxnStats,
 };
 use aptos_logger::info;
 use rand::SeedableRng;
@@ -68,19 +68,27 @@ impl TransactinWorkload {
             add_created_accounts_to_pool: true,
             max_account_working_set: 10_000_000,
             creation_balance: 200_000_000,
+            account_pool: None,
+            max_pool_size: None,
         };
 
         match self {
             Self::NoOp => request.transaction_type(TransactionType::CallCustomModules {
                 entry_point: EntryPoints::Nop,
                 num_modules: 1,
+                module_address_space: 0,
                 use_account_pool: false,
+                account_pool: None,
+                gas_overrides: GasOverrides::default(),
             }),
             Self::LargeModuleWorkingSet => {
                 request.transaction_type(TransactionType::CallCustomModules {
                     entry_point: EntryPoints::Nop,
                     num_modules: 1000,
+                    module_address_space: 0,
                     use_account_pool: false,
+                    account_pool: None,
+                    gas_overrides: GasOverrides::default(),
                 })
             },
             Self::WriteResourceSmall | Self::WriteResourceBig => {
@@ -95,13 +103,16 @@ impl TransactinWorkload {
                         ),
                     },
                     num_modules: 1,
+                    module_address_space: 0,
                     use_account_pool: true,
+                    account_pool: None,
+                    gas_overrides: GasOverrides::default(),
                 };
                 request.transaction_mix_per_phase(vec![
                     // warmup
+                    vec![(account_creation_type.clone(), 1)],
                     vec![(account_creation_type, 1)],
-                    vec![(account_creation_type, 1)],
-                    vec![(write_type, 1)],
+                    vec![(write_type.clone(), 1)],
                     // cooldown
                     vec![(write_type, 1)],
                 ])
@@ -109,12 +120,15 @@ impl TransactinWorkload {
             Self::PublishPackages => {
                 let write_type = TransactionType::PublishPackage {
                     use_account_pool: true,
+                    account_pool: None,
+                    num_modules: 1,
+                    module_bytes_padding: 0,
                 };
                 request.transaction_mix_per_phase(vec![
                     // warmup
+                    vec![(account_creation_type.clone(), 1)],
                     vec![(account_creation_type, 1)],
-                    vec![(account_creation_type, 1)],
-                    vec![(write_type, 1)],
+                    vec![(write_type.clone(), 1)],
                     // cooldown
                     vec![(write_type, 1)],
                 ])
@@ -125,26 +139,33 @@ impl TransactinWorkload {
             Self::NoOpUnique | Self::CoinTransferUnique => {
                 let write_type = if let Self::CoinTransferUnique = self {
                     TransactionType::CoinTransfer {
+                        amount_range: (1, 1),
                         invalid_transaction_ratio: 0,
+                        invalid_kinds: Vec::new(),
                         sender_use_account_pool: true,
+                        account_pool: None,
+                        gas_overrides: GasOverrides::default(),
                     }
                 } else {
                     TransactionType::CallCustomModules {
                         entry_point: EntryPoints::Nop,
                         num_modules: 1,
+                        module_address_space: 0,
                         use_account_pool: true,
+                        account_pool: None,
+                        gas_overrides: GasOverrides::default(),
                     }
                 };
                 request.transaction_mix_per_phase(vec![
                     // warmup
+                    vec![(account_creation_type.clone(), 1)],
                     vec![(account_creation_type, 1)],
-                    vec![(account_creation_type, 1)],
-                    vec![(write_type, 1)],
+                    vec![(write_type.clone(), 1)],
                     // cooldown
                     vec![(write_type, 1)],
                 ])
             },
-            Self::NftMint => request.transaction_type(TransactionType::NftMintAndTransfer),
+            Self::NftMint => request.transaction_type(TransactionType::default_nft()),
         }
     }
 }